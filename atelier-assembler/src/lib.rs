@@ -8,6 +8,15 @@ in different representations and assemble them seamlessly.
 Note that if a path processed by the assembler is a directory then all files in the directory will
 be added **recursively**.
 
+Files are read and parsed in parallel, the resulting models are then merged in the sorted order of
+their file paths so that the assembled model is the same regardless of how the reads were scheduled.
+Both the assembler and the file type registry are `Send + Sync` and so may be shared across threads.
+
+By default any conflict between the models, for example two different shapes with the same
+identifier, results in an error. The assembler may instead be given a set of `MergePolicies` to
+resolve conflicts, in which case each conflict is logged as a warning; use
+[`assemble`](struct.ModelAssembler.html#method.assemble) to retrieve the full `MergeReport`.
+
 # Example
 
 The following is the simple, and most common, method of using the assembler. This uses the
@@ -55,16 +64,18 @@ extern crate log;
 
 use atelier_core::error::{Error, ErrorKind, Result};
 use atelier_core::io::ModelReader;
+use atelier_core::model::merge::{MergePolicies, MergeReport};
 use atelier_core::model::Model;
 use atelier_json as json;
 use atelier_smithy as smithy;
+use rayon::prelude::*;
 use search_path::SearchPath;
 use std::collections::{BTreeMap, HashSet};
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Formatter};
 use std::fs::{read_dir, File};
 use std::path::{Path, PathBuf};
-use std::rc::Rc;
+use std::sync::Arc;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -94,8 +105,8 @@ pub struct FileType {
 ///
 #[derive(Clone)]
 pub struct FileTypeRegistry {
-    by_extension: BTreeMap<String, Rc<FileType>>,
-    by_mime_type: BTreeMap<String, Rc<FileType>>,
+    by_extension: BTreeMap<String, Arc<FileType>>,
+    by_mime_type: BTreeMap<String, Arc<FileType>>,
 }
 
 ///
@@ -110,6 +121,7 @@ pub struct FileTypeRegistry {
 pub struct ModelAssembler {
     file_types: FileTypeRegistry,
     paths: HashSet<PathBuf>,
+    merge_policies: MergePolicies,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -140,8 +152,8 @@ impl FileType {
     ///
     /// Construct a new file type with the provided display name and reader function.
     ///
-    pub fn new(name: &str, reader_fn: FileReader) -> Rc<Self> {
-        Rc::new(Self {
+    pub fn new(name: &str, reader_fn: FileReader) -> Arc<Self> {
+        Arc::new(Self {
             display_name: name.to_string(),
             mime_type: None,
             reader_fn,
@@ -151,8 +163,8 @@ impl FileType {
     ///
     /// Construct a new file type with the provided display name, MIME type, and reader function.
     ///
-    pub fn new_with_mime_type(name: &str, reader_fn: FileReader, mime_type: &str) -> Rc<Self> {
-        Rc::new(Self {
+    pub fn new_with_mime_type(name: &str, reader_fn: FileReader, mime_type: &str) -> Arc<Self> {
+        Arc::new(Self {
             display_name: name.to_string(),
             mime_type: Some(mime_type.to_string()),
             reader_fn,
@@ -227,7 +239,7 @@ impl FileTypeRegistry {
     ///
     /// Add a mapping from file extension to reader function.
     ///
-    pub fn register(&mut self, file_type: Rc<FileType>, extension: &str) {
+    pub fn register(&mut self, file_type: Arc<FileType>, extension: &str) {
         let _ = self
             .by_extension
             .insert(extension.to_lowercase(), file_type.clone());
@@ -241,7 +253,7 @@ impl FileTypeRegistry {
     ///
     /// Add a mapping from file extension to reader function.
     ///
-    pub fn register_all(&mut self, file_type: Rc<FileType>, extensions: &[&str]) {
+    pub fn register_all(&mut self, file_type: Arc<FileType>, extensions: &[&str]) {
         for extension in extensions {
             let _ = self
                 .by_extension
@@ -259,21 +271,21 @@ impl FileTypeRegistry {
     ///
     /// Returns the reader function for the provided extension, if present.
     ///
-    pub fn get(&self, extension: &str) -> Option<&Rc<FileType>> {
+    pub fn get(&self, extension: &str) -> Option<&Arc<FileType>> {
         self.by_extension.get(&extension.to_lowercase())
     }
 
     ///
     /// Returns the reader function for the provided MIME type, if present.
     ///
-    pub fn get_by_mime_type(&self, mime_type: &str) -> Option<&Rc<FileType>> {
+    pub fn get_by_mime_type(&self, mime_type: &str) -> Option<&Arc<FileType>> {
         self.by_mime_type.get(&mime_type.to_lowercase())
     }
 
     ///
     /// Remove the mapping for the provided extension.
     ///
-    pub fn remove(&mut self, extension: &str) -> Option<Rc<FileType>> {
+    pub fn remove(&mut self, extension: &str) -> Option<Arc<FileType>> {
         self.by_extension.remove(&extension.to_lowercase())
     }
 
@@ -307,36 +319,14 @@ impl TryFrom<&mut ModelAssembler> for Model {
 
     fn try_from(value: &mut ModelAssembler) -> std::result::Result<Self, Self::Error> {
         info!("Model::try_from::<ModelAssembler>(...)");
-        if value.is_empty() {
-            Ok(Model::default())
-        } else {
-            let models: std::result::Result<Vec<Model>, Self::Error> = value
-                .expand_file_paths()
-                .iter()
-                .map(|file_name| value.read_model(&file_name))
-                .collect();
-            debug!(
-                "Model::try_from::<ModelAssembler>(...): found models => {:#?}",
-                &models
+        let (model, report) = value.assemble()?;
+        for (conflict, resolution) in report.conflicts() {
+            warn!(
+                "Model::try_from::<ModelAssembler>(...): {} ({})",
+                conflict, resolution
             );
-            match models {
-                Ok(mut models) => {
-                    if models.is_empty() {
-                        warn!(
-                            "Model::try_from::<ModelAssembler>(...): No models found to assemble!"
-                        );
-                        Ok(Model::default())
-                    } else {
-                        let mut merged = models.remove(0);
-                        for other in models {
-                            merged.merge(other)?;
-                        }
-                        Ok(merged)
-                    }
-                }
-                Err(err) => Err(err),
-            }
         }
+        Ok(model)
     }
 }
 
@@ -350,6 +340,7 @@ impl ModelAssembler {
         let new_self = Self {
             file_types,
             paths: Default::default(),
+            merge_policies: Default::default(),
         };
         match search_path {
             None => new_self,
@@ -377,6 +368,57 @@ impl ModelAssembler {
         self
     }
 
+    ///
+    /// Returns the policies used to resolve conflicts when merging the models read by this
+    /// assembler.
+    ///
+    pub fn merge_policies(&self) -> &MergePolicies {
+        &self.merge_policies
+    }
+
+    ///
+    /// Set the policies used to resolve conflicts when merging the models read by this assembler,
+    /// by default all conflicts result in an error.
+    ///
+    pub fn set_merge_policies(&mut self, merge_policies: MergePolicies) -> &mut Self {
+        self.merge_policies = merge_policies;
+        self
+    }
+
+    ///
+    /// Read all the files found by [`expand_file_paths`](method.expand_file_paths.html) and merge
+    /// them into a single model, resolving any conflicts using the assembler's merge policies. The
+    /// returned report contains all the conflicts found, across all files, in merge order.
+    ///
+    pub fn assemble(&self) -> Result<(Model, MergeReport)> {
+        info!("ModelAssembler::assemble()");
+        let mut report = MergeReport::default();
+        if self.is_empty() {
+            return Ok((Model::default(), report));
+        }
+        let models: Vec<Model> = self
+            .expand_file_paths()
+            .par_iter()
+            .map(|file_name| self.read_model(file_name))
+            .collect::<Vec<Result<Model>>>()
+            .into_iter()
+            .collect::<Result<Vec<Model>>>()?;
+        debug!("ModelAssembler::assemble(): found models => {:#?}", &models);
+        let mut models = models.into_iter();
+        match models.next() {
+            None => {
+                warn!("ModelAssembler::assemble(): No models found to assemble!");
+                Ok((Model::default(), report))
+            }
+            Some(mut merged) => {
+                for other in models {
+                    report.append(merged.merge_with(other, &self.merge_policies)?);
+                }
+                Ok((merged, report))
+            }
+        }
+    }
+
     ///
     /// Returns `true` if there are no paths added to this assembler, else `false`.
     ///
@@ -400,7 +442,8 @@ impl ModelAssembler {
 
     ///
     /// This processes all the paths added to the assembler and filters them for those with
-    /// supported file extensions as well as finding files recursively in directory paths.
+    /// supported file extensions as well as finding files recursively in directory paths. The
+    /// returned file paths are sorted, and contain no duplicates.
     ///
     pub fn expand_file_paths(&self) -> Vec<PathBuf> {
         info!("ModelAssembler::expand_file_paths()");
@@ -408,6 +451,8 @@ impl ModelAssembler {
         for path in &self.paths {
             self.expand_path(path, &mut results);
         }
+        results.sort();
+        results.dedup();
         results
     }
 
@@ -446,7 +491,9 @@ impl ModelAssembler {
             let extension = extension.to_string_lossy().to_lowercase();
             if let Some(file_type) = self.file_types.get(extension.as_ref()) {
                 let mut file = File::open(path).unwrap();
-                Ok(file_type.reader()(&mut file)?)
+                let mut model = file_type.reader()(&mut file)?;
+                model.set_source_file(&path.to_string_lossy());
+                Ok(model)
             } else {
                 error!("ModelAssembler::read_model - not a known extension");
                 Err(ErrorKind::InvalidRepresentation("unknown".to_string()).into())
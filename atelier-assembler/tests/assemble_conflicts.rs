@@ -0,0 +1,63 @@
+use atelier_assembler::ModelAssembler;
+use atelier_core::model::merge::{MergeConflict, MergePolicies, MergeResolution};
+use atelier_core::model::shapes::HasTraits;
+use atelier_core::model::values::Value;
+use atelier_core::model::{Model, ShapeID};
+use std::convert::TryFrom;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn make_assembler() -> ModelAssembler {
+    let mut path = PathBuf::from(MANIFEST_DIR);
+    path.push("tests");
+    path.push("conflicts");
+
+    let mut assembler = ModelAssembler::new(Default::default(), None);
+    let _ = assembler.push(&path);
+    assembler
+}
+
+#[test]
+fn test_conflicts_fail_by_default() {
+    assert!(Model::try_from(make_assembler()).is_err());
+}
+
+#[test]
+fn test_conflicts_reported() {
+    let mut assembler = make_assembler();
+    let _ = assembler.set_merge_policies(MergePolicies::keep_first());
+    let (model, report) = assembler.assemble().unwrap();
+
+    let thing = ShapeID::from_str("example.conflicts#Thing").unwrap();
+    assert_eq!(
+        report.conflicts().cloned().collect::<Vec<_>>(),
+        vec![
+            (
+                MergeConflict::Metadata("owner".to_string()),
+                MergeResolution::KeptFirst
+            ),
+            (
+                MergeConflict::Shape(thing.clone()),
+                MergeResolution::KeptFirst
+            ),
+        ]
+    );
+    assert_eq!(
+        model.metadata_value("owner"),
+        Some(&Value::String("first".to_string()))
+    );
+    assert!(model
+        .shape(&thing)
+        .unwrap()
+        .has_member(&"name".parse().unwrap()));
+
+    let name = ShapeID::from_str("example.conflicts#Name").unwrap();
+    assert!(model
+        .shape(&name)
+        .unwrap()
+        .has_trait(&ShapeID::from_str("smithy.api#documentation").unwrap()));
+
+    assert_eq!(Model::try_from(assembler).unwrap(), model);
+}
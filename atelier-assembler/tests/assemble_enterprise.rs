@@ -1,5 +1,5 @@
 use atelier_assembler::ModelAssembler;
-use atelier_core::model::Model;
+use atelier_core::model::{HasSourceLocation, Model};
 use std::convert::TryFrom;
 use std::path::PathBuf;
 
@@ -21,3 +21,19 @@ fn merge_good_models() {
     assert!(model.is_ok());
     assert_ne!(model.unwrap(), Model::default());
 }
+
+#[test]
+fn merged_models_keep_source_files() {
+    let mut path = PathBuf::from(MANIFEST_DIR);
+    path.push("tests");
+    path.push("good");
+
+    let mut assembler = ModelAssembler::default();
+    let _ = assembler.push(&path);
+
+    let model = Model::try_from(assembler).unwrap();
+    for shape in model.shapes().filter(|shape| !shape.is_unresolved()) {
+        let location = shape.location().as_ref().unwrap();
+        assert!(location.file().as_ref().unwrap().ends_with(".smithy"));
+    }
+}
@@ -0,0 +1,60 @@
+use atelier_assembler::{FileTypeRegistry, ModelAssembler};
+use atelier_core::io::ModelReader;
+use atelier_core::model::Model;
+use atelier_smithy::SmithyReader;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::path::PathBuf;
+use std::thread;
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+fn good_models_path() -> PathBuf {
+    let mut path = PathBuf::from(MANIFEST_DIR);
+    path.push("tests");
+    path.push("good");
+    path
+}
+
+#[test]
+fn assembler_is_send_and_sync() {
+    assert_send_sync::<ModelAssembler>();
+    assert_send_sync::<FileTypeRegistry>();
+    assert_send_sync::<Model>();
+}
+
+#[test]
+fn parallel_assembly_matches_sequential() {
+    let mut assembler = ModelAssembler::new(FileTypeRegistry::default(), None);
+    let _ = assembler.push(&good_models_path());
+
+    let file_paths = assembler.expand_file_paths();
+    assert_eq!(file_paths.len(), 3);
+    assert!(file_paths.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let mut models = file_paths.iter().map(|file_path| {
+        let mut file = File::open(file_path).unwrap();
+        let mut model = SmithyReader.read(&mut file).unwrap();
+        model.set_source_file(&file_path.to_string_lossy());
+        model
+    });
+    let mut sequential = models.next().unwrap();
+    for model in models {
+        sequential.merge(model).unwrap();
+    }
+
+    let assembled = Model::try_from(assembler.clone()).unwrap();
+    assert_eq!(assembled, sequential);
+
+    let handles: Vec<thread::JoinHandle<Model>> = (0..4)
+        .map(|_| {
+            let assembler = assembler.clone();
+            thread::spawn(move || Model::try_from(assembler).unwrap())
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), assembled);
+    }
+}
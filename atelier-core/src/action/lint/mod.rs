@@ -52,7 +52,7 @@ use crate::error::Result as ModelResult;
 use crate::model::shapes::{HasTraits, ShapeKind};
 use crate::model::values::Value;
 use crate::model::{HasIdentity, Identifier, Model, ShapeID};
-use heck::{CamelCase, MixedCase};
+use heck::{CamelCase, MixedCase, ShoutySnakeCase};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
@@ -83,7 +83,7 @@ linter_or_validator_defn! {
 
 ///
 /// Run each provided `Linter`, in order, against the provided `Model`. All issues will be collated
-/// and returned together.
+/// and returned together, with the source location of each issue's locus added where it is known.
 ///
 /// The `fail_fast` flag determines the behavior if a linter action returns an error. If `true` the
 /// process stops and returns all reported issues up to that point, if `false` it continues on.
@@ -98,6 +98,9 @@ pub fn run_linter_actions(
     for linter in linters.iter_mut() {
         linter.check(model)?;
         let new_issues = linter.issues_mut();
+        for issue in new_issues.iter_mut() {
+            issue.locate_in(model);
+        }
         issues.append(new_issues);
         if fail_fast
             && new_issues
@@ -138,6 +141,12 @@ impl Linter for NamingConventions {
                         self.check_applied_trait_names(member.traits());
                     }
                 }
+                ShapeKind::Enum(body) | ShapeKind::IntEnum(body) => {
+                    for member in body.members() {
+                        self.check_enum_member_name(member.id(), shape.id());
+                        self.check_applied_trait_names(member.traits());
+                    }
+                }
                 _ => {}
             }
         }
@@ -190,6 +199,19 @@ impl NamingConventions {
             ));
         }
     }
+    fn check_enum_member_name(&mut self, id: &Identifier, parent: &ShapeID) {
+        let shape_name = id.to_string();
+        if shape_name.to_shouty_snake_case() != shape_name {
+            self.issues.push(ActionIssue::info_at(
+                self.label(),
+                &format!(
+                    "Enum member names should conform to UPPER_SNAKE_CASE, i.e. {}",
+                    shape_name.to_shouty_snake_case()
+                ),
+                parent.clone(),
+            ));
+        }
+    }
     fn check_applied_trait_names(&mut self, ids: &HashMap<ShapeID, Option<Value>>) {
         for id in ids.keys() {
             self.check_trait_name(id);
@@ -223,6 +245,14 @@ impl Linter for UnwelcomeTerms {
                         }
                     }
                 }
+                ShapeKind::Enum(body) | ShapeKind::IntEnum(body) => {
+                    for member in body.members() {
+                        self.check_identifier(member.id(), Some(shape.id()));
+                        for id in member.traits().keys() {
+                            self.check_shape_id(id);
+                        }
+                    }
+                }
                 _ => {}
             }
         }
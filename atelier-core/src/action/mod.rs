@@ -66,6 +66,7 @@ shape or member identifier accordingly.
                 member_name: None,
             },
         ),
+        location: None,
     },
     ActionIssue {
         reporter: "CorrectTypeReferences",
@@ -82,6 +83,7 @@ shape or member identifier accordingly.
                 member_name: None,
             },
         ),
+        location: None,
     },
 ]
 ```
@@ -89,7 +91,7 @@ shape or member identifier accordingly.
 */
 
 use crate::error::Result as ModelResult;
-use crate::model::{Model, ShapeID};
+use crate::model::{Model, ShapeID, SourceLocation};
 use std::fmt::{Display, Formatter};
 
 // ------------------------------------------------------------------------------------------------
@@ -122,6 +124,7 @@ pub struct ActionIssue {
     level: IssueLevel,
     message: String,
     locus: Option<ShapeID>,
+    location: Option<SourceLocation>,
 }
 
 ///
@@ -207,13 +210,17 @@ impl Display for ActionIssue {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{} {}{}: {}",
+            "{} {}{}{}: {}",
             self.reporter(),
             self.level(),
             match self.locus() {
                 Some(id) => format!(" {}", id),
                 None => String::new(),
             },
+            match self.location() {
+                Some(location) => format!(" ({})", location),
+                None => String::new(),
+            },
             self.message()
         )
     }
@@ -230,6 +237,7 @@ impl ActionIssue {
             level,
             message: message.to_string(),
             locus: None,
+            location: None,
         }
     }
 
@@ -242,6 +250,7 @@ impl ActionIssue {
             level,
             message: message.to_string(),
             locus: Some(locus),
+            location: None,
         }
     }
 
@@ -297,6 +306,28 @@ impl ActionIssue {
     pub fn locus(&self) -> &Option<ShapeID> {
         &self.locus
     }
+
+    /// Return the source location of the locus of the error, if one is known.
+    pub fn location(&self) -> &Option<SourceLocation> {
+        &self.location
+    }
+
+    /// Set the source location of the locus of the error.
+    pub fn set_location(&mut self, location: SourceLocation) {
+        self.location = Some(location);
+    }
+
+    ///
+    /// If this issue has a locus, but no source location, set the location to that recorded
+    /// for the corresponding shape, or member, in the provided model.
+    ///
+    pub fn locate_in(&mut self, model: &Model) {
+        if self.location.is_none() {
+            if let Some(locus) = &self.locus {
+                self.location = model.location_of(locus).cloned();
+            }
+        }
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -305,7 +336,6 @@ impl ActionIssue {
 
 pub mod lint;
 
-#[doc(hidden)]
 pub mod transform;
 
 pub mod validate;
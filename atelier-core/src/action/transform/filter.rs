@@ -0,0 +1,359 @@
+use crate::action::{Action, ActionIssue, Transformer};
+use crate::error::{Error, Result as ModelResult};
+use crate::model::shapes::{
+    AppliedTraits, Enumeration, HasTraits, ListOrSet, Map, MemberShape, Operation, Resource,
+    Service, ShapeKind, Simple, StructureOrUnion, TopLevelShape,
+};
+use crate::model::traits::TagsTrait;
+use crate::model::values::Value;
+use crate::model::visitor::{walk_model_mut, ModelVisitorMut, VisitAction};
+use crate::model::{body_members, HasIdentity, Identifier, Model, ShapeID};
+use crate::prelude::{PRELUDE_NAMESPACE, TRAIT_INTERNAL, TRAIT_PRIVATE};
+use std::collections::HashSet;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { FilterShapes {
+    include_tags: Vec<String>,
+    exclude_tags: Vec<String>,
+    exclude_traits: Vec<ShapeID>,
+    strip_traits: Vec<ShapeID>
+}, r#"This transformer creates a new model which is a filtered view, or projection, of the source
+model; it is the equivalent of the `includeShapesByTag`, `excludeShapesByTag`,
+`excludeShapesByTrait`, and `excludeTraits` transforms of a `smithy-build` projection.
+
+* If `include_tags` is not empty, only those shapes with at least one of these values in their
+  `@tags` trait are kept; trait definitions are always kept.
+* Shapes, and members, with any of the values in `exclude_tags` in their `@tags` trait are removed.
+* Shapes, and members, with any of the traits in `exclude_traits` applied are removed, see
+  `FilterShapes::external` for a filter that removes `@internal` and `@private` shapes.
+* The traits in `strip_traits` are removed from all shapes and members, as are their definitions.
+
+Members that target a removed shape are also removed, as are lists, sets, and maps whose members
+target a removed shape. References to removed shapes from services, resources, operations, and
+mixins are removed, and each removed shape and member is reported as an informational issue."# }
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct ShapeFilter<'a> {
+    filter: &'a FilterShapes,
+    removed: &'a HashSet<ShapeID>,
+    removed_members: Vec<ShapeID>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { FilterShapes {
+    include_tags: Default::default(),
+    exclude_tags: Default::default(),
+    exclude_traits: Default::default(),
+    strip_traits: Default::default()
+} }
+
+linter_or_validator_action_impl! { FilterShapes, "FilterShapes" }
+
+impl Transformer for FilterShapes {
+    fn transform(&mut self, source: &Model) -> ModelResult<Model> {
+        let removed = self.removed_shapes(source)?;
+
+        let mut model = source.clone();
+        let mut shape_filter = ShapeFilter {
+            filter: self,
+            removed: &removed,
+            removed_members: Default::default(),
+        };
+        walk_model_mut(&mut model, &mut shape_filter)?;
+        let mut removed_members = shape_filter.removed_members;
+
+        for shape in model.shapes.values_mut() {
+            if shape.mixins().any(|mixin| removed.contains(mixin)) {
+                let mixins: Vec<ShapeID> = shape
+                    .mixins()
+                    .filter(|mixin| !removed.contains(mixin))
+                    .cloned()
+                    .collect();
+                shape.remove_mixins();
+                for mixin in mixins {
+                    shape.add_mixin(mixin);
+                }
+            }
+        }
+
+        let mut removed: Vec<&ShapeID> = removed.iter().collect();
+        removed.sort();
+        for shape_id in removed {
+            self.issues.push(ActionIssue::info_at(
+                self.label(),
+                &format!("The shape {} was removed by the filter.", shape_id),
+                shape_id.clone(),
+            ));
+        }
+        removed_members.sort();
+        for member_id in removed_members {
+            self.issues.push(ActionIssue::info_at(
+                self.label(),
+                &format!("The member {} was removed by the filter.", member_id),
+                member_id,
+            ));
+        }
+
+        Ok(model)
+    }
+}
+
+impl FilterShapes {
+    ///
+    /// Construct a new filter that removes all shapes, and members, with either of the prelude
+    /// traits `internal` or `private` applied.
+    ///
+    pub fn external() -> Self {
+        let mut new = Self::default();
+        new.add_exclude_trait(ShapeID::new_unchecked(
+            PRELUDE_NAMESPACE,
+            TRAIT_INTERNAL,
+            None,
+        ));
+        new.add_exclude_trait(ShapeID::new_unchecked(
+            PRELUDE_NAMESPACE,
+            TRAIT_PRIVATE,
+            None,
+        ));
+        new
+    }
+
+    array_member! { include_tags, include_tag, String }
+
+    array_member! { exclude_tags, exclude_tag, String }
+
+    array_member! { exclude_traits, exclude_trait, ShapeID }
+
+    array_member! { strip_traits, strip_trait, ShapeID }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn removed_shapes(&self, model: &Model) -> ModelResult<HashSet<ShapeID>> {
+        let mut removed: HashSet<ShapeID> = Default::default();
+        for shape in model.shapes() {
+            let stripped = shape.is_trait() && self.strip_traits.contains(shape.id());
+            let not_included =
+                self.has_include_tags() && !shape.is_trait() && !self.is_included(shape)?;
+            if stripped || not_included || self.is_excluded(shape)? {
+                let _ = removed.insert(shape.id().clone());
+            }
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for shape in model.shapes() {
+                if removed.contains(shape.id()) {
+                    continue;
+                }
+                if let ShapeKind::List(_) | ShapeKind::Set(_) | ShapeKind::Map(_) = shape.body() {
+                    for member in body_members(shape.body()) {
+                        if removed.contains(member.target()) || self.is_excluded(member)? {
+                            let _ = removed.insert(shape.id().clone());
+                            changed = true;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(removed)
+    }
+
+    fn is_included(&self, element: &impl HasTraits) -> ModelResult<bool> {
+        Ok(match element.typed_trait::<TagsTrait>()? {
+            None => false,
+            Some(tags) => tags.values().any(|tag| self.include_tags.contains(tag)),
+        })
+    }
+
+    fn is_excluded(&self, element: &impl HasTraits) -> ModelResult<bool> {
+        if self
+            .exclude_traits
+            .iter()
+            .any(|trait_id| element.has_trait(trait_id))
+        {
+            return Ok(true);
+        }
+        Ok(match element.typed_trait::<TagsTrait>()? {
+            None => false,
+            Some(tags) => tags.values().any(|tag| self.exclude_tags.contains(tag)),
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+macro_rules! filter_shape_fn {
+    ($fn_name:ident, $shape_type:ty) => {
+        fn $fn_name(
+            &mut self,
+            id: &ShapeID,
+            _: &mut AppliedTraits,
+            _: &mut $shape_type,
+        ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+            Ok(self.shape_action(id))
+        }
+    };
+}
+
+impl ModelVisitorMut for ShapeFilter<'_> {
+    type Error = Error;
+
+    filter_shape_fn! { simple_shape, Simple }
+    filter_shape_fn! { list, ListOrSet }
+    filter_shape_fn! { set, ListOrSet }
+    filter_shape_fn! { map, Map }
+    filter_shape_fn! { structure, StructureOrUnion }
+    filter_shape_fn! { union, StructureOrUnion }
+    filter_shape_fn! { enum_shape, Enumeration }
+    filter_shape_fn! { int_enum, Enumeration }
+
+    fn service(
+        &mut self,
+        id: &ShapeID,
+        _: &mut AppliedTraits,
+        shape: &mut Service,
+    ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+        let operations = self.kept(shape.operations());
+        shape.set_operations(&operations);
+        let resources = self.kept(shape.resources());
+        shape.set_resources(&resources);
+        let renamed: Vec<ShapeID> = shape
+            .renames()
+            .map(|(shape_id, _)| shape_id)
+            .filter(|shape_id| self.removed.contains(shape_id))
+            .cloned()
+            .collect();
+        for shape_id in renamed {
+            let _ = shape.remove_rename_shape(&shape_id);
+        }
+        Ok(self.shape_action(id))
+    }
+
+    fn operation(
+        &mut self,
+        id: &ShapeID,
+        _: &mut AppliedTraits,
+        shape: &mut Operation,
+    ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+        if self.is_removed(shape.input()) {
+            shape.unset_input();
+        }
+        if self.is_removed(shape.output()) {
+            shape.unset_output();
+        }
+        let errors = self.kept(shape.errors());
+        shape.set_errors(&errors);
+        Ok(self.shape_action(id))
+    }
+
+    fn resource(
+        &mut self,
+        id: &ShapeID,
+        _: &mut AppliedTraits,
+        shape: &mut Resource,
+    ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+        let identifiers: Vec<Identifier> = shape
+            .identifiers()
+            .filter(|(_, target)| self.removed.contains(target))
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in identifiers {
+            let _ = shape.remove_identifier(&name);
+        }
+        if self.is_removed(shape.create()) {
+            shape.unset_create();
+        }
+        if self.is_removed(shape.put()) {
+            shape.unset_put();
+        }
+        if self.is_removed(shape.read()) {
+            shape.unset_read();
+        }
+        if self.is_removed(shape.update()) {
+            shape.unset_update();
+        }
+        if self.is_removed(shape.delete()) {
+            shape.unset_delete();
+        }
+        if self.is_removed(shape.list()) {
+            shape.unset_list();
+        }
+        let operations = self.kept(shape.operations());
+        shape.set_operations(&operations);
+        let collection_operations = self.kept(shape.collection_operations());
+        shape.set_collection_operations(&collection_operations);
+        let resources = self.kept(shape.resources());
+        shape.set_resources(&resources);
+        Ok(self.shape_action(id))
+    }
+
+    fn unresolved_id(
+        &mut self,
+        id: &ShapeID,
+        _: &mut AppliedTraits,
+    ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+        Ok(self.shape_action(id))
+    }
+
+    fn member(
+        &mut self,
+        parent_shape_id: &ShapeID,
+        member: &mut MemberShape,
+    ) -> Result<VisitAction<MemberShape>, Self::Error> {
+        if self.removed.contains(member.target()) || self.filter.is_excluded(member)? {
+            self.removed_members
+                .push(parent_shape_id.make_member(member.id().clone()));
+            Ok(VisitAction::Remove)
+        } else {
+            Ok(VisitAction::Keep)
+        }
+    }
+
+    fn applied_trait(
+        &mut self,
+        _: &ShapeID,
+        trait_id: &ShapeID,
+        _: &mut Option<Value>,
+    ) -> Result<VisitAction<Option<Value>>, Self::Error> {
+        if self.filter.strip_traits.contains(trait_id) || self.removed.contains(trait_id) {
+            Ok(VisitAction::Remove)
+        } else {
+            Ok(VisitAction::Keep)
+        }
+    }
+}
+
+impl ShapeFilter<'_> {
+    fn shape_action(&self, id: &ShapeID) -> VisitAction<TopLevelShape> {
+        if self.removed.contains(id) {
+            VisitAction::Remove
+        } else {
+            VisitAction::Keep
+        }
+    }
+
+    fn is_removed(&self, id: &Option<ShapeID>) -> bool {
+        id.as_ref()
+            .map(|id| self.removed.contains(id))
+            .unwrap_or_default()
+    }
+
+    fn kept<'a>(&self, ids: impl Iterator<Item = &'a ShapeID>) -> Vec<ShapeID> {
+        ids.filter(|id| !self.removed.contains(id))
+            .cloned()
+            .collect()
+    }
+}
@@ -2,28 +2,103 @@
 This module contains core `Transformer` implementations.
 */
 
-// use ...
+use crate::action::{Action, ActionIssue, Transformer};
+use crate::error::Result as ModelResult;
+use crate::model::shapes::{
+    Enumeration, HasTraits, MemberShape, ShapeKind, StructureOrUnion, TopLevelShape,
+};
+use crate::model::{HasIdentity, Model};
+use crate::syntax::{MEMBER_KEY, MEMBER_MEMBER, MEMBER_VALUE};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
-
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+linter_or_validator_defn! { FlattenMixins, r#"This transformer creates a new model in which all
+mixins have been flattened into the shapes that use them. Each shape that applies mixins is replaced
+with a shape containing its effective members and traits, and all shapes marked with the `mixin`
+trait are removed; the resulting model is therefore suitable for tools that do not support mixins."# }
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+linter_or_validator_default_impl! { FlattenMixins }
+
+linter_or_validator_action_impl! { FlattenMixins, "FlattenMixins" }
+
+impl Transformer for FlattenMixins {
+    fn transform(&mut self, source: &Model) -> ModelResult<Model> {
+        let mut model = Model::new(*source.smithy_version());
+        for (key, value) in source.metadata() {
+            let _ = model.add_metadata(key.clone(), value.clone())?;
+        }
+        for shape in source.shapes() {
+            if shape.is_mixin() {
+                continue;
+            }
+            let mut shape = shape.clone();
+            if shape.has_mixins() {
+                let members = source.effective_members(shape.id())?;
+                let traits = source.effective_traits(shape.id())?;
+                shape.remove_mixins();
+                *shape.traits_mut() = traits;
+                set_members(&mut shape, members);
+            }
+            model.add_shape(shape)?;
+        }
+        Ok(model)
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn set_members(shape: &mut TopLevelShape, members: Vec<MemberShape>) {
+    match shape.body_mut() {
+        ShapeKind::List(body) | ShapeKind::Set(body) => {
+            for member in members {
+                if member.id().to_string() == MEMBER_MEMBER {
+                    body.set_member(member);
+                }
+            }
+        }
+        ShapeKind::Map(body) => {
+            for member in members {
+                if member.id().to_string() == MEMBER_KEY {
+                    body.set_key(member);
+                } else if member.id().to_string() == MEMBER_VALUE {
+                    body.set_value(member);
+                }
+            }
+        }
+        ShapeKind::Structure(body) | ShapeKind::Union(body) => {
+            *body = StructureOrUnion::with_members(&members);
+        }
+        ShapeKind::Enum(body) | ShapeKind::IntEnum(body) => {
+            *body = Enumeration::with_members(&members);
+        }
+        _ => {}
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
+
+#[doc(hidden)]
+pub mod rename;
+pub use rename::RenameShapes;
+
+#[doc(hidden)]
+pub mod prune;
+pub use prune::PruneUnreachableShapes;
+
+#[doc(hidden)]
+pub mod filter;
+pub use filter::FilterShapes;
+
+#[doc(hidden)]
+pub mod namespace;
+pub use namespace::{NamespaceRule, RelocateNamespaces};
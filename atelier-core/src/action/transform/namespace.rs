@@ -0,0 +1,188 @@
+use crate::action::transform::rename::{rename_shapes, ReferenceRewriter};
+use crate::action::{Action, ActionIssue, Transformer};
+use crate::error::{ErrorKind, Result as ModelResult};
+use crate::model::visitor::walk_model_mut;
+use crate::model::{HasIdentity, Model, NamespaceID, ShapeID};
+use crate::prelude::PRELUDE_NAMESPACE;
+use crate::syntax::SHAPE_ID_NAMESPACE_SEPARATOR;
+use std::collections::{BTreeMap, HashMap};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A rule used by `RelocateNamespaces` to determine the new namespace for a shape.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum NamespaceRule {
+    /// Shapes in exactly the namespace `from` are moved to the namespace `to`.
+    Exact {
+        /// The namespace to match.
+        from: NamespaceID,
+        /// The replacement namespace.
+        to: NamespaceID,
+    },
+    ///
+    /// Shapes in the namespace `from`, or any namespace it is a prefix of, are moved by replacing
+    /// the prefix with `to`; for example the prefix rule `com.acme.legacy` to `com.acme.core` will
+    /// move `com.acme.legacy.auth` to `com.acme.core.auth`.
+    ///
+    Prefix {
+        /// The namespace prefix to match.
+        from: NamespaceID,
+        /// The replacement namespace prefix.
+        to: NamespaceID,
+    },
+}
+
+linter_or_validator_defn! { RelocateNamespaces { rules: Vec<NamespaceRule> }, r#"This transformer
+creates a new model in which shapes have been moved from one namespace to another according to a
+list of rules; for each shape the first matching rule is used, shapes in the prelude namespace are
+never moved. All references to the moved shapes are rewritten, as with `RenameShapes`.
+
+If a relocated shape would have the same identifier as another shape in the model, either one that
+was not moved or one moved by a different rule, no model is produced. Instead each collision is
+reported as an error issue, and these are returned in an `ErrorKind::ActionIssue` error. Otherwise
+each relocated shape is reported as an informational issue."# }
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl NamespaceRule {
+    /// Construct a rule that moves shapes in exactly the namespace `from` to `to`.
+    pub fn exact(from: NamespaceID, to: NamespaceID) -> Self {
+        Self::Exact { from, to }
+    }
+
+    /// Construct a rule that moves shapes in `from`, and all namespaces below it, to `to`.
+    pub fn prefix(from: NamespaceID, to: NamespaceID) -> Self {
+        Self::Prefix { from, to }
+    }
+
+    /// Returns the new namespace for `namespace` if this rule matches it, else `None`.
+    pub fn relocate(&self, namespace: &NamespaceID) -> Option<NamespaceID> {
+        match self {
+            Self::Exact { from, to } => {
+                if namespace == from {
+                    Some(to.clone())
+                } else {
+                    None
+                }
+            }
+            Self::Prefix { from, to } => {
+                let namespace = namespace.to_string();
+                let from = from.to_string();
+                if namespace == from {
+                    Some(to.clone())
+                } else {
+                    namespace
+                        .strip_prefix(&from)
+                        .filter(|rest| rest.starts_with(SHAPE_ID_NAMESPACE_SEPARATOR))
+                        .map(|rest| NamespaceID::new_unchecked(&format!("{}{}", to, rest)))
+                }
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { RelocateNamespaces { rules: Default::default() } }
+
+linter_or_validator_action_impl! { RelocateNamespaces, "RelocateNamespaces" }
+
+impl Transformer for RelocateNamespaces {
+    fn transform(&mut self, source: &Model) -> ModelResult<Model> {
+        let renames = self.relocations(source);
+
+        let mut targets: BTreeMap<&ShapeID, Vec<&ShapeID>> = Default::default();
+        for (from, to) in &renames {
+            targets.entry(to).or_default().push(from);
+        }
+        let mut errors: Vec<ActionIssue> = Default::default();
+        for (to, mut sources) in targets {
+            sources.sort();
+            if sources.len() > 1 {
+                errors.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The shapes {} would all be relocated to {}.",
+                        sources
+                            .iter()
+                            .map(|id| id.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                        to
+                    ),
+                    to.clone(),
+                ));
+            } else if source.has_shape(to) && !renames.contains_key(to) {
+                errors.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The shape {} cannot be relocated to {}, a shape with that identifier already exists.",
+                        sources[0], to
+                    ),
+                    sources[0].clone(),
+                ));
+            }
+        }
+        if !errors.is_empty() {
+            self.issues.extend(errors.iter().cloned());
+            return Err(ErrorKind::ActionIssue(errors).into());
+        }
+
+        let mut model = source.clone();
+        let mut rewriter = ReferenceRewriter::new(source, &renames);
+        walk_model_mut(&mut model, &mut rewriter)?;
+        rename_shapes(&mut model, &renames, &mut rewriter);
+
+        let mut renames: Vec<(&ShapeID, &ShapeID)> = renames.iter().collect();
+        renames.sort();
+        for (from, to) in renames {
+            self.issues.push(ActionIssue::info_at(
+                self.label(),
+                &format!("The shape {} was relocated to {}.", from, to),
+                to.clone(),
+            ));
+        }
+
+        Ok(model)
+    }
+}
+
+impl RelocateNamespaces {
+    /// Construct a new transformer that will relocate shapes using the provided rules.
+    pub fn new(rules: &[NamespaceRule]) -> Self {
+        Self {
+            issues: Default::default(),
+            rules: rules.to_vec(),
+        }
+    }
+
+    array_member! { rules, rule, NamespaceRule }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn relocations(&self, model: &Model) -> HashMap<ShapeID, ShapeID> {
+        model
+            .shapes()
+            .map(|shape| shape.id())
+            .filter(|shape_id| shape_id.namespace().to_string() != PRELUDE_NAMESPACE)
+            .filter_map(|shape_id| {
+                self.rules
+                    .iter()
+                    .find_map(|rule| rule.relocate(shape_id.namespace()))
+                    .map(|namespace| {
+                        (
+                            shape_id.clone(),
+                            namespace.make_shape(shape_id.shape_name().clone()),
+                        )
+                    })
+            })
+            .filter(|(from, to)| from != to)
+            .collect()
+    }
+}
@@ -0,0 +1,155 @@
+use crate::action::{Action, ActionIssue, Transformer};
+use crate::error::{ErrorKind, Result as ModelResult};
+use crate::model::conformance::shape_id_values;
+use crate::model::neighbor::NeighborIndex;
+use crate::model::shapes::{HasTraits, TopLevelShape};
+use crate::model::{body_members, HasIdentity, Model, ShapeID};
+use std::collections::{BTreeSet, HashSet};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { PruneUnreachableShapes { services: Vec<ShapeID>, keep_traits: bool },
+r#"This transformer creates a new model containing only the shapes reachable from one or more
+service shapes; if no services are specified all the services in the model are used. The closure
+follows service and resource bindings, operation inputs, outputs, and errors, members and their
+targets, mixins, applied traits, and shape identifiers embedded in trait values. Only those trait
+values defined as shape identifiers, using the `@idRef` trait, are followed, along with the
+identifiers in `@references` and `@examples` errors.
+
+If `keep_traits` is set, shapes with the `@trait` trait, and the shapes they reference, are kept
+even if no reachable shape applies them. Each removed shape is reported as an informational
+issue."# }
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { PruneUnreachableShapes {
+    services: Default::default(),
+    keep_traits: false
+} }
+
+linter_or_validator_action_impl! { PruneUnreachableShapes, "PruneUnreachableShapes" }
+
+impl Transformer for PruneUnreachableShapes {
+    fn transform(&mut self, source: &Model) -> ModelResult<Model> {
+        let mut roots: Vec<ShapeID> = if self.services.is_empty() {
+            source
+                .shapes()
+                .filter(|shape| shape.is_service())
+                .map(|shape| shape.id().clone())
+                .collect()
+        } else {
+            for service in &self.services {
+                match source.shape(service) {
+                    None => return Err(ErrorKind::UnknownShape(service.to_string()).into()),
+                    Some(shape) if !shape.is_service() => {
+                        return Err(ErrorKind::ServiceExpected(service.clone()).into())
+                    }
+                    _ => {}
+                }
+            }
+            self.services.clone()
+        };
+        if self.keep_traits {
+            roots.extend(
+                source
+                    .shapes()
+                    .filter(|shape| shape.is_trait())
+                    .map(|shape| shape.id().clone()),
+            );
+        }
+
+        let reachable = reachable_shapes(source, roots);
+
+        let mut model = Model::new(*source.smithy_version());
+        for (key, value) in source.metadata() {
+            let _ = model.add_metadata(key.clone(), value.clone())?;
+        }
+        let mut removed: Vec<&ShapeID> = Default::default();
+        for shape in source.shapes() {
+            if reachable.contains(shape.id()) {
+                model.add_shape(shape.clone())?;
+            } else {
+                removed.push(shape.id());
+            }
+        }
+        removed.sort();
+        for shape_id in removed {
+            self.issues.push(ActionIssue::info_at(
+                self.label(),
+                &format!(
+                    "The shape {} was removed, it is not reachable from a service.",
+                    shape_id
+                ),
+                shape_id.clone(),
+            ));
+        }
+
+        Ok(model)
+    }
+}
+
+impl PruneUnreachableShapes {
+    /// Construct a new transformer that keeps only the shapes reachable from `services`.
+    pub fn new(services: &[ShapeID]) -> Self {
+        Self {
+            issues: Default::default(),
+            services: services.to_vec(),
+            keep_traits: false,
+        }
+    }
+
+    array_member! { services, service, ShapeID }
+
+    boolean_member! { keep_traits }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the top-level shape identifiers in the transitive closure of `roots`; member shape
+/// identifiers are followed but only their enclosing shapes are returned.
+///
+fn reachable_shapes(model: &Model, roots: Vec<ShapeID>) -> HashSet<ShapeID> {
+    let index = NeighborIndex::from(model);
+    let mut visited: HashSet<ShapeID> = Default::default();
+    let mut reachable: HashSet<ShapeID> = Default::default();
+    let mut pending = roots;
+    while let Some(shape_id) = pending.pop() {
+        if !visited.insert(shape_id.clone()) {
+            continue;
+        }
+        pending.extend(index.neighbors(&shape_id).cloned());
+        if let Some(shape) = model.shape(&shape_id.shape_only()) {
+            let _ = reachable.insert(shape_id.shape_only());
+            if !shape_id.is_member() {
+                pending.extend(trait_value_references(model, shape));
+            }
+        }
+    }
+    reachable
+}
+
+///
+/// Return the shape identifiers, within the values of traits applied to `shape` or its members,
+/// of shapes in `model`; see `shape_id_values` for which strings are identifiers.
+///
+fn trait_value_references(model: &Model, shape: &TopLevelShape) -> BTreeSet<ShapeID> {
+    let traits = shape.traits().iter().chain(
+        body_members(shape.body())
+            .into_iter()
+            .flat_map(|member| member.traits().iter()),
+    );
+    traits
+        .filter_map(|(trait_id, value)| value.as_ref().map(|value| (trait_id, value)))
+        .flat_map(|(trait_id, value)| shape_id_values(model, trait_id, value))
+        .filter_map(|s| ShapeID::from_str(s).ok())
+        .filter(|id| model.has_shape(&id.shape_only()))
+        .collect()
+}
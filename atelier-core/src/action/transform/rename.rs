@@ -0,0 +1,404 @@
+use crate::action::{Action, ActionIssue, Transformer};
+use crate::error::{Error, ErrorKind, Result as ModelResult};
+use crate::model::conformance::shape_id_values_mut;
+use crate::model::shapes::{
+    AppliedTraits, Enumeration, HasTraits, ListOrSet, Map, MemberShape, Operation, Resource,
+    Service, Simple, StructureOrUnion, TopLevelShape,
+};
+use crate::model::values::Value;
+use crate::model::visitor::{walk_model_mut, ModelVisitorMut, VisitAction};
+use crate::model::{HasIdentity, Model, ShapeID};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { RenameShapes { renames: HashMap<ShapeID, ShapeID> }, r#"This
+transformer creates a new model in which one or more top-level shapes have been renamed, the new
+name may be in a different namespace. Every reference to a renamed shape is rewritten, including
+member targets, operation inputs, outputs, and errors, service and resource bindings, service
+renames, mixins, applied trait identifiers, and shape identifiers embedded in trait values. Only
+those trait values defined as shape identifiers, using the `@idRef` trait, are rewritten, along
+with the identifiers in `@references` and `@examples` errors; other strings, such as
+documentation, are left unchanged even if they look like a renamed shape's identifier.
+
+Each renamed shape, and each shape with references that were rewritten, is reported as an
+informational issue."# }
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A mutable visitor that rewrites all references to renamed shapes, recording the shapes that
+/// contained those references. Note that this does not rename the shapes themselves, or rewrite
+/// mixins, see `rename_shapes`.
+///
+#[derive(Debug)]
+pub(crate) struct ReferenceRewriter<'a> {
+    model: &'a Model,
+    renames: &'a HashMap<ShapeID, ShapeID>,
+    rewritten: BTreeMap<ShapeID, BTreeSet<ShapeID>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { RenameShapes { renames: Default::default() } }
+
+linter_or_validator_action_impl! { RenameShapes, "RenameShapes" }
+
+impl Transformer for RenameShapes {
+    fn transform(&mut self, source: &Model) -> ModelResult<Model> {
+        let mut targets: BTreeSet<&ShapeID> = Default::default();
+        for (from, to) in &self.renames {
+            if from.is_member() {
+                return Err(ErrorKind::ShapeIDExpected(from.clone()).into());
+            }
+            if to.is_member() {
+                return Err(ErrorKind::ShapeIDExpected(to.clone()).into());
+            }
+            if !targets.insert(to) || (source.has_shape(to) && !self.renames.contains_key(to)) {
+                return Err(ErrorKind::MergeShapeConflict(to.clone()).into());
+            }
+        }
+
+        let mut model = source.clone();
+        let mut rewriter = ReferenceRewriter::new(source, &self.renames);
+        walk_model_mut(&mut model, &mut rewriter)?;
+        rename_shapes(&mut model, &self.renames, &mut rewriter);
+
+        let mut renames: Vec<(&ShapeID, &ShapeID)> = self.renames.iter().collect();
+        renames.sort();
+        for (from, to) in renames {
+            if source.has_shape(from) {
+                self.issues.push(ActionIssue::info_at(
+                    self.label(),
+                    &format!("The shape {} was renamed to {}.", from, to),
+                    to.clone(),
+                ));
+            } else {
+                self.issues.push(ActionIssue::warning_at(
+                    self.label(),
+                    &format!(
+                        "The shape {} cannot be renamed, it is not in the model.",
+                        from
+                    ),
+                    from.clone(),
+                ));
+            }
+        }
+        for (shape_id, references) in rewriter.rewritten() {
+            self.issues.push(ActionIssue::info_at(
+                self.label(),
+                &format!(
+                    "The references from {} to {} were rewritten.",
+                    shape_id,
+                    references
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                shape_id.clone(),
+            ));
+        }
+
+        Ok(model)
+    }
+}
+
+impl RenameShapes {
+    /// Construct a new transformer that will rename each key in `renames` to its value.
+    pub fn new(renames: HashMap<ShapeID, ShapeID>) -> Self {
+        Self {
+            issues: Default::default(),
+            renames,
+        }
+    }
+
+    hash_member! { renames, rename, ShapeID, to, ShapeID }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+macro_rules! rewrite_traits_fn {
+    ($fn_name:ident, $shape_type:ty) => {
+        fn $fn_name(
+            &mut self,
+            id: &ShapeID,
+            traits: &mut AppliedTraits,
+            _: &mut $shape_type,
+        ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+            self.rewrite_trait_ids(id, traits);
+            Ok(VisitAction::Keep)
+        }
+    };
+}
+
+impl ModelVisitorMut for ReferenceRewriter<'_> {
+    type Error = Error;
+
+    rewrite_traits_fn! { simple_shape, Simple }
+    rewrite_traits_fn! { list, ListOrSet }
+    rewrite_traits_fn! { set, ListOrSet }
+    rewrite_traits_fn! { map, Map }
+    rewrite_traits_fn! { structure, StructureOrUnion }
+    rewrite_traits_fn! { union, StructureOrUnion }
+    rewrite_traits_fn! { enum_shape, Enumeration }
+    rewrite_traits_fn! { int_enum, Enumeration }
+
+    fn service(
+        &mut self,
+        id: &ShapeID,
+        traits: &mut AppliedTraits,
+        shape: &mut Service,
+    ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+        self.rewrite_trait_ids(id, traits);
+        let operations = self.rewrite_ids(id, shape.operations());
+        shape.set_operations(&operations);
+        let resources = self.rewrite_ids(id, shape.resources());
+        shape.set_resources(&resources);
+        let renames: Vec<(ShapeID, _)> = shape
+            .renames()
+            .map(|(shape_id, local_name)| (shape_id.clone(), local_name.clone()))
+            .collect();
+        shape.set_renames(
+            renames
+                .into_iter()
+                .map(|(shape_id, local_name)| (self.rewrite_id(id, &shape_id), local_name))
+                .collect(),
+        );
+        Ok(VisitAction::Keep)
+    }
+
+    fn operation(
+        &mut self,
+        id: &ShapeID,
+        traits: &mut AppliedTraits,
+        shape: &mut Operation,
+    ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+        self.rewrite_trait_ids(id, traits);
+        if let Some(input) = shape.input().clone() {
+            shape.set_input(self.rewrite_id(id, &input));
+        }
+        if let Some(output) = shape.output().clone() {
+            shape.set_output(self.rewrite_id(id, &output));
+        }
+        let errors = self.rewrite_ids(id, shape.errors());
+        shape.set_errors(&errors);
+        Ok(VisitAction::Keep)
+    }
+
+    fn resource(
+        &mut self,
+        id: &ShapeID,
+        traits: &mut AppliedTraits,
+        shape: &mut Resource,
+    ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+        self.rewrite_trait_ids(id, traits);
+        let identifiers: Vec<_> = shape
+            .identifiers()
+            .map(|(name, target)| (name.clone(), target.clone()))
+            .collect();
+        shape.set_identifiers(
+            identifiers
+                .into_iter()
+                .map(|(name, target)| (name, self.rewrite_id(id, &target)))
+                .collect(),
+        );
+        if let Some(target) = shape.create().clone() {
+            shape.set_create(self.rewrite_id(id, &target));
+        }
+        if let Some(target) = shape.put().clone() {
+            shape.set_put(self.rewrite_id(id, &target));
+        }
+        if let Some(target) = shape.read().clone() {
+            shape.set_read(self.rewrite_id(id, &target));
+        }
+        if let Some(target) = shape.update().clone() {
+            shape.set_update(self.rewrite_id(id, &target));
+        }
+        if let Some(target) = shape.delete().clone() {
+            shape.set_delete(self.rewrite_id(id, &target));
+        }
+        if let Some(target) = shape.list().clone() {
+            shape.set_list(self.rewrite_id(id, &target));
+        }
+        let operations = self.rewrite_ids(id, shape.operations());
+        shape.set_operations(&operations);
+        let collection_operations = self.rewrite_ids(id, shape.collection_operations());
+        shape.set_collection_operations(&collection_operations);
+        let resources = self.rewrite_ids(id, shape.resources());
+        shape.set_resources(&resources);
+        Ok(VisitAction::Keep)
+    }
+
+    fn unresolved_id(
+        &mut self,
+        id: &ShapeID,
+        traits: &mut AppliedTraits,
+    ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+        self.rewrite_trait_ids(id, traits);
+        Ok(VisitAction::Keep)
+    }
+
+    fn member(
+        &mut self,
+        parent_shape_id: &ShapeID,
+        member: &mut MemberShape,
+    ) -> Result<VisitAction<MemberShape>, Self::Error> {
+        let target = self.rewrite_id(parent_shape_id, member.target());
+        member.set_target(target);
+        self.rewrite_trait_ids(parent_shape_id, member.traits_mut());
+        Ok(VisitAction::Keep)
+    }
+
+    fn applied_trait(
+        &mut self,
+        shape_id: &ShapeID,
+        trait_id: &ShapeID,
+        value: &mut Option<Value>,
+    ) -> Result<VisitAction<Option<Value>>, Self::Error> {
+        if let Some(value) = value {
+            let owner = shape_id.shape_only();
+            let trait_id = self.original_id(trait_id);
+            for s in shape_id_values_mut(self.model, &trait_id, value) {
+                if let Ok(id) = ShapeID::from_str(s) {
+                    let renamed = self.rewrite_id(&owner, &id);
+                    if renamed != id {
+                        *s = renamed.to_string();
+                    }
+                }
+            }
+        }
+        Ok(VisitAction::Keep)
+    }
+}
+
+impl<'a> ReferenceRewriter<'a> {
+    ///
+    /// Construct a new rewriter, `model` is the model before any references are rewritten and is
+    /// used to find the definitions of applied traits.
+    ///
+    pub(crate) fn new(model: &'a Model, renames: &'a HashMap<ShapeID, ShapeID>) -> Self {
+        Self {
+            model,
+            renames,
+            rewritten: Default::default(),
+        }
+    }
+
+    ///
+    /// Return the shapes, by their original identifier, with rewritten references along with the
+    /// original identifiers of the shapes they referenced.
+    ///
+    pub(crate) fn rewritten(&self) -> &BTreeMap<ShapeID, BTreeSet<ShapeID>> {
+        &self.rewritten
+    }
+
+    fn renamed(&self, id: &ShapeID) -> Option<ShapeID> {
+        if id.is_member() {
+            self.renames
+                .get(&id.shape_only())
+                .map(|renamed| renamed.make_member(id.member_name().clone().unwrap()))
+        } else {
+            self.renames.get(id).cloned()
+        }
+    }
+
+    fn original_id(&self, id: &ShapeID) -> ShapeID {
+        self.renames
+            .iter()
+            .find(|(_, renamed)| *renamed == id)
+            .map(|(original, _)| original.clone())
+            .unwrap_or_else(|| id.clone())
+    }
+
+    fn rewrite_id(&mut self, owner: &ShapeID, id: &ShapeID) -> ShapeID {
+        match self.renamed(id) {
+            None => id.clone(),
+            Some(renamed) => {
+                let _ = self
+                    .rewritten
+                    .entry(owner.clone())
+                    .or_default()
+                    .insert(id.shape_only());
+                renamed
+            }
+        }
+    }
+
+    fn rewrite_ids<'b>(
+        &mut self,
+        owner: &ShapeID,
+        ids: impl Iterator<Item = &'b ShapeID>,
+    ) -> Vec<ShapeID> {
+        let ids: Vec<ShapeID> = ids.cloned().collect();
+        ids.iter().map(|id| self.rewrite_id(owner, id)).collect()
+    }
+
+    fn rewrite_trait_ids(&mut self, owner: &ShapeID, traits: &mut AppliedTraits) {
+        if traits.keys().any(|id| self.renames.contains_key(id)) {
+            *traits = std::mem::take(traits)
+                .into_iter()
+                .map(|(id, value)| (self.rewrite_id(owner, &id), value))
+                .collect();
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Rename the shapes in `model`, after their references have been rewritten with `rewriter`, this
+/// also rewrites the mixins applied to each shape. The shapes recorded by `rewriter` are updated
+/// to their new identifiers.
+///
+pub(crate) fn rename_shapes(
+    model: &mut Model,
+    renames: &HashMap<ShapeID, ShapeID>,
+    rewriter: &mut ReferenceRewriter<'_>,
+) {
+    for shape in model.shapes.values_mut() {
+        if shape.mixins().any(|mixin| renames.contains_key(mixin)) {
+            let shape_id = shape.id().clone();
+            let mixins: Vec<ShapeID> = shape
+                .mixins()
+                .map(|mixin| rewriter.rewrite_id(&shape_id, mixin))
+                .collect();
+            shape.remove_mixins();
+            for mixin in mixins {
+                shape.add_mixin(mixin);
+            }
+        }
+    }
+
+    let renamed: Vec<TopLevelShape> = renames
+        .iter()
+        .filter_map(|(from, to)| {
+            model.shapes.remove(from).map(|mut shape| {
+                shape.set_id(to.clone());
+                shape
+            })
+        })
+        .collect();
+    for shape in renamed {
+        let _ = model.shapes.insert(shape.id().clone(), shape);
+    }
+
+    rewriter.rewritten = std::mem::take(&mut rewriter.rewritten)
+        .into_iter()
+        .map(|(shape_id, references)| {
+            (
+                renames.get(&shape_id).cloned().unwrap_or(shape_id),
+                references,
+            )
+        })
+        .collect();
+}
@@ -0,0 +1,406 @@
+use crate::action::{Action, ActionIssue, Validator};
+use crate::error::Result as ModelResult;
+use crate::model::diff::{Change, ModelDiff};
+use crate::model::neighbor::{Relationship, RelationshipKind};
+use crate::model::shapes::{HasTraits, ShapeKind};
+use crate::model::values::{Number, Value};
+use crate::model::{Model, ShapeID};
+use crate::prelude::{
+    PRELUDE_NAMESPACE, TRAIT_ENUM, TRAIT_ENUMVALUE, TRAIT_ERROR, TRAIT_HTTP, TRAIT_LENGTH,
+    TRAIT_PATTERN, TRAIT_RANGE, TRAIT_REQUIRED,
+};
+use std::cmp::Ordering;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { BackwardCompatibility { original: Model }, r#"This validator compares a
+model against an original, or previously released, version of the same model and reports changes
+that are not backward compatible.
+
+Changes that will break existing clients, such as removing an operation, changing the target of a
+member, adding the `@required` trait to an existing member, narrowing a `@length` or `@range`
+constraint, removing an enum value, or changing the `@http` binding of an operation are reported as
+errors. Changes that may break some clients, such as adding an error to an operation or a member to
+a union, are reported as warnings."# }
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_action_impl! { BackwardCompatibility, "BackwardCompatibility" }
+
+impl Validator for BackwardCompatibility {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        let diff = ModelDiff::new(&self.original, model);
+        for change in diff.changes() {
+            self.check_change(change, model);
+        }
+        Ok(())
+    }
+}
+
+impl BackwardCompatibility {
+    /// Construct a new validator that will compare models against the `original` model.
+    pub fn new(original: Model) -> Self {
+        Self {
+            issues: Default::default(),
+            original,
+        }
+    }
+
+    /// Return the original model that other models are compared against.
+    pub fn original(&self) -> &Model {
+        &self.original
+    }
+
+    fn check_change(&mut self, change: &Change, model: &Model) {
+        match change {
+            Change::ShapeRemoved { shape } => {
+                let kind = match self.original.shape(shape).map(|shape| shape.body()) {
+                    Some(ShapeKind::Operation(_)) => "operation",
+                    Some(ShapeKind::Resource(_)) => "resource",
+                    Some(ShapeKind::Service(_)) => "service",
+                    _ => "shape",
+                };
+                self.error(shape, &format!("The {} {} was removed.", kind, shape));
+            }
+            Change::ShapeTypeChanged { shape, from, to } => self.error(
+                shape,
+                &format!(
+                    "The type of shape {} was changed from {} to {}.",
+                    shape, from, to
+                ),
+            ),
+            Change::MemberRemoved { member, .. } => {
+                let parent = self.original.shape(&member.shape_only());
+                if parent.map(|shape| shape.is_enum() || shape.is_int_enum()) == Some(true) {
+                    self.error(member, &format!("The enum value {} was removed.", member));
+                } else {
+                    self.error(member, &format!("The member {} was removed.", member));
+                }
+            }
+            Change::MemberAdded { member, .. } => {
+                let parent = model.shape(&member.shape_only());
+                if let Some(parent) = parent {
+                    let is_required = member
+                        .member_name()
+                        .as_ref()
+                        .and_then(|name| parent.member(name))
+                        .map(|member| member.has_trait(&prelude_trait(TRAIT_REQUIRED)))
+                        .unwrap_or_default();
+                    if parent.is_structure() && is_required {
+                        self.error(
+                            member,
+                            &format!("The required member {} was added.", member),
+                        );
+                    } else if parent.is_union() {
+                        self.warning(
+                            member,
+                            &format!(
+                                "The member {} was added to a union, clients may not expect it.",
+                                member
+                            ),
+                        );
+                    }
+                }
+            }
+            Change::MemberRetargeted { member, from, to } => self.error(
+                member,
+                &format!(
+                    "The target of member {} was changed from {} to {}.",
+                    member, from, to
+                ),
+            ),
+            Change::TraitAdded {
+                shape, trait_id, ..
+            } => self.check_trait_added(shape, trait_id),
+            Change::TraitRemoved {
+                shape, trait_id, ..
+            } if is_prelude_trait(trait_id, TRAIT_HTTP)
+                || is_prelude_trait(trait_id, TRAIT_ERROR) =>
+            {
+                self.error(
+                    shape,
+                    &format!("The trait @{} was removed from {}.", trait_id, shape),
+                );
+            }
+            Change::TraitChanged {
+                shape,
+                trait_id,
+                from,
+                to,
+            } => self.check_trait_changed(shape, trait_id, from, to),
+            Change::ReferenceRemoved(rel) => self.check_reference_removed(rel),
+            Change::ReferenceAdded(rel) => self.check_reference_added(rel),
+            Change::IdentifierAdded { resource, name, .. } => self.error(
+                resource,
+                &format!(
+                    "The identifier {} was added to resource {}.",
+                    name, resource
+                ),
+            ),
+            Change::IdentifierRemoved { resource, name, .. } => self.error(
+                resource,
+                &format!(
+                    "The identifier {} was removed from resource {}.",
+                    name, resource
+                ),
+            ),
+            Change::IdentifierRetargeted {
+                resource,
+                name,
+                from,
+                to,
+            } => self.error(
+                resource,
+                &format!(
+                    "The target of identifier {} on resource {} was changed from {} to {}.",
+                    name, resource, from, to
+                ),
+            ),
+            Change::ServiceRenameChanged { service, shape, .. } => self.warning(
+                service,
+                &format!(
+                    "The name of shape {} in the closure of service {} was changed.",
+                    shape, service
+                ),
+            ),
+            _ => {}
+        }
+    }
+
+    fn check_trait_added(&mut self, shape: &ShapeID, trait_id: &ShapeID) {
+        if is_prelude_trait(trait_id, TRAIT_REQUIRED) {
+            if self.existed(shape) {
+                self.error(shape, &format!("The member {} is now required.", shape));
+            }
+        } else if is_prelude_trait(trait_id, TRAIT_LENGTH)
+            || is_prelude_trait(trait_id, TRAIT_RANGE)
+            || is_prelude_trait(trait_id, TRAIT_ENUM)
+        {
+            self.error(
+                shape,
+                &format!(
+                    "The constraint trait @{} was added to {}, narrowing the accepted values.",
+                    trait_id, shape
+                ),
+            );
+        } else if is_prelude_trait(trait_id, TRAIT_PATTERN) {
+            self.warning(
+                shape,
+                &format!("The constraint trait @{} was added to {}.", trait_id, shape),
+            );
+        }
+    }
+
+    fn check_trait_changed(
+        &mut self,
+        shape: &ShapeID,
+        trait_id: &ShapeID,
+        from: &Option<Value>,
+        to: &Option<Value>,
+    ) {
+        if is_prelude_trait(trait_id, TRAIT_LENGTH) || is_prelude_trait(trait_id, TRAIT_RANGE) {
+            if is_narrowed(from, to) {
+                self.error(
+                    shape,
+                    &format!(
+                        "The constraint trait @{} on {} was narrowed.",
+                        trait_id, shape
+                    ),
+                );
+            }
+        } else if is_prelude_trait(trait_id, TRAIT_ENUM) {
+            let to_values = enum_trait_values(to);
+            for value in enum_trait_values(from) {
+                if !to_values.contains(&value) {
+                    self.error(
+                        shape,
+                        &format!("The enum value {} was removed from {}.", value, shape),
+                    );
+                }
+            }
+        } else if is_prelude_trait(trait_id, TRAIT_ENUMVALUE) {
+            self.error(
+                shape,
+                &format!("The value of enum member {} was changed.", shape),
+            );
+        } else if is_prelude_trait(trait_id, TRAIT_HTTP) {
+            self.error(
+                shape,
+                &format!("The HTTP binding of operation {} was changed.", shape),
+            );
+        } else if is_prelude_trait(trait_id, TRAIT_ERROR) {
+            self.error(
+                shape,
+                &format!("The error source of {} was changed.", shape),
+            );
+        } else if is_prelude_trait(trait_id, TRAIT_PATTERN) {
+            self.warning(
+                shape,
+                &format!(
+                    "The constraint trait @{} on {} was changed.",
+                    trait_id, shape
+                ),
+            );
+        }
+    }
+
+    fn check_reference_removed(&mut self, rel: &Relationship) {
+        let (source, target) = (rel.source(), rel.target());
+        match rel.kind() {
+            RelationshipKind::Operation
+            | RelationshipKind::Resource
+            | RelationshipKind::CollectionOperation => self.error(
+                source,
+                &format!("The {} {} was removed from {}.", rel.kind(), target, source),
+            ),
+            RelationshipKind::Create
+            | RelationshipKind::Put
+            | RelationshipKind::Read
+            | RelationshipKind::Update
+            | RelationshipKind::Delete
+            | RelationshipKind::List => self.error(
+                source,
+                &format!(
+                    "The {} lifecycle operation {} was removed from {}.",
+                    rel.kind(),
+                    target,
+                    source
+                ),
+            ),
+            RelationshipKind::Input | RelationshipKind::Output => self.error(
+                source,
+                &format!(
+                    "The {} of operation {} was changed, it was {}.",
+                    rel.kind(),
+                    source,
+                    target
+                ),
+            ),
+            RelationshipKind::Error => self.warning(
+                source,
+                &format!("The error {} was removed from {}.", target, source),
+            ),
+            RelationshipKind::Mixin => self.warning(
+                source,
+                &format!("The mixin {} was removed from {}.", target, source),
+            ),
+            _ => {}
+        }
+    }
+
+    fn check_reference_added(&mut self, rel: &Relationship) {
+        let (source, target) = (rel.source(), rel.target());
+        match rel.kind() {
+            RelationshipKind::Input | RelationshipKind::Output => {
+                let had_reference = match self.original.shape(source).map(|shape| shape.body()) {
+                    Some(ShapeKind::Operation(operation)) => {
+                        if rel.kind() == &RelationshipKind::Input {
+                            operation.has_input()
+                        } else {
+                            operation.has_output()
+                        }
+                    }
+                    _ => false,
+                };
+                if !had_reference {
+                    self.error(
+                        source,
+                        &format!(
+                            "The {} {} was added to operation {}.",
+                            rel.kind(),
+                            target,
+                            source
+                        ),
+                    );
+                }
+            }
+            RelationshipKind::Error => self.warning(
+                source,
+                &format!(
+                    "The error {} was added to {}, clients may not expect it.",
+                    target, source
+                ),
+            ),
+            _ => {}
+        }
+    }
+
+    fn existed(&self, shape_id: &ShapeID) -> bool {
+        match shape_id.member_name() {
+            None => self.original.has_shape(shape_id),
+            Some(member_name) => self
+                .original
+                .shape(&shape_id.shape_only())
+                .map(|shape| shape.has_member(member_name))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn error(&mut self, locus: &ShapeID, message: &str) {
+        self.issues
+            .push(ActionIssue::error_at(self.label(), message, locus.clone()));
+    }
+
+    fn warning(&mut self, locus: &ShapeID, message: &str) {
+        self.issues.push(ActionIssue::warning_at(
+            self.label(),
+            message,
+            locus.clone(),
+        ));
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn prelude_trait(name: &str) -> ShapeID {
+    ShapeID::new_unchecked(PRELUDE_NAMESPACE, name, None)
+}
+
+#[inline]
+fn is_prelude_trait(id: &ShapeID, name: &str) -> bool {
+    id == &prelude_trait(name)
+}
+
+fn bound<'a>(value: &'a Option<Value>, key: &str) -> Option<&'a Number> {
+    match value {
+        Some(Value::Object(map)) => match map.get(key) {
+            Some(Value::Number(n)) => Some(n),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_narrowed(from: &Option<Value>, to: &Option<Value>) -> bool {
+    let min_narrowed = match (bound(from, "min"), bound(to, "min")) {
+        (None, Some(_)) => true,
+        (Some(from), Some(to)) => to.numeric_cmp(from) == Some(Ordering::Greater),
+        _ => false,
+    };
+    let max_narrowed = match (bound(from, "max"), bound(to, "max")) {
+        (None, Some(_)) => true,
+        (Some(from), Some(to)) => to.numeric_cmp(from) == Some(Ordering::Less),
+        _ => false,
+    };
+    min_narrowed || max_narrowed
+}
+
+fn enum_trait_values(value: &Option<Value>) -> Vec<String> {
+    match value {
+        Some(Value::Array(definitions)) => definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Value::Object(map) => map.get("value").and_then(|v| v.as_string()).cloned(),
+                _ => None,
+            })
+            .collect(),
+        _ => Default::default(),
+    }
+}
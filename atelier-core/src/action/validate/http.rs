@@ -0,0 +1,513 @@
+use crate::action::{Action, ActionIssue, Validator};
+use crate::error::Result as ModelResult;
+use crate::model::conformance::resolve_shape;
+use crate::model::shapes::{
+    HasTraits, MemberShape, ShapeKind, Simple, StructureOrUnion, TopLevelShape,
+};
+use crate::model::traits::{
+    HttpHeaderTrait, HttpLabelTrait, HttpPayloadTrait, HttpQueryTrait, HttpTrait, TypedTrait,
+};
+use crate::model::{service_operations, sorted_shapes, HasIdentity, Identifier, Model, ShapeID};
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { CorrectHttpBindings, r#"This validator ensures that the HTTP binding
+traits applied to each operation, and the members of its input and output, are consistent.
+
+* The `uri` of the `@http` trait must be a valid pattern; it must start with `/`, each label must
+  be a complete path segment, `{name}` or the greedy form `{name+}`, label names must be unique
+  identifiers, there may be only one greedy label, and the query string must not contain labels.
+* Each label in the pattern must be bound to a `@required` member of the input structure, with the
+  same name, that has the `@httpLabel` trait applied. Each `@httpLabel` member must correspond to a
+  label in the pattern, and must target a boolean, number, string, or timestamp; a member bound to
+  a greedy label must target a string.
+* The input and output structures may each have at most one `@httpPayload` member.
+* `@httpHeader` and `@httpQuery` members must target a boolean, number, string, or timestamp, or a
+  list or set of these."# }
+
+linter_or_validator_defn! { NoHttpBindingConflicts, r#"This validator ensures that no two
+operations in the closure of a service have conflicting HTTP bindings.
+
+The operations of a service are those bound to it directly, and those bound to its resources and
+their child resources. Two operations conflict when they have the same HTTP method and their URI
+patterns may match the same request. A label conflicts with a literal path segment in the same
+position, and a greedy label conflicts with any path segments at or after it; patterns whose paths
+are otherwise equivalent conflict if they have the same literal query string."# }
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Label(String),
+    GreedyLabel(String),
+}
+
+#[derive(Clone, Debug)]
+struct UriPattern {
+    segments: Vec<Segment>,
+    query: BTreeSet<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { CorrectHttpBindings }
+
+linter_or_validator_action_impl! { CorrectHttpBindings, "CorrectHttpBindings" }
+
+impl Validator for CorrectHttpBindings {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        for shape in sorted_shapes(model) {
+            let operation = match shape.body() {
+                ShapeKind::Operation(operation) => operation,
+                _ => continue,
+            };
+            let http = match self.binding_trait::<HttpTrait>(shape.id(), shape.id(), shape) {
+                Some(http) => http,
+                None => continue,
+            };
+            let pattern = match UriPattern::from_str(http.uri()) {
+                Ok(pattern) => Some(pattern),
+                Err(reason) => {
+                    self.issues.push(ActionIssue::error_at(
+                        self.label(),
+                        &format!(
+                            "The URI pattern {} of operation {} is not valid, {}.",
+                            http.uri(),
+                            shape.id(),
+                            reason
+                        ),
+                        shape.id().clone(),
+                    ));
+                    None
+                }
+            };
+            let input = operation_structure(model, operation.input());
+            if let Some(pattern) = pattern {
+                self.check_labels(model, shape.id(), &pattern, input);
+            }
+            for (name, structure) in [
+                ("input", input),
+                ("output", operation_structure(model, operation.output())),
+            ] {
+                if let Some((structure_id, structure)) = structure {
+                    self.check_payload(shape.id(), name, structure_id, structure);
+                    self.check_headers_and_query(model, shape.id(), structure_id, structure);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CorrectHttpBindings {
+    fn check_labels(
+        &mut self,
+        model: &Model,
+        operation_id: &ShapeID,
+        pattern: &UriPattern,
+        input: Option<(&ShapeID, &StructureOrUnion)>,
+    ) {
+        let members: Vec<&MemberShape> = input
+            .map(|(_, structure)| sorted_members(structure))
+            .unwrap_or_default();
+        let member_id = |member: &MemberShape| match input {
+            Some((structure_id, _)) => structure_id.make_member(member.id().clone()),
+            None => operation_id.clone(),
+        };
+        let mut label_members: Vec<&MemberShape> = Default::default();
+        for member in members {
+            if self
+                .binding_trait::<HttpLabelTrait>(operation_id, &member_id(member), member)
+                .is_some()
+            {
+                label_members.push(member);
+            }
+        }
+        let mut labels: BTreeSet<String> = Default::default();
+        for segment in &pattern.segments {
+            let (label, greedy) = match segment {
+                Segment::Literal(_) => continue,
+                Segment::Label(label) => (label, false),
+                Segment::GreedyLabel(label) => (label, true),
+            };
+            let _ = labels.insert(label.clone());
+            match label_members.iter().find(|m| m.id().to_string() == *label) {
+                None => self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The label {} in the URI pattern of operation {} has no corresponding @httpLabel member in the input structure.",
+                        label, operation_id
+                    ),
+                    operation_id.clone(),
+                )),
+                Some(member) => {
+                    if !member.is_required() {
+                        self.issues.push(ActionIssue::error_at(
+                            self.label(),
+                            &format!(
+                                "The @httpLabel member {} of operation {} must have the @required trait applied.",
+                                member_id(member),
+                                operation_id
+                            ),
+                            operation_id.clone(),
+                        ));
+                    }
+                    let target = resolve_shape(model, member.target());
+                    if greedy && !target.map(is_string).unwrap_or(true) {
+                        self.issues.push(ActionIssue::error_at(
+                            self.label(),
+                            &format!(
+                                "The @httpLabel member {} of operation {} is bound to a greedy label and must target a string shape.",
+                                member_id(member),
+                                operation_id
+                            ),
+                            operation_id.clone(),
+                        ));
+                    } else if !target.map(is_scalar).unwrap_or(true) {
+                        self.issues.push(ActionIssue::error_at(
+                            self.label(),
+                            &format!(
+                                "The @httpLabel member {} of operation {} must target a boolean, number, string, or timestamp shape.",
+                                member_id(member),
+                                operation_id
+                            ),
+                            operation_id.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+        for member in label_members {
+            if !labels.contains(&member.id().to_string()) {
+                self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The @httpLabel member {} of operation {} does not correspond to a label in the URI pattern.",
+                        member_id(member),
+                        operation_id
+                    ),
+                    operation_id.clone(),
+                ));
+            }
+        }
+    }
+
+    fn check_payload(
+        &mut self,
+        operation_id: &ShapeID,
+        name: &str,
+        structure_id: &ShapeID,
+        structure: &StructureOrUnion,
+    ) {
+        let mut payloads: Vec<String> = Default::default();
+        for member in sorted_members(structure) {
+            let member_id = structure_id.make_member(member.id().clone());
+            if self
+                .binding_trait::<HttpPayloadTrait>(operation_id, &member_id, member)
+                .is_some()
+            {
+                payloads.push(member_id.to_string());
+            }
+        }
+        if payloads.len() > 1 {
+            self.issues.push(ActionIssue::error_at(
+                self.label(),
+                &format!(
+                    "The {} structure {} of operation {} has more than one @httpPayload member: {}.",
+                    name,
+                    structure_id,
+                    operation_id,
+                    payloads.join(", ")
+                ),
+                operation_id.clone(),
+            ));
+        }
+    }
+
+    fn check_headers_and_query(
+        &mut self,
+        model: &Model,
+        operation_id: &ShapeID,
+        structure_id: &ShapeID,
+        structure: &StructureOrUnion,
+    ) {
+        for member in sorted_members(structure) {
+            let member_id = structure_id.make_member(member.id().clone());
+            let bindings = [
+                (
+                    "httpHeader",
+                    self.binding_trait::<HttpHeaderTrait>(operation_id, &member_id, member)
+                        .is_some(),
+                ),
+                (
+                    "httpQuery",
+                    self.binding_trait::<HttpQueryTrait>(operation_id, &member_id, member)
+                        .is_some(),
+                ),
+            ];
+            for (trait_name, _) in bindings.iter().filter(|(_, applied)| *applied) {
+                let valid = match resolve_shape(model, member.target()) {
+                    None => true,
+                    Some(target) => match target.body() {
+                        ShapeKind::List(list) | ShapeKind::Set(list) => {
+                            resolve_shape(model, list.member().target())
+                                .map(is_scalar)
+                                .unwrap_or(true)
+                        }
+                        _ => is_scalar(target),
+                    },
+                };
+                if !valid {
+                    self.issues.push(ActionIssue::error_at(
+                        self.label(),
+                        &format!(
+                            "The @{} member {} of operation {} must target a boolean, number, string, or timestamp shape, or a list or set of these.",
+                            trait_name,
+                            member_id,
+                            operation_id
+                        ),
+                        operation_id.clone(),
+                    ));
+                }
+            }
+        }
+    }
+
+    ///
+    /// Return the value of the trait `T` applied to `shape`, if any. If the value is not valid an
+    /// issue is reported, and the trait treated as not applied, so that the remaining bindings are
+    /// still checked.
+    ///
+    fn binding_trait<T: TypedTrait>(
+        &mut self,
+        operation_id: &ShapeID,
+        shape_id: &ShapeID,
+        shape: &impl HasTraits,
+    ) -> Option<T> {
+        match shape.typed_trait::<T>() {
+            Ok(value) => value,
+            Err(_) => {
+                self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The value of the trait @{} applied to {} is not valid.",
+                        T::TRAIT_NAME,
+                        shape_id
+                    ),
+                    operation_id.clone(),
+                ));
+                None
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { NoHttpBindingConflicts }
+
+linter_or_validator_action_impl! { NoHttpBindingConflicts, "NoHttpBindingConflicts" }
+
+impl Validator for NoHttpBindingConflicts {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        for shape in sorted_shapes(model) {
+            if let ShapeKind::Service(service) = shape.body() {
+                let mut bindings: Vec<(&ShapeID, String, String, UriPattern)> = Default::default();
+                for operation_id in service_operations(model, service) {
+                    if let Some(Ok(Some(http))) = model
+                        .shape(operation_id)
+                        .filter(|operation| operation.body().is_operation())
+                        .map(|operation| operation.typed_trait::<HttpTrait>())
+                    {
+                        if let Ok(pattern) = UriPattern::from_str(http.uri()) {
+                            bindings.push((
+                                operation_id,
+                                http.method().to_uppercase(),
+                                http.uri().clone(),
+                                pattern,
+                            ));
+                        }
+                    }
+                }
+                for (i, (lhs_id, lhs_method, lhs_uri, lhs_pattern)) in bindings.iter().enumerate() {
+                    for (rhs_id, rhs_method, rhs_uri, rhs_pattern) in &bindings[i + 1..] {
+                        if lhs_method == rhs_method && lhs_pattern.conflicts_with(rhs_pattern) {
+                            self.issues.push(ActionIssue::error_at(
+                                self.label(),
+                                &format!(
+                                    "The operations {} ({} {}) and {} ({} {}) have conflicting HTTP bindings in the service {}.",
+                                    lhs_id, lhs_method, lhs_uri, rhs_id, rhs_method, rhs_uri, shape.id()
+                                ),
+                                shape.id().clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl FromStr for UriPattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = match s.strip_prefix('/') {
+            Some(path) => path,
+            None => return Err("it must start with '/'".to_string()),
+        };
+        if s.contains('#') {
+            return Err("it must not contain a fragment".to_string());
+        }
+        let (path, query) = match path.split_once('?') {
+            Some((path, query)) => (path, query),
+            None => (path, ""),
+        };
+        if query.contains(['{', '}']) {
+            return Err("labels are not allowed in the query string".to_string());
+        }
+
+        let mut segments: Vec<Segment> = Default::default();
+        if !path.is_empty() {
+            for segment in path.split('/') {
+                if segment.is_empty() {
+                    return Err("it must not contain empty path segments".to_string());
+                }
+                if !segment.contains(['{', '}']) {
+                    segments.push(Segment::Literal(segment.to_string()));
+                    continue;
+                }
+                let label = match segment
+                    .strip_prefix('{')
+                    .and_then(|segment| segment.strip_suffix('}'))
+                {
+                    Some(label) if !label.contains(['{', '}']) => label,
+                    _ => {
+                        return Err(format!(
+                            "the label in segment {} must be the entire segment",
+                            segment
+                        ))
+                    }
+                };
+                let (name, greedy) = match label.strip_suffix('+') {
+                    Some(name) => (name, true),
+                    None => (label, false),
+                };
+                if Identifier::from_str(name).is_err() {
+                    return Err(format!("the label {} is not a valid identifier", name));
+                }
+                if segments.iter().any(|segment| match segment {
+                    Segment::Label(other) | Segment::GreedyLabel(other) => other == name,
+                    _ => false,
+                }) {
+                    return Err(format!("the label {} appears more than once", name));
+                }
+                if greedy {
+                    if segments
+                        .iter()
+                        .any(|segment| matches!(segment, Segment::GreedyLabel(_)))
+                    {
+                        return Err("only one greedy label is allowed".to_string());
+                    }
+                    segments.push(Segment::GreedyLabel(name.to_string()));
+                } else {
+                    segments.push(Segment::Label(name.to_string()));
+                }
+            }
+        }
+
+        Ok(Self {
+            segments,
+            query: query
+                .split('&')
+                .filter(|param| !param.is_empty())
+                .map(str::to_string)
+                .collect(),
+        })
+    }
+}
+
+impl UriPattern {
+    ///
+    /// Two patterns conflict if a request could match both. Patterns of different lengths only
+    /// conflict if one has a greedy label, a label conflicts with a literal in the same position,
+    /// and a greedy label conflicts with any segments at or after it. Only if the paths are
+    /// equivalent are the query string literals compared.
+    ///
+    fn conflicts_with(&self, other: &Self) -> bool {
+        if self.segments.len() != other.segments.len()
+            && !(self.has_greedy_label() || other.has_greedy_label())
+        {
+            return false;
+        }
+        for (lhs, rhs) in self.segments.iter().zip(other.segments.iter()) {
+            match (lhs, rhs) {
+                (Segment::Literal(lhs), Segment::Literal(rhs)) => {
+                    if lhs != rhs {
+                        return false;
+                    }
+                }
+                (Segment::Literal(_), _) | (_, Segment::Literal(_)) => return true,
+                (Segment::GreedyLabel(_), _) | (_, Segment::GreedyLabel(_)) => return true,
+                (Segment::Label(_), Segment::Label(_)) => {}
+            }
+        }
+        self.query == other.query
+    }
+
+    fn has_greedy_label(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::GreedyLabel(_)))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn sorted_members(structure: &StructureOrUnion) -> Vec<&MemberShape> {
+    let mut members: Vec<&MemberShape> = structure.members().collect();
+    members.sort_by_key(|member| member.id());
+    members
+}
+
+fn operation_structure<'a>(
+    model: &'a Model,
+    shape_id: &'a Option<ShapeID>,
+) -> Option<(&'a ShapeID, &'a StructureOrUnion)> {
+    shape_id
+        .as_ref()
+        .and_then(|shape_id| resolve_shape(model, shape_id))
+        .and_then(|shape| match shape.body() {
+            ShapeKind::Structure(structure) => Some((shape.id(), structure)),
+            _ => None,
+        })
+}
+
+fn is_scalar(shape: &TopLevelShape) -> bool {
+    match shape.body() {
+        ShapeKind::Simple(Simple::Blob) | ShapeKind::Simple(Simple::Document) => false,
+        ShapeKind::Simple(_) | ShapeKind::Enum(_) | ShapeKind::IntEnum(_) => true,
+        _ => false,
+    }
+}
+
+fn is_string(shape: &TopLevelShape) -> bool {
+    matches!(
+        shape.body(),
+        ShapeKind::Simple(Simple::String) | ShapeKind::Enum(_)
+    )
+}
@@ -0,0 +1,294 @@
+use crate::action::{Action, ActionIssue, Validator};
+use crate::error::Result as ModelResult;
+use crate::model::conformance::resolve_shape;
+use crate::model::shapes::{HasTraits, MemberShape, Resource, ShapeKind, TopLevelShape};
+use crate::model::traits::{ResourceIdentifierTrait, TypedTrait};
+use crate::model::{body_members, sorted_shapes, HasIdentity, Identifier, Model, ShapeID};
+use std::collections::BTreeMap;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { CorrectOperationShapes, r#"This validator ensures that the shapes
+referenced by each operation are of the correct kind.
+
+The `input` and `output` of an operation must be structures, and each of the operation's `errors`
+must be a structure with the `@error` trait applied. References to shapes that cannot be resolved
+are left to `CorrectTypeReferences`."# }
+
+linter_or_validator_defn! { CorrectResourceBindings, r#"This validator ensures that each resource
+follows the lifecycle rules of the Smithy specification.
+
+* The `put` and `delete` lifecycle operations must have the `@idempotent` trait applied.
+* The `read` and `list` lifecycle operations must have the `@readonly` trait applied.
+* Each instance operation, that is `put`, `read`, `update`, `delete`, and those in `operations`,
+  must bind every identifier of the resource to a `@required` member of its input structure. The
+  member must either have the same name as the identifier, or a `@resourceIdentifier` trait naming
+  it, and must target the same shape as the identifier.
+* A resource must not be bound to more than one parent resource."# }
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { CorrectOperationShapes }
+
+linter_or_validator_action_impl! { CorrectOperationShapes, "CorrectOperationShapes" }
+
+impl Validator for CorrectOperationShapes {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        for shape in sorted_shapes(model) {
+            if let ShapeKind::Operation(operation) = shape.body() {
+                if let Some(input) = operation.input() {
+                    self.check_structure(model, shape.id(), input, "input");
+                }
+                if let Some(output) = operation.output() {
+                    self.check_structure(model, shape.id(), output, "output");
+                }
+                for error in operation.errors() {
+                    if let Some(target) = resolve_shape(model, error) {
+                        if !target.body().is_structure() || !target.is_error() {
+                            self.issues.push(ActionIssue::error_at(
+                                self.label(),
+                                &format!(
+                                    "The error {} of operation {} must be a structure with the @error trait applied.",
+                                    error,
+                                    shape.id()
+                                ),
+                                shape.id().clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CorrectOperationShapes {
+    fn check_structure(
+        &mut self,
+        model: &Model,
+        operation_id: &ShapeID,
+        target_id: &ShapeID,
+        member: &str,
+    ) {
+        if let Some(target) = resolve_shape(model, target_id) {
+            if !target.body().is_structure() {
+                self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The {} {} of operation {} must be a structure.",
+                        member, target_id, operation_id
+                    ),
+                    operation_id.clone(),
+                ));
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { CorrectResourceBindings }
+
+linter_or_validator_action_impl! { CorrectResourceBindings, "CorrectResourceBindings" }
+
+impl Validator for CorrectResourceBindings {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        let mut parents: BTreeMap<&ShapeID, Vec<&ShapeID>> = Default::default();
+        for shape in sorted_shapes(model) {
+            if let ShapeKind::Resource(resource) = shape.body() {
+                self.check_lifecycle(model, shape.id(), resource);
+                self.check_identifier_bindings(model, shape.id(), resource);
+                for child in resource.resources() {
+                    parents.entry(child).or_default().push(shape.id());
+                }
+            }
+        }
+        for (child, parents) in parents.into_iter().filter(|(_, p)| p.len() > 1) {
+            self.issues.push(ActionIssue::error_at(
+                self.label(),
+                &format!(
+                    "The resource {} is bound to more than one parent resource: {}.",
+                    child,
+                    parents
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                child.clone(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl CorrectResourceBindings {
+    fn check_lifecycle(&mut self, model: &Model, resource_id: &ShapeID, resource: &Resource) {
+        let lifecycle = [
+            ("put", resource.put(), true),
+            ("read", resource.read(), false),
+            ("delete", resource.delete(), true),
+            ("list", resource.list(), false),
+        ];
+        for (name, operation_id, idempotent) in lifecycle {
+            let operation = match operation_id.as_ref().and_then(|id| model.shape(id)) {
+                Some(operation) if operation.body().is_operation() => operation,
+                _ => continue,
+            };
+            let (valid, trait_name) = if idempotent {
+                (operation.is_idempotent(), "idempotent")
+            } else {
+                (operation.is_readonly(), "readonly")
+            };
+            if !valid {
+                self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The {} operation {} of resource {} must have the @{} trait applied.",
+                        name,
+                        operation.id(),
+                        resource_id,
+                        trait_name
+                    ),
+                    operation.id().clone(),
+                ));
+            }
+        }
+    }
+
+    fn check_identifier_bindings(
+        &mut self,
+        model: &Model,
+        resource_id: &ShapeID,
+        resource: &Resource,
+    ) {
+        if !resource.has_identifiers() {
+            return;
+        }
+        let mut identifiers: Vec<(&Identifier, &ShapeID)> = resource.identifiers().collect();
+        identifiers.sort();
+        let instance_operations = resource
+            .put()
+            .iter()
+            .chain(resource.read().iter())
+            .chain(resource.update().iter())
+            .chain(resource.delete().iter())
+            .chain(resource.operations());
+        for operation_id in instance_operations {
+            let operation = match model.shape(operation_id).map(|shape| shape.body()) {
+                Some(ShapeKind::Operation(operation)) => operation,
+                _ => continue,
+            };
+            let input = match operation
+                .input()
+                .as_ref()
+                .and_then(|id| resolve_shape(model, id))
+            {
+                Some(input) if input.body().is_structure() => input,
+                _ => {
+                    self.issues.push(ActionIssue::error_at(
+                        self.label(),
+                        &format!(
+                            "The instance operation {} of resource {} must have an input structure to bind the resource identifiers.",
+                            operation_id, resource_id
+                        ),
+                        operation_id.clone(),
+                    ));
+                    continue;
+                }
+            };
+            let members = self.resource_identifiers(input);
+            for (name, target) in &identifiers {
+                match identifier_member(&members, name) {
+                    None => self.issues.push(ActionIssue::error_at(
+                        self.label(),
+                        &format!(
+                            "The input of instance operation {} does not bind the identifier {} of resource {}.",
+                            operation_id, name, resource_id
+                        ),
+                        operation_id.clone(),
+                    )),
+                    Some(member) => {
+                        if member.target() != *target {
+                            self.issues.push(ActionIssue::error_at(
+                                self.label(),
+                                &format!(
+                                    "The input member {} of instance operation {} must target {}, the target of identifier {} of resource {}.",
+                                    member.id(), operation_id, target, name, resource_id
+                                ),
+                                operation_id.clone(),
+                            ));
+                        }
+                        if !member.is_required() {
+                            self.issues.push(ActionIssue::error_at(
+                                self.label(),
+                                &format!(
+                                    "The input member {} of instance operation {} binds the identifier {} of resource {} and must have the @required trait applied.",
+                                    member.id(), operation_id, name, resource_id
+                                ),
+                                operation_id.clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    ///
+    /// Return the members of the structure `input`, each with the name given by its
+    /// `@resourceIdentifier` trait, if any. If the trait value is not valid an issue is reported,
+    /// and the trait treated as not applied.
+    ///
+    fn resource_identifiers<'a>(
+        &mut self,
+        input: &'a TopLevelShape,
+    ) -> Vec<(&'a MemberShape, Option<String>)> {
+        let mut members: Vec<&MemberShape> = body_members(input.body());
+        members.sort_by_key(|member| member.id());
+        members
+            .into_iter()
+            .map(
+                |member| match member.typed_trait::<ResourceIdentifierTrait>() {
+                    Ok(value) => (member, value.map(|value| value.value().clone())),
+                    Err(_) => {
+                        self.issues.push(ActionIssue::error_at(
+                            self.label(),
+                            &format!(
+                                "The value of the trait @{} applied to {} is not valid.",
+                                ResourceIdentifierTrait::TRAIT_NAME,
+                                input.id().make_member(member.id().clone())
+                            ),
+                            input.id().clone(),
+                        ));
+                        (member, None)
+                    }
+                },
+            )
+            .collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the member bound to the identifier `name`, a member with a `@resourceIdentifier` trait
+/// naming the identifier takes precedence over one with the same name.
+///
+fn identifier_member<'a>(
+    members: &[(&'a MemberShape, Option<String>)],
+    name: &Identifier,
+) -> Option<&'a MemberShape> {
+    members
+        .iter()
+        .find(|(_, resource_identifier)| resource_identifier.as_ref() == Some(&name.to_string()))
+        .or_else(|| members.iter().find(|(member, _)| member.id() == name))
+        .map(|(member, _)| *member)
+}
@@ -9,7 +9,7 @@ use crate::action::{Action, ActionIssue, IssueLevel, Validator};
 use crate::error::Result as ModelResult;
 use crate::model::shapes::{HasTraits, ShapeKind};
 use crate::model::{HasIdentity, Identifier, Model, ShapeID};
-use crate::prelude::PRELUDE_NAMESPACE;
+use crate::prelude::prelude_definition;
 use crate::syntax::{MEMBER_ERRORS, MEMBER_INPUT, MEMBER_OUTPUT};
 
 // ------------------------------------------------------------------------------------------------
@@ -30,7 +30,8 @@ is complete; the model contains no unresolved shape references."# }
 
 ///
 /// Run each provided `Validator`, in order, against the provided `Model`. All issues will be
-/// collated and returned together.
+/// collated and returned together, with the source location of each issue's locus added where
+/// it is known.
 ///
 /// The `fail_fast` flag determines the behavior if a validation action returns an error. If `true`  
 /// the process stops and returns all reported issues up to that point, if `false` it continues on.
@@ -45,6 +46,9 @@ pub fn run_validation_actions(
     for validator in validators.iter_mut() {
         validator.validate(model)?;
         let new_issues = validator.issues_mut();
+        for issue in new_issues.iter_mut() {
+            issue.locate_in(model);
+        }
         issues.append(new_issues);
         if fail_fast
             && new_issues
@@ -229,7 +233,7 @@ impl CorrectTypeReferences {
                     shape.clone(),
                 ));
             }
-        } else if target_id.namespace().to_string() != PRELUDE_NAMESPACE {
+        } else if prelude_definition(target_id).is_none() {
             self.issues.push(ActionIssue::warning_at(
                 self.label(),
                 &format!(
@@ -257,7 +261,7 @@ impl CorrectTypeReferences {
                     shape.clone(),
                 ));
             }
-        } else if target.namespace().to_string() != PRELUDE_NAMESPACE {
+        } else if prelude_definition(target).is_none() {
             self.issues.push(ActionIssue::warning_at(
                 self.label(),
                 &format!(
@@ -285,7 +289,7 @@ impl CorrectTypeReferences {
                     shape.clone(),
                 ));
             }
-        } else if target.namespace().to_string() != PRELUDE_NAMESPACE {
+        } else if prelude_definition(target).is_none() {
             self.issues.push(ActionIssue::warning_at(
                 self.label(),
                 &format!(
@@ -307,7 +311,9 @@ linter_or_validator_action_impl! { NoUnresolvedReferences, "NoUnresolvedReferenc
 impl Validator for NoUnresolvedReferences {
     fn validate(&mut self, model: &Model) -> ModelResult<()> {
         for shape_id in model.shapes().filter_map(|shape| match shape.body() {
-            ShapeKind::Unresolved => Some(shape.id().to_string()),
+            ShapeKind::Unresolved if prelude_definition(shape.id()).is_none() => {
+                Some(shape.id().to_string())
+            }
             _ => None,
         }) {
             self.issues.push(ActionIssue::error(
@@ -321,3 +327,35 @@ impl Validator for NoUnresolvedReferences {
         Ok(())
     }
 }
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+#[doc(hidden)]
+pub mod compatibility;
+pub use compatibility::BackwardCompatibility;
+
+#[doc(hidden)]
+pub mod http;
+pub use http::{CorrectHttpBindings, NoHttpBindingConflicts};
+
+#[doc(hidden)]
+pub mod lifecycle;
+pub use lifecycle::{CorrectOperationShapes, CorrectResourceBindings};
+
+#[doc(hidden)]
+pub mod paginated;
+pub use paginated::CorrectPaginatedTraits;
+
+#[doc(hidden)]
+pub mod recursion;
+pub use recursion::NoIllegalRecursion;
+
+#[doc(hidden)]
+pub mod service_closure;
+pub use service_closure::NoServiceNameConflicts;
+
+#[doc(hidden)]
+pub mod trait_values;
+pub use trait_values::CorrectTraitValues;
@@ -0,0 +1,274 @@
+use crate::action::{Action, ActionIssue, Validator};
+use crate::error::Result as ModelResult;
+use crate::model::conformance::resolve_shape;
+use crate::model::paginated::{PaginatedIndex, PaginationInfo};
+use crate::model::shapes::{HasTraits, MemberShape, ShapeKind, Simple, TopLevelShape};
+use crate::model::traits::{PaginatedTrait, TypedTrait};
+use crate::model::{sorted_shapes, HasIdentity, Model, ShapeID};
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { CorrectPaginatedTraits, r#"This validator ensures that the values of
+the `@paginated` trait, merged from the service and operation as described by `PaginatedIndex`,
+refer to suitable members of each operation's input and output.
+
+* Both `inputToken` and `outputToken` must be specified.
+* `inputToken` must name a member of the input structure that targets a string.
+* `pageSize`, if specified, must name a member of the input structure that targets an integer.
+* The `inputToken` and `pageSize` members must not have the `@required` trait applied.
+* `outputToken` must be a path to a member, starting from the output structure, that targets a
+  string.
+* `items`, if specified, must be a path to a member, starting from the output structure, that
+  targets a list, set, or map.
+
+A paginated operation bound to one or more services is checked within each of them; otherwise it is
+checked using only its own trait value. A service or operation whose `@paginated` trait value is not
+valid is reported, and the trait is treated as not applied."# }
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The operation structure that a `@paginated` member is resolved within.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Structure {
+    Input,
+    Output,
+}
+
+///
+/// A single member of the `@paginated` trait, and the targets acceptable for the member it resolves
+/// to.
+///
+struct PaginatedCheck<'a> {
+    name: &'static str,
+    value: &'a Option<String>,
+    member_id: Option<&'a ShapeID>,
+    structure: Structure,
+    required: bool,
+    expected: &'static str,
+    accepts: fn(&ShapeKind) -> bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Structure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Structure::Input => "input",
+                Structure::Output => "output",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { CorrectPaginatedTraits }
+
+linter_or_validator_action_impl! { CorrectPaginatedTraits, "CorrectPaginatedTraits" }
+
+impl Validator for CorrectPaginatedTraits {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        for shape in sorted_shapes(model)
+            .into_iter()
+            .filter(|shape| shape.is_service() || shape.is_operation())
+        {
+            if shape.typed_trait::<PaginatedTrait>().is_err() {
+                self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The value of the trait @{} applied to {} is not valid.",
+                        PaginatedTrait::TRAIT_NAME,
+                        shape.id()
+                    ),
+                    shape.id().clone(),
+                ));
+            }
+        }
+        let index = PaginatedIndex::from(model);
+        let bound: BTreeSet<&ShapeID> = index
+            .pagination_infos()
+            .filter(|info| info.service().is_some())
+            .map(|info| info.operation())
+            .collect();
+        for info in index
+            .pagination_infos()
+            .filter(|info| info.service().is_some() || !bound.contains(info.operation()))
+        {
+            self.check_info(model, info);
+        }
+        Ok(())
+    }
+}
+
+impl CorrectPaginatedTraits {
+    fn check_info(&mut self, model: &Model, info: &PaginationInfo) {
+        let context = match info.service() {
+            Some(service_id) => format!(" in the service {}", service_id),
+            None => String::new(),
+        };
+        let paginated = info.paginated();
+        let checks = [
+            PaginatedCheck {
+                name: "inputToken",
+                value: paginated.input_token(),
+                member_id: info.input_token_member().as_ref(),
+                structure: Structure::Input,
+                required: true,
+                expected: "a string",
+                accepts: is_string,
+            },
+            PaginatedCheck {
+                name: "pageSize",
+                value: paginated.page_size(),
+                member_id: info.page_size_member().as_ref(),
+                structure: Structure::Input,
+                required: false,
+                expected: "an integer",
+                accepts: is_integer,
+            },
+            PaginatedCheck {
+                name: "outputToken",
+                value: paginated.output_token(),
+                member_id: info.output_token_member(),
+                structure: Structure::Output,
+                required: true,
+                expected: "a string",
+                accepts: is_string,
+            },
+            PaginatedCheck {
+                name: "items",
+                value: paginated.items(),
+                member_id: info.items_member(),
+                structure: Structure::Output,
+                required: false,
+                expected: "a list, set, or map",
+                accepts: is_collection,
+            },
+        ];
+        for check in checks {
+            let PaginatedCheck {
+                name,
+                value,
+                member_id,
+                structure,
+                required,
+                expected,
+                accepts,
+            } = check;
+            match (value, member_id) {
+                (None, _) => {
+                    if required {
+                        self.issues.push(ActionIssue::error_at(
+                            self.label(),
+                            &format!(
+                                "The paginated operation {}{} must specify {}.",
+                                info.operation(),
+                                context,
+                                name
+                            ),
+                            info.operation().clone(),
+                        ));
+                    }
+                }
+                (Some(value), None) => self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The {} value {} of paginated operation {}{} does not resolve to a member of the {} structure.",
+                        name,
+                        value,
+                        info.operation(),
+                        context,
+                        structure
+                    ),
+                    info.operation().clone(),
+                )),
+                (Some(_), Some(member_id)) => {
+                    // a client omits these to request the first page, or the default page size.
+                    let required = match structure {
+                        Structure::Input => member_shape(model, member_id)
+                            .map(|member| member.is_required())
+                            .unwrap_or(false),
+                        Structure::Output => false,
+                    };
+                    if required {
+                        self.issues.push(ActionIssue::error_at(
+                            self.label(),
+                            &format!(
+                                "The {} member {} of paginated operation {}{} must not have the @required trait applied.",
+                                name,
+                                member_id,
+                                info.operation(),
+                                context
+                            ),
+                            info.operation().clone(),
+                        ));
+                    }
+                    let valid = member_target(model, member_id)
+                        .map(|target| accepts(target.body()))
+                        .unwrap_or(true);
+                    if !valid {
+                        self.issues.push(ActionIssue::error_at(
+                            self.label(),
+                            &format!(
+                                "The {} member {} of paginated operation {}{} must target {} shape.",
+                                name,
+                                member_id,
+                                info.operation(),
+                                context,
+                                expected
+                            ),
+                            info.operation().clone(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_string(body: &ShapeKind) -> bool {
+    matches!(body, ShapeKind::Simple(Simple::String) | ShapeKind::Enum(_))
+}
+
+fn is_integer(body: &ShapeKind) -> bool {
+    matches!(
+        body,
+        ShapeKind::Simple(Simple::Integer) | ShapeKind::IntEnum(_)
+    )
+}
+
+fn is_collection(body: &ShapeKind) -> bool {
+    matches!(
+        body,
+        ShapeKind::List(_) | ShapeKind::Set(_) | ShapeKind::Map(_)
+    )
+}
+
+fn member_shape<'a>(model: &'a Model, member_id: &ShapeID) -> Option<&'a MemberShape> {
+    let member_name = member_id.member_name().as_ref()?;
+    match resolve_shape(model, &member_id.shape_only())?.body() {
+        ShapeKind::Structure(structure) => structure.member(member_name),
+        _ => None,
+    }
+}
+
+fn member_target<'a>(model: &'a Model, member_id: &ShapeID) -> Option<&'a TopLevelShape> {
+    resolve_shape(model, member_shape(model, member_id)?.target())
+}
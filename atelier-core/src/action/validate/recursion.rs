@@ -0,0 +1,217 @@
+use crate::action::{Action, ActionIssue, Validator};
+use crate::error::Result as ModelResult;
+use crate::model::shapes::{HasTraits, ShapeKind};
+use crate::model::{body_members, HasIdentity, Model, ShapeID};
+use crate::syntax::{SHAPE_LIST, SHAPE_MAP, SHAPE_SET};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { NoIllegalRecursion, r#"This validator finds recursive definitions
+through aggregate shapes; structures, unions, lists, sets, and maps.
+
+A cycle in which every step is a `@required` member of a structure describes a value that can
+never be instantiated, and is reported as an error. A cycle that passes through a list, set, or
+map, an optional structure member, or a union member can be terminated and so is reported only
+as an informational issue, which names the shape or member that breaks the cycle. Each issue
+includes the full path of the cycle as a sequence of member identifiers."# }
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+enum StepKind {
+    Required,
+    Optional,
+    Union,
+    Collection(&'static str),
+}
+
+#[derive(Clone, Debug)]
+struct Step<'a> {
+    source: &'a ShapeID,
+    member_id: ShapeID,
+    target: &'a ShapeID,
+    kind: StepKind,
+}
+
+type Steps<'a> = BTreeMap<&'a ShapeID, Vec<Step<'a>>>;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { NoIllegalRecursion }
+
+linter_or_validator_action_impl! { NoIllegalRecursion, "NoIllegalRecursion" }
+
+impl Validator for NoIllegalRecursion {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        let steps = aggregate_steps(model);
+
+        let mut reported: BTreeSet<Vec<ShapeID>> = Default::default();
+        let mut illegal: BTreeSet<&ShapeID> = Default::default();
+        for shape_id in steps.keys() {
+            if let Some(cycle) =
+                shortest_cycle(&steps, shape_id, |step| step.kind == StepKind::Required)
+            {
+                illegal.extend(cycle.iter().map(|step| step.source));
+                if reported.insert(cycle_key(&cycle)) {
+                    self.issues.push(ActionIssue::error_at(
+                        self.label(),
+                        &format!(
+                            "The shape {} is recursive through required members only, and so cannot be instantiated: {}.",
+                            shape_id,
+                            cycle_path(&cycle)
+                        ),
+                        (*shape_id).clone(),
+                    ));
+                }
+            }
+        }
+
+        for shape_id in steps.keys().filter(|shape_id| !illegal.contains(*shape_id)) {
+            if let Some(cycle) = shortest_cycle(&steps, shape_id, |_| true) {
+                if reported.insert(cycle_key(&cycle)) {
+                    let broken_by = cycle
+                        .iter()
+                        .find(|step| step.kind != StepKind::Required)
+                        .map(|step| match &step.kind {
+                            StepKind::Collection(kind) => format!("the {} {}", kind, step.source),
+                            StepKind::Union => format!("the union member {}", step.member_id),
+                            _ => format!("the optional member {}", step.member_id),
+                        })
+                        .unwrap_or_default();
+                    self.issues.push(ActionIssue::info_at(
+                        self.label(),
+                        &format!(
+                            "The shape {} is recursive, the cycle is broken by {}: {}.",
+                            shape_id,
+                            broken_by,
+                            cycle_path(&cycle)
+                        ),
+                        (*shape_id).clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return, for each aggregate shape in the model, the steps from its members to the aggregate
+/// shapes they target.
+///
+fn aggregate_steps(model: &Model) -> Steps<'_> {
+    let mut steps: Steps<'_> = Default::default();
+    for shape in model.shapes() {
+        let step_kind = |required: bool| match shape.body() {
+            ShapeKind::List(_) => StepKind::Collection(SHAPE_LIST),
+            ShapeKind::Set(_) => StepKind::Collection(SHAPE_SET),
+            ShapeKind::Map(_) => StepKind::Collection(SHAPE_MAP),
+            ShapeKind::Union(_) => StepKind::Union,
+            _ if required => StepKind::Required,
+            _ => StepKind::Optional,
+        };
+        let shape_steps: Vec<Step<'_>> = body_members(shape.body())
+            .into_iter()
+            .filter_map(|member| match model.shape(member.target()) {
+                Some(target) if is_aggregate(target.body()) => Some(Step {
+                    source: shape.id(),
+                    member_id: shape.id().make_member(member.id().clone()),
+                    target: target.id(),
+                    kind: step_kind(member.is_required()),
+                }),
+                _ => None,
+            })
+            .collect();
+        if is_aggregate(shape.body()) && !shape_steps.is_empty() {
+            let _ = steps.insert(shape.id(), shape_steps);
+        }
+    }
+    for shape_steps in steps.values_mut() {
+        shape_steps.sort_by(|lhs, rhs| lhs.member_id.cmp(&rhs.member_id));
+    }
+    steps
+}
+
+fn is_aggregate(body: &ShapeKind) -> bool {
+    matches!(
+        body,
+        ShapeKind::List(_)
+            | ShapeKind::Set(_)
+            | ShapeKind::Map(_)
+            | ShapeKind::Structure(_)
+            | ShapeKind::Union(_)
+    )
+}
+
+///
+/// Find the shortest cycle, using only those steps for which `allowed` returns `true`, that
+/// starts and ends at `start`.
+///
+fn shortest_cycle<'a>(
+    steps: &'a Steps<'a>,
+    start: &'a ShapeID,
+    allowed: impl Fn(&Step<'a>) -> bool,
+) -> Option<Vec<&'a Step<'a>>> {
+    let mut arrived_by: BTreeMap<&ShapeID, &Step<'a>> = Default::default();
+    let mut pending: VecDeque<&ShapeID> = VecDeque::from(vec![start]);
+    while let Some(shape_id) = pending.pop_front() {
+        for step in steps.get(shape_id).into_iter().flatten() {
+            if !allowed(step) {
+                continue;
+            }
+            if step.target == start {
+                let mut cycle = vec![step];
+                let mut current = step.source;
+                while current != start {
+                    let previous = arrived_by[current];
+                    cycle.push(previous);
+                    current = previous.source;
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+            if !arrived_by.contains_key(step.target) {
+                let _ = arrived_by.insert(step.target, step);
+                pending.push_back(step.target);
+            }
+        }
+    }
+    None
+}
+
+///
+/// The same cycle may be found from any of the shapes within it, so the key is the list of member
+/// identifiers rotated to start with the smallest.
+///
+fn cycle_key(cycle: &[&Step<'_>]) -> Vec<ShapeID> {
+    let mut key: Vec<ShapeID> = cycle.iter().map(|step| step.member_id.clone()).collect();
+    let smallest = key
+        .iter()
+        .enumerate()
+        .min_by(|(_, lhs), (_, rhs)| lhs.cmp(rhs))
+        .map(|(index, _)| index)
+        .unwrap_or_default();
+    key.rotate_left(smallest);
+    key
+}
+
+fn cycle_path(cycle: &[&Step<'_>]) -> String {
+    cycle
+        .iter()
+        .map(|step| step.member_id.to_string())
+        .chain(cycle.last().map(|step| step.target.to_string()))
+        .collect::<Vec<String>>()
+        .join(" -> ")
+}
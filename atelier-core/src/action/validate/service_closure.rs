@@ -0,0 +1,135 @@
+use crate::action::{Action, ActionIssue, Validator};
+use crate::error::Result as ModelResult;
+use crate::model::neighbor::{NeighborIndex, RelationshipKind};
+use crate::model::shapes::{Service, ShapeKind, TopLevelShape};
+use crate::model::{HasIdentity, Model, ShapeID};
+use crate::prelude::PRELUDE_NAMESPACE;
+use std::collections::{BTreeMap, BTreeSet};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { NoServiceNameConflicts, r#"This validator ensures that the names of
+shapes in the closure of each service are unique.
+
+The closure of a service is every shape reachable from it by following its operations and
+resources, resource lifecycle operations and identifiers, operation inputs, outputs, and errors,
+members and their targets, and mixins. Any names provided in the service's `rename` property are
+applied, and then an error is reported for each set of shapes whose names are equal when compared
+case-insensitively. Shapes in the prelude are not checked. An error is also reported for each
+entry in `rename` that refers to a shape not in the closure of the service."# }
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { NoServiceNameConflicts }
+
+linter_or_validator_action_impl! { NoServiceNameConflicts, "NoServiceNameConflicts" }
+
+impl Validator for NoServiceNameConflicts {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        let index = NeighborIndex::from(model);
+        let mut services: Vec<&TopLevelShape> =
+            model.shapes().filter(|shape| shape.is_service()).collect();
+        services.sort_by_key(|shape| shape.id());
+        for shape in services {
+            if let ShapeKind::Service(service) = shape.body() {
+                let closure = service_closure(model, &index, shape.id());
+                self.check_renames(shape.id(), service, &closure);
+                self.check_conflicts(shape.id(), service, &closure);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl NoServiceNameConflicts {
+    fn check_renames(
+        &mut self,
+        service_id: &ShapeID,
+        service: &Service,
+        closure: &BTreeSet<ShapeID>,
+    ) {
+        let mut renames: Vec<(&ShapeID, _)> = service.renames().collect();
+        renames.sort();
+        for (shape_id, local_name) in renames {
+            if !closure.contains(shape_id) {
+                self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The service {} renames the shape {} to {}, but that shape is not in the closure of the service.",
+                        service_id, shape_id, local_name
+                    ),
+                    service_id.clone(),
+                ));
+            }
+        }
+    }
+
+    fn check_conflicts(
+        &mut self,
+        service_id: &ShapeID,
+        service: &Service,
+        closure: &BTreeSet<ShapeID>,
+    ) {
+        let mut names: BTreeMap<String, Vec<&ShapeID>> = Default::default();
+        for shape_id in closure {
+            let name = match service.contains_rename_shape(shape_id) {
+                Some(local_name) => local_name.to_string(),
+                None => shape_id.shape_name().to_string(),
+            };
+            names.entry(name.to_lowercase()).or_default().push(shape_id);
+        }
+        for shape_ids in names.values().filter(|shape_ids| shape_ids.len() > 1) {
+            self.issues.push(ActionIssue::error_at(
+                self.label(),
+                &format!(
+                    "The shapes {} have conflicting names in the closure of the service {}, consider adding a rename to the service.",
+                    shape_ids
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    service_id
+                ),
+                service_id.clone(),
+            ));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the top-level shape identifiers, excluding those in the prelude, reachable from
+/// `service_id`; trait relationships are not followed.
+///
+fn service_closure(
+    model: &Model,
+    index: &NeighborIndex,
+    service_id: &ShapeID,
+) -> BTreeSet<ShapeID> {
+    let mut visited: BTreeSet<ShapeID> = Default::default();
+    let mut closure: BTreeSet<ShapeID> = Default::default();
+    let mut pending = vec![service_id.clone()];
+    while let Some(shape_id) = pending.pop() {
+        if !visited.insert(shape_id.clone()) {
+            continue;
+        }
+        pending.extend(
+            index
+                .relationships_from(&shape_id)
+                .filter(|rel| *rel.kind() != RelationshipKind::Trait)
+                .map(|rel| rel.target().clone()),
+        );
+        let shape_only = shape_id.shape_only();
+        if shape_only.namespace().to_string() != PRELUDE_NAMESPACE && model.has_shape(&shape_only) {
+            let _ = closure.insert(shape_only);
+        }
+    }
+    closure
+}
@@ -0,0 +1,104 @@
+use crate::action::{Action, ActionIssue, Validator};
+use crate::error::Result as ModelResult;
+use crate::model::conformance::{check_shape_value, resolve_shape};
+use crate::model::shapes::{AppliedTraits, HasTraits};
+use crate::model::values::Value;
+use crate::model::{body_members, HasIdentity, Model, ShapeID};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_defn! { CorrectTraitValues, r#"This validator ensures that the value of each
+applied trait conforms to the trait's definition.
+
+Each applied trait is resolved to its definition shape, either a shape in the model marked with the
+`@trait` trait, or one of the traits defined in the Smithy prelude. The applied value is then checked
+against the definition; that it has the correct type, that any structure has only the members
+defined, that all `@required` members are present, and that the value satisfies any constraint
+traits on the definition."# }
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+linter_or_validator_default_impl! { CorrectTraitValues }
+
+linter_or_validator_action_impl! { CorrectTraitValues, "CorrectTraitValues" }
+
+impl Validator for CorrectTraitValues {
+    fn validate(&mut self, model: &Model) -> ModelResult<()> {
+        for shape in model.shapes() {
+            self.check_applied_traits(model, shape.id(), shape.traits());
+            for member in body_members(shape.body()) {
+                self.check_applied_traits(
+                    model,
+                    &shape.id().make_member(member.id().clone()),
+                    member.traits(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CorrectTraitValues {
+    fn check_applied_traits(&mut self, model: &Model, locus: &ShapeID, traits: &AppliedTraits) {
+        for (trait_id, value) in traits {
+            let definition = match resolve_shape(model, trait_id) {
+                None => continue,
+                Some(definition) => definition,
+            };
+            if !definition.is_trait() {
+                self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &format!(
+                        "The shape {} applied to {} is not a trait, it has no @trait trait.",
+                        trait_id, locus
+                    ),
+                    locus.clone(),
+                ));
+                continue;
+            }
+            let violations = match value {
+                None if definition.is_structure() => {
+                    check_shape_value(model, &Value::Object(Default::default()), definition)
+                }
+                None => {
+                    self.issues.push(ActionIssue::error_at(
+                        self.label(),
+                        &format!(
+                            "The value of trait @{} applied to {} is invalid: a value is required.",
+                            trait_id, locus
+                        ),
+                        locus.clone(),
+                    ));
+                    continue;
+                }
+                Some(value) => check_shape_value(model, value, definition),
+            };
+            for violation in violations {
+                self.issues.push(ActionIssue::error_at(
+                    self.label(),
+                    &if violation.path().is_empty() {
+                        format!(
+                            "The value of trait @{} applied to {} is invalid: {}.",
+                            trait_id,
+                            locus,
+                            violation.message()
+                        )
+                    } else {
+                        format!(
+                            "The value of trait @{} applied to {} is invalid at `{}`: {}.",
+                            trait_id,
+                            locus,
+                            violation.path(),
+                            violation.message()
+                        )
+                    },
+                    locus.clone(),
+                ));
+            }
+        }
+    }
+}
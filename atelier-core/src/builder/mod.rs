@@ -19,13 +19,14 @@ For more information, see [the Rust Atelier book](https://rust-atelier.dev/using
 use crate::builder::id::ShapeName;
 use crate::error::{Error, ErrorKind};
 use crate::model::shapes::{
-    ListOrSet, Map, MemberShape, Operation, Resource, Service, ShapeKind, StructureOrUnion,
-    TopLevelShape,
+    Enumeration, HasTraits, ListOrSet, Map, MemberShape, Operation, Resource, Service, ShapeKind,
+    StructureOrUnion, TopLevelShape,
 };
 use crate::model::values::{Value, ValueMap};
-use crate::model::{Identifier, Model, NamespaceID, ShapeID};
+use crate::model::{HasSourceLocation, Identifier, Model, NamespaceID, ShapeID, SourceLocation};
 use crate::prelude::{
     defined_prelude_shapes, defined_prelude_traits, prelude_namespace_id, PRELUDE_NAMESPACE,
+    TRAIT_ENUMVALUE,
 };
 use crate::syntax::{MEMBER_KEY, MEMBER_MEMBER, MEMBER_VALUE};
 use crate::Version;
@@ -61,6 +62,8 @@ enum TopLevelShapeBuilder {
     Map(MapBuilder),
     Structure(StructureBuilder),
     Union(StructureBuilder),
+    Enum(EnumBuilder),
+    IntEnum(EnumBuilder),
     Service(ServiceBuilder),
     Operation(OperationBuilder),
     Resource(ResourceBuilder),
@@ -162,6 +165,22 @@ impl ModelBuilder {
         )
     }
 
+    /// Create and add a new enum shape to this model using the `EnumBuilder` instance.
+    pub fn enum_shape(&mut self, builder: EnumBuilder) -> &mut Self {
+        self.insert(
+            builder.shape_name.clone(),
+            TopLevelShapeBuilder::Enum(builder),
+        )
+    }
+
+    /// Create and add a new intEnum shape to this model using the `EnumBuilder` instance.
+    pub fn int_enum(&mut self, builder: EnumBuilder) -> &mut Self {
+        self.insert(
+            builder.shape_name.clone(),
+            TopLevelShapeBuilder::IntEnum(builder),
+        )
+    }
+
     /// Create and add a new service shape to this model using the `ServiceBuilder` instance.
     pub fn service(&mut self, builder: ServiceBuilder) -> &mut Self {
         self.insert(builder.shape_name.clone(), builder.into())
@@ -352,7 +371,7 @@ impl ModelBuilder {
         builder: &TopLevelShapeBuilder,
         references: &mut HashSet<ShapeID>,
     ) -> Result<TopLevelShape, Error> {
-        Ok(match builder {
+        let mut shape = match builder {
             TopLevelShapeBuilder::SimpleShape(builder) => {
                 self.make_simple_shape(builder, references)?
             }
@@ -361,11 +380,15 @@ impl ModelBuilder {
             TopLevelShapeBuilder::Map(builder) => self.make_map(builder, references)?,
             TopLevelShapeBuilder::Structure(builder) => self.make_structure(builder, references)?,
             TopLevelShapeBuilder::Union(builder) => self.make_union(builder, references)?,
+            TopLevelShapeBuilder::Enum(builder) => self.make_enum(builder, references)?,
+            TopLevelShapeBuilder::IntEnum(builder) => self.make_int_enum(builder, references)?,
             TopLevelShapeBuilder::Service(builder) => self.make_service(builder, references)?,
             TopLevelShapeBuilder::Operation(builder) => self.make_operation(builder, references)?,
             TopLevelShapeBuilder::Resource(builder) => self.make_resource(builder, references)?,
             TopLevelShapeBuilder::Reference(builder) => self.make_reference(builder, references)?,
-        })
+        };
+        self.make_locations(&mut shape, builder.location(), builder.applied_traits())?;
+        Ok(shape)
     }
 
     fn make_simple_shape(
@@ -399,11 +422,11 @@ impl ModelBuilder {
         }
         Ok(TopLevelShape::with_traits(
             shape_id,
-            ShapeKind::List(ListOrSet::from(MemberShape::with_traits(
-                builder.member.member_name.clone(),
+            ShapeKind::List(ListOrSet::from(self.make_member(
+                &builder.member,
                 target_id,
-                self.make_traits(&builder.member.applied_traits, references)?,
-            ))),
+                references,
+            )?)),
             self.make_traits(&builder.applied_traits, references)?,
         ))
     }
@@ -423,11 +446,11 @@ impl ModelBuilder {
         }
         Ok(TopLevelShape::with_traits(
             shape_id,
-            ShapeKind::List(ListOrSet::from(MemberShape::with_traits(
-                builder.member.member_name.clone(),
+            ShapeKind::Set(ListOrSet::from(self.make_member(
+                &builder.member,
                 target_id,
-                self.make_traits(&builder.member.applied_traits, references)?,
-            ))),
+                references,
+            )?)),
             self.make_traits(&builder.applied_traits, references)?,
         ))
     }
@@ -453,16 +476,8 @@ impl ModelBuilder {
         Ok(TopLevelShape::with_traits(
             shape_id,
             ShapeKind::Map(Map::from(
-                MemberShape::with_traits(
-                    builder.key.member_name.clone(),
-                    key_target_id,
-                    self.make_traits(&builder.key.applied_traits, references)?,
-                ),
-                MemberShape::with_traits(
-                    builder.value.member_name.clone(),
-                    value_target_id,
-                    self.make_traits(&builder.value.applied_traits, references)?,
-                ),
+                self.make_member(&builder.key, key_target_id, references)?,
+                self.make_member(&builder.value, value_target_id, references)?,
             )),
             self.make_traits(&builder.applied_traits, references)?,
         ))
@@ -482,11 +497,7 @@ impl ModelBuilder {
                 if new_reference {
                     let _ = references.insert(member_target_id.clone());
                 }
-                Ok(MemberShape::with_traits(
-                    mb.member_name.clone(),
-                    member_target_id,
-                    self.make_traits(&mb.applied_traits, references)?,
-                ))
+                self.make_member(mb, member_target_id, references)
             })
             .collect();
         members.map(|members| StructureOrUnion::with_members(&members))
@@ -501,11 +512,13 @@ impl ModelBuilder {
         if new_reference {
             let _ = references.insert(shape_id.clone());
         }
-        Ok(TopLevelShape::with_traits(
+        let mut shape = TopLevelShape::with_traits(
             shape_id,
             ShapeKind::Structure(self.make_structure_inner(builder, references)?),
             self.make_traits(&builder.applied_traits, references)?,
-        ))
+        );
+        self.make_mixins(&mut shape, &builder.mixins, references)?;
+        Ok(shape)
     }
 
     fn make_union(
@@ -517,10 +530,86 @@ impl ModelBuilder {
         if new_reference {
             let _ = references.insert(shape_id.clone());
         }
-        Ok(TopLevelShape::with_traits(
+        let mut shape = TopLevelShape::with_traits(
             shape_id,
             ShapeKind::Union(self.make_structure_inner(builder, references)?),
             self.make_traits(&builder.applied_traits, references)?,
+        );
+        self.make_mixins(&mut shape, &builder.mixins, references)?;
+        Ok(shape)
+    }
+
+    fn make_mixins(
+        &self,
+        shape: &mut TopLevelShape,
+        mixins: &[ShapeName],
+        references: &mut HashSet<ShapeID>,
+    ) -> Result<(), Error> {
+        for mixin in mixins {
+            let (mixin_id, new_reference) = self.resolve_shape_name(mixin, false)?;
+            if new_reference {
+                let _ = references.insert(mixin_id.clone());
+            }
+            shape.add_mixin(mixin_id);
+        }
+        Ok(())
+    }
+
+    fn make_enum_inner(
+        &self,
+        builder: &EnumBuilder,
+        references: &mut HashSet<ShapeID>,
+    ) -> Result<Enumeration, Error> {
+        let members: Result<Vec<MemberShape>, Error> = builder
+            .members
+            .iter()
+            .map(|mb| {
+                let (member_target_id, _) = self.resolve_shape_name(&mb.target, false)?;
+                self.make_member(mb, member_target_id, references)
+            })
+            .collect();
+        members.map(|members| Enumeration::with_members(&members))
+    }
+
+    fn make_enum(
+        &self,
+        builder: &EnumBuilder,
+        references: &mut HashSet<ShapeID>,
+    ) -> Result<TopLevelShape, Error> {
+        let (shape_id, new_reference) = self.resolve_shape_name(&builder.shape_name, false)?;
+        if new_reference {
+            let _ = references.insert(shape_id.clone());
+        }
+        Ok(TopLevelShape::with_traits(
+            shape_id,
+            ShapeKind::Enum(self.make_enum_inner(builder, references)?),
+            self.make_traits(&builder.applied_traits, references)?,
+        ))
+    }
+
+    fn make_int_enum(
+        &self,
+        builder: &EnumBuilder,
+        references: &mut HashSet<ShapeID>,
+    ) -> Result<TopLevelShape, Error> {
+        let (shape_id, new_reference) = self.resolve_shape_name(&builder.shape_name, false)?;
+        if new_reference {
+            let _ = references.insert(shape_id.clone());
+        }
+        let body = self.make_enum_inner(builder, references)?;
+        let enum_value_id = ShapeID::new_unchecked(PRELUDE_NAMESPACE, TRAIT_ENUMVALUE, None);
+        if body.members().any(|member| {
+            matches!(
+                member.trait_named(&enum_value_id),
+                Some(Some(Value::String(_)))
+            )
+        }) {
+            return Err(ErrorKind::InvalidTraitValue(enum_value_id.to_string()).into());
+        }
+        Ok(TopLevelShape::with_traits(
+            shape_id,
+            ShapeKind::IntEnum(body),
+            self.make_traits(&builder.applied_traits, references)?,
         ))
     }
 
@@ -695,6 +784,39 @@ impl ModelBuilder {
         ))
     }
 
+    fn make_member(
+        &self,
+        builder: &MemberBuilder,
+        target_id: ShapeID,
+        references: &mut HashSet<ShapeID>,
+    ) -> Result<MemberShape, Error> {
+        let mut member = MemberShape::with_traits(
+            builder.member_name.clone(),
+            target_id,
+            self.make_traits(&builder.applied_traits, references)?,
+        );
+        self.make_locations(&mut member, &builder.location, &builder.applied_traits)?;
+        Ok(member)
+    }
+
+    fn make_locations(
+        &self,
+        element: &mut impl HasSourceLocation,
+        location: &Option<SourceLocation>,
+        builders: &[TraitBuilder],
+    ) -> Result<(), Error> {
+        if let Some(location) = location {
+            element.set_location(location.clone());
+        }
+        for builder in builders {
+            if let Some(location) = &builder.location {
+                let (shape_id, _) = self.resolve_shape_name(&builder.shape_id, true)?;
+                element.set_trait_location(shape_id, location.clone());
+            }
+        }
+        Ok(())
+    }
+
     fn make_traits(
         &self,
         builders: &[TraitBuilder],
@@ -718,6 +840,36 @@ impl ModelBuilder {
     }
 }
 
+impl TopLevelShapeBuilder {
+    fn location(&self) -> &Option<SourceLocation> {
+        match self {
+            Self::SimpleShape(builder) => &builder.location,
+            Self::List(builder) | Self::Set(builder) => &builder.location,
+            Self::Map(builder) => &builder.location,
+            Self::Structure(builder) | Self::Union(builder) => &builder.location,
+            Self::Enum(builder) | Self::IntEnum(builder) => &builder.location,
+            Self::Service(builder) => &builder.location,
+            Self::Operation(builder) => &builder.location,
+            Self::Resource(builder) => &builder.location,
+            Self::Reference(builder) => &builder.location,
+        }
+    }
+
+    fn applied_traits(&self) -> &[TraitBuilder] {
+        match self {
+            Self::SimpleShape(builder) => &builder.applied_traits,
+            Self::List(builder) | Self::Set(builder) => &builder.applied_traits,
+            Self::Map(builder) => &builder.applied_traits,
+            Self::Structure(builder) | Self::Union(builder) => &builder.applied_traits,
+            Self::Enum(builder) | Self::IntEnum(builder) => &builder.applied_traits,
+            Self::Service(builder) => &builder.applied_traits,
+            Self::Operation(builder) => &builder.applied_traits,
+            Self::Resource(builder) => &builder.applied_traits,
+            Self::Reference(builder) => &builder.applied_traits,
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
@@ -779,6 +931,18 @@ fn apply_to_member(
                 false
             }
         }
+        TopLevelShapeBuilder::Enum(shape) | TopLevelShapeBuilder::IntEnum(shape) => {
+            if let Some(member) = shape
+                .members
+                .iter_mut()
+                .find(|m| &m.member_name == member_name)
+            {
+                let _ = member.apply_trait(a_trait);
+                true
+            } else {
+                false
+            }
+        }
         _ => false,
     };
     if !ok {
@@ -806,6 +970,9 @@ fn apply_to_shape(shape: &mut TopLevelShapeBuilder, a_trait: TraitBuilder) {
         TopLevelShapeBuilder::Union(shape) => {
             let _ = shape.apply_trait(a_trait);
         }
+        TopLevelShapeBuilder::Enum(shape) | TopLevelShapeBuilder::IntEnum(shape) => {
+            let _ = shape.apply_trait(a_trait);
+        }
         TopLevelShapeBuilder::Service(shape) => {
             let _ = shape.apply_trait(a_trait);
         }
@@ -833,8 +1000,8 @@ pub mod selector;
 #[doc(hidden)]
 pub mod shapes;
 pub use shapes::{
-    ListBuilder, MapBuilder, MemberBuilder, OperationBuilder, ReferenceBuilder, ResourceBuilder,
-    ServiceBuilder, ShapeTraits, SimpleShapeBuilder, StructureBuilder,
+    EnumBuilder, ListBuilder, MapBuilder, MemberBuilder, OperationBuilder, ReferenceBuilder,
+    ResourceBuilder, ServiceBuilder, ShapeTraits, SimpleShapeBuilder, StructureBuilder,
 };
 
 #[doc(hidden)]
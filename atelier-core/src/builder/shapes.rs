@@ -1,12 +1,12 @@
 use crate::builder::id::ShapeName;
 use crate::builder::traits::ErrorSource;
 use crate::builder::{traits, TopLevelShapeBuilder, TraitBuilder};
-use crate::model::shapes::Simple;
-use crate::model::{Identifier, ShapeID};
+use crate::model::shapes::{EnumValue, Simple};
+use crate::model::{Identifier, ShapeID, SourceLocation};
 use crate::prelude::{
     PRELUDE_NAMESPACE, SHAPE_BIGDECIMAL, SHAPE_BIGINTEGER, SHAPE_BLOB, SHAPE_BOOLEAN, SHAPE_BYTE,
     SHAPE_DOCUMENT, SHAPE_DOUBLE, SHAPE_FLOAT, SHAPE_INTEGER, SHAPE_LONG, SHAPE_SHORT,
-    SHAPE_STRING, SHAPE_TIMESTAMP,
+    SHAPE_STRING, SHAPE_TIMESTAMP, SHAPE_UNIT,
 };
 use crate::syntax::{MEMBER_KEY, MEMBER_MEMBER, MEMBER_VALUE};
 use std::collections::HashMap;
@@ -73,6 +73,18 @@ macro_rules! shape_traits_impl {
     };
 }
 
+macro_rules! source_location_impl {
+    ($builder:ident) => {
+        impl $builder {
+            /// Record the location in a source representation that this model element was read from.
+            pub fn source_location(&mut self, location: SourceLocation) -> &mut Self {
+                self.location = Some(location);
+                self
+            }
+        }
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -82,6 +94,7 @@ macro_rules! shape_traits_impl {
 pub struct SimpleShapeBuilder {
     pub(super) shape_name: ShapeName,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
     pub(super) simple_shape: Simple,
 }
 
@@ -90,6 +103,7 @@ pub struct SimpleShapeBuilder {
 pub struct ListBuilder {
     pub(super) shape_name: ShapeName,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
     pub(super) member: MemberBuilder,
 }
 
@@ -98,6 +112,7 @@ pub struct ListBuilder {
 pub struct MapBuilder {
     pub(super) shape_name: ShapeName,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
     pub(super) key: MemberBuilder,
     pub(super) value: MemberBuilder,
 }
@@ -107,6 +122,17 @@ pub struct MapBuilder {
 pub struct StructureBuilder {
     pub(super) shape_name: ShapeName,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
+    pub(super) mixins: Vec<ShapeName>,
+    pub(super) members: Vec<MemberBuilder>,
+}
+
+/// Builder for `ShapeKind::Enum` and `ShapeKind::IntEnum` shapes.
+#[derive(Clone, Debug)]
+pub struct EnumBuilder {
+    pub(super) shape_name: ShapeName,
+    pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
     pub(super) members: Vec<MemberBuilder>,
 }
 
@@ -115,6 +141,7 @@ pub struct StructureBuilder {
 pub struct ServiceBuilder {
     pub(super) shape_name: ShapeName,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
     pub(super) version: String,
     pub(super) operations: Vec<ShapeName>,
     pub(super) resources: Vec<ShapeName>,
@@ -126,6 +153,7 @@ pub struct ServiceBuilder {
 pub struct OperationBuilder {
     pub(super) shape_name: ShapeName,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
     pub(super) input: Option<ShapeName>,
     pub(super) output: Option<ShapeName>,
     pub(super) errors: Vec<ShapeName>,
@@ -136,6 +164,7 @@ pub struct OperationBuilder {
 pub struct ResourceBuilder {
     pub(super) shape_name: ShapeName,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
     pub(super) identifiers: HashMap<Identifier, ShapeName>,
     pub(super) create: Option<ShapeName>,
     pub(super) put: Option<ShapeName>,
@@ -152,6 +181,7 @@ pub struct ResourceBuilder {
 pub struct ReferenceBuilder {
     pub(super) shape_id: ShapeName,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
 }
 
 /// Builder for `MemberShape` shapes.
@@ -159,6 +189,7 @@ pub struct ReferenceBuilder {
 pub struct MemberBuilder {
     pub(super) member_name: Identifier,
     pub(super) applied_traits: Vec<TraitBuilder>,
+    pub(super) location: Option<SourceLocation>,
     pub(super) target: ShapeName,
 }
 
@@ -235,12 +266,15 @@ from_impls! { SimpleShapeBuilder, SimpleShape }
 
 shape_traits_impl! { SimpleShapeBuilder }
 
+source_location_impl! { SimpleShapeBuilder }
+
 impl SimpleShapeBuilder {
     ///Construct a new simple shape builder.
     pub fn new(shape_name: &str, simple_shape: Simple) -> Self {
         Self {
             shape_name: ShapeName::from_str(shape_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             simple_shape,
         }
     }
@@ -325,12 +359,15 @@ from_impls! { ListBuilder }
 
 shape_traits_impl! { ListBuilder }
 
+source_location_impl! { ListBuilder }
+
 impl ListBuilder {
     ///Construct a new list or set shape builder.
     pub fn new(shape_name: &str, member_target: &str) -> Self {
         Self {
             shape_name: ShapeName::from_str(shape_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             member: MemberBuilder::new(MEMBER_MEMBER, member_target),
         }
     }
@@ -340,6 +377,7 @@ impl ListBuilder {
         Self {
             shape_name: ShapeName::from_str(shape_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             member: MemberBuilder::with_target(MEMBER_MEMBER, member_target),
         }
     }
@@ -362,12 +400,15 @@ from_impls! { MapBuilder, Map }
 
 shape_traits_impl! { MapBuilder }
 
+source_location_impl! { MapBuilder }
+
 impl MapBuilder {
     ///Construct a new map shape builder.
     pub fn new(shape_name: &str, key_target: &str, value_target: &str) -> Self {
         Self {
             shape_name: ShapeName::from_str(shape_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             key: MemberBuilder::new(MEMBER_KEY, key_target),
             value: MemberBuilder::new(MEMBER_VALUE, value_target),
         }
@@ -390,16 +431,26 @@ from_impls! { StructureBuilder }
 
 shape_traits_impl! { StructureBuilder }
 
+source_location_impl! { StructureBuilder }
+
 impl StructureBuilder {
     ///Construct a new structure or union shape builder.
     pub fn new(shape_name: &str) -> Self {
         Self {
             shape_name: ShapeName::from_str(shape_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
+            mixins: Default::default(),
             members: Default::default(),
         }
     }
 
+    /// Add a mixin, by name, whose members and traits this structure or union will inherit.
+    pub fn with_mixin(&mut self, mixin: &str) -> &mut Self {
+        self.mixins.push(ShapeName::from_str(mixin).unwrap());
+        self
+    }
+
     /// Create a new member in this structure or union with the given identifier and target type.
     pub fn member(&mut self, member_name: &str, member_target: &str) -> &mut Self {
         let _ = self.add_member(MemberBuilder::new(member_name, member_target));
@@ -481,20 +532,76 @@ impl StructureBuilder {
 
     add_trait!(pub error_source(src: ErrorSource));
 
+    add_trait!(pub mixin);
+
     add_trait!(pub sensitive);
 }
 
 // ------------------------------------------------------------------------------------------------
 
+from_impls! { EnumBuilder }
+
+shape_traits_impl! { EnumBuilder }
+
+source_location_impl! { EnumBuilder }
+
+impl EnumBuilder {
+    /// Construct a new enum or intEnum shape builder.
+    pub fn new(shape_name: &str) -> Self {
+        Self {
+            shape_name: ShapeName::from_str(shape_name).unwrap(),
+            applied_traits: Default::default(),
+            location: None,
+            members: Default::default(),
+        }
+    }
+
+    ///
+    /// Create a new member in this enum, the value of the member is the same as its identifier.
+    /// This is only valid for an enum, an intEnum with string member values will fail to build.
+    ///
+    pub fn member(&mut self, member_name: &str) -> &mut Self {
+        self.string_member(member_name, member_name)
+    }
+
+    ///
+    /// Create a new member in this enum with the given identifier and string value. This is only
+    /// valid for an enum, an intEnum with string member values will fail to build.
+    ///
+    pub fn string_member(&mut self, member_name: &str, value: &str) -> &mut Self {
+        let mut member = MemberBuilder::unit(member_name);
+        let _ = member.enum_value(EnumValue::String(value.to_string()));
+        self.add_member(member)
+    }
+
+    /// Create a new member in this intEnum with the given identifier and integer value.
+    pub fn int_member(&mut self, member_name: &str, value: i32) -> &mut Self {
+        let mut member = MemberBuilder::unit(member_name);
+        let _ = member.enum_value(EnumValue::Integer(value));
+        self.add_member(member)
+    }
+
+    /// Create a new member in this enum or intEnum.
+    pub fn add_member(&mut self, member: MemberBuilder) -> &mut Self {
+        self.members.push(member);
+        self
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
 from_impls! { ServiceBuilder, Service }
 
 shape_traits_impl! { ServiceBuilder }
 
+source_location_impl! { ServiceBuilder }
+
 impl ServiceBuilder {
     pub fn new(shape_name: &str, version: &str) -> Self {
         Self {
             shape_name: ShapeName::from_str(shape_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             version: version.to_string(),
             operations: Default::default(),
             resources: Default::default(),
@@ -564,11 +671,14 @@ from_impls! { OperationBuilder, Operation }
 
 shape_traits_impl! { OperationBuilder }
 
+source_location_impl! { OperationBuilder }
+
 impl OperationBuilder {
     pub fn new(shape_name: &str) -> Self {
         Self {
             shape_name: ShapeName::from_str(shape_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             input: None,
             output: None,
             errors: Default::default(),
@@ -622,11 +732,14 @@ from_impls! { ResourceBuilder, Resource }
 
 shape_traits_impl! { ResourceBuilder }
 
+source_location_impl! { ResourceBuilder }
+
 impl ResourceBuilder {
     pub fn new(shape_name: &str) -> Self {
         Self {
             shape_name: ShapeName::from_str(shape_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             identifiers: Default::default(),
             create: None,
             put: None,
@@ -747,12 +860,15 @@ impl From<ShapeID> for ReferenceBuilder {
         Self {
             shape_id: ShapeName::from(shape_id),
             applied_traits: Default::default(),
+            location: None,
         }
     }
 }
 
 shape_traits_impl! { ReferenceBuilder }
 
+source_location_impl! { ReferenceBuilder }
+
 impl ReferenceBuilder {
     /// Construct a new `ShapeKind::Unresolved` builder, with id.
     pub fn new(shape_name: &str) -> Self {
@@ -768,12 +884,15 @@ from_impls! { MemberBuilder }
 
 shape_traits_impl! { MemberBuilder }
 
+source_location_impl! { MemberBuilder }
+
 impl MemberBuilder {
     /// Construct a new member shape builder, with id target
     pub fn new(member_name: &str, target: &str) -> Self {
         Self {
             member_name: Identifier::from_str(member_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             target: ShapeName::from_str(target).unwrap(),
         }
     }
@@ -782,6 +901,7 @@ impl MemberBuilder {
         Self {
             member_name: Identifier::from_str(member_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             target,
         }
     }
@@ -790,6 +910,7 @@ impl MemberBuilder {
         Self {
             member_name: Identifier::from_str(member_name).unwrap(),
             applied_traits: Default::default(),
+            location: None,
             target: ShapeName::Qualified(ShapeID::new_unchecked(
                 target_namespace,
                 target_shape_name,
@@ -873,7 +994,14 @@ impl MemberBuilder {
         Self::new_unchecked(member_name, PRELUDE_NAMESPACE, SHAPE_TIMESTAMP)
     }
 
+    /// Constructs a new member with a target `PRELUDE_NAMESPACE::Unit`, as used by enum members.
+    pub fn unit(member_name: &str) -> Self {
+        Self::new_unchecked(member_name, PRELUDE_NAMESPACE, SHAPE_UNIT)
+    }
+
     // --------------------------------------------------------------------------------------------
 
+    add_trait!(pub enum_value(value: EnumValue));
+
     add_trait!(pub required);
 }
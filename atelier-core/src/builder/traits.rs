@@ -1,15 +1,16 @@
 use crate::builder::id::ShapeName;
 use crate::builder::values::ObjectBuilder;
 use crate::error::{Error, ErrorKind};
-use crate::model::shapes::TraitValue;
+use crate::model::shapes::{EnumValue, TraitValue};
 use crate::model::values::{Number, Value, ValueMap};
-use crate::model::ShapeID;
+use crate::model::{ShapeID, SourceLocation};
 use crate::prelude::{
-    PRELUDE_NAMESPACE, TRAIT_BOX, TRAIT_DEPRECATED, TRAIT_DOCUMENTATION, TRAIT_ERROR,
-    TRAIT_EXTERNALDOCUMENTATION, TRAIT_IDEMPOTENT, TRAIT_LENGTH, TRAIT_NOREPLACE, TRAIT_PAGINATED,
-    TRAIT_PATTERN, TRAIT_PRIVATE, TRAIT_RANGE, TRAIT_READONLY, TRAIT_REFERENCES, TRAIT_REQUIRED,
-    TRAIT_REQUIRESLENGTH, TRAIT_SENSITIVE, TRAIT_SINCE, TRAIT_STREAMING, TRAIT_TAGS, TRAIT_TITLE,
-    TRAIT_TRAIT, TRAIT_UNIQUEITEMS, TRAIT_UNSTABLE,
+    PRELUDE_NAMESPACE, TRAIT_BOX, TRAIT_DEPRECATED, TRAIT_DOCUMENTATION, TRAIT_ENUMVALUE,
+    TRAIT_ERROR, TRAIT_EXTERNALDOCUMENTATION, TRAIT_IDEMPOTENT, TRAIT_INTERNAL, TRAIT_LENGTH,
+    TRAIT_MIXIN, TRAIT_NOREPLACE, TRAIT_PAGINATED, TRAIT_PATTERN, TRAIT_PRIVATE, TRAIT_RANGE,
+    TRAIT_READONLY, TRAIT_REFERENCES, TRAIT_REQUIRED, TRAIT_REQUIRESLENGTH, TRAIT_SENSITIVE,
+    TRAIT_SINCE, TRAIT_STREAMING, TRAIT_TAGS, TRAIT_TITLE, TRAIT_TRAIT, TRAIT_UNIQUEITEMS,
+    TRAIT_UNSTABLE,
 };
 use crate::syntax::SHAPE_ID_ABSOLUTE_SEPARATOR;
 use std::fmt::{Display, Formatter};
@@ -37,6 +38,7 @@ pub enum ErrorSource {
 pub struct TraitBuilder {
     pub(crate) shape_id: ShapeName,
     pub(crate) value: TraitValue,
+    pub(crate) location: Option<SourceLocation>,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -65,6 +67,11 @@ pub fn documentation(value: &str) -> TraitBuilder {
     TraitBuilder::with_value(&prelude_name(TRAIT_DOCUMENTATION), value.into())
 }
 
+/// Create a new `TraitBuilder` for the corresponding prelude trait.
+pub fn enum_value(value: EnumValue) -> TraitBuilder {
+    TraitBuilder::with_value(&prelude_name(TRAIT_ENUMVALUE), value.into())
+}
+
 /// Create a new `TraitBuilder` for the corresponding prelude trait.
 pub fn error_source(src: ErrorSource) -> TraitBuilder {
     TraitBuilder::with_value(&prelude_name(TRAIT_ERROR), src.to_string().into())
@@ -107,6 +114,11 @@ pub fn length_min(value: usize) -> TraitBuilder {
     length(Some(value), None)
 }
 
+/// Create a new `TraitBuilder` for the corresponding prelude trait.
+pub fn mixin() -> TraitBuilder {
+    TraitBuilder::annotation(&prelude_name(TRAIT_MIXIN))
+}
+
 /// Create a new `TraitBuilder` for the corresponding prelude trait.
 pub fn no_replace() -> TraitBuilder {
     TraitBuilder::annotation(&prelude_name(TRAIT_NOREPLACE))
@@ -141,6 +153,11 @@ pub fn pattern(pat: &str) -> TraitBuilder {
     TraitBuilder::with_value(&prelude_name(TRAIT_PATTERN), pat.into())
 }
 
+/// Create a new `TraitBuilder` for the corresponding prelude trait.
+pub fn internal() -> TraitBuilder {
+    TraitBuilder::annotation(&prelude_name(TRAIT_INTERNAL))
+}
+
 /// Create a new `TraitBuilder` for the corresponding prelude trait.
 pub fn private() -> TraitBuilder {
     TraitBuilder::annotation(&prelude_name(TRAIT_PRIVATE))
@@ -278,6 +295,7 @@ impl TraitBuilder {
         Self {
             shape_id: ShapeName::from_str(id).unwrap(),
             value: None,
+            location: None,
         }
     }
 
@@ -286,6 +304,7 @@ impl TraitBuilder {
         Self {
             shape_id: ShapeName::from_str(id).unwrap(),
             value: Some(Value::Object(ValueMap::new())),
+            location: None,
         }
     }
 
@@ -294,6 +313,7 @@ impl TraitBuilder {
         Self {
             shape_id: ShapeName::from_str(id).unwrap(),
             value: Some(value),
+            location: None,
         }
     }
 
@@ -344,6 +364,12 @@ impl TraitBuilder {
         self.value = Some(value);
         self
     }
+
+    /// Record the location in a source representation that this trait application was read from.
+    pub fn source_location(&mut self, location: SourceLocation) -> &mut Self {
+        self.location = Some(location);
+        self
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
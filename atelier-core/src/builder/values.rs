@@ -1,5 +1,4 @@
-use crate::model::values::{Number, Value};
-use std::collections::HashMap;
+use crate::model::values::{Number, Value, ValueMap};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -18,7 +17,7 @@ pub struct ArrayBuilder {
 ///
 #[derive(Debug)]
 pub struct ObjectBuilder {
-    inner: HashMap<String, Value>,
+    inner: ValueMap,
 }
 
 ///
@@ -42,6 +42,12 @@ error_chain! {
             display("Expected a member, not shape, ID: '{}'.", id)
         }
 
+        #[doc = "Expected the ID of a service shape."]
+        ServiceExpected(id: ShapeID) {
+            description("Expected the ID of a service shape.")
+            display("Expected the ID of a service shape: '{}'.", id)
+        }
+
         // ----------------------------------------------------------------------------------------
         // Shape Resolution Errors
         // ----------------------------------------------------------------------------------------
@@ -64,6 +70,24 @@ error_chain! {
             display("A shape name resolved to multiple shape IDs: '{}'.", s)
         }
 
+        #[doc = "A shape referenced a mixin that is unknown, or is not marked as a mixin."]
+        InvalidMixin(id: String, mixin: String) {
+            description("A shape referenced a mixin that is unknown, or is not marked as a mixin.")
+            display("The shape '{}' references '{}' which is unknown, or is not marked as a mixin.", id, mixin)
+        }
+
+        #[doc = "A shape, directly or indirectly, uses itself as a mixin."]
+        MixinCycle(id: ShapeID) {
+            description("A shape, directly or indirectly, uses itself as a mixin.")
+            display("The shape '{}', directly or indirectly, uses itself as a mixin.", id)
+        }
+
+        #[doc = "A shape, or member, was replaced by one with the identifier of another."]
+        ReplacedShapeConflict(id: ShapeID) {
+            description("A shape, or member, was replaced by one with the identifier of another.")
+            display("A shape, or member, was replaced by one with the identifier of another: '{}'.", id)
+        }
+
         // ----------------------------------------------------------------------------------------
         // Shape Resolution Errors
         // ----------------------------------------------------------------------------------------
@@ -108,6 +132,12 @@ error_chain! {
             display("Invalid, or missing, value specified for trait named '{}'.", name)
         }
 
+        #[doc = "Invalid numeric literal."]
+        InvalidNumber(s: String) {
+            description("Invalid numeric literal.")
+            display("Invalid numeric literal: '{}'.", s)
+        }
+
         #[doc = "Invalid simple shape name."]
         InvalidSimpleShape(s: String) {
             description("Invalid simple shape name.")
@@ -33,11 +33,12 @@ and "`<=`" the *value assignment* operator.
  6. {shape_type}::{shape_id}::{identifier}::trait::{shape_id}<={value...}
  7. {shape_type}::{shape_id}::identifier::{identifier}=>{shape_id}
  8. {shape_type}::{shape_id}::rename::{shape_id}<={identifier}
- 9. meta::{identifier}<={value...}
-10. ()
-11. {simple_value}
-12. [{integer}]={value...}
-13. {{identifier}}={value...}
+ 9. {shape_type}::{shape_id}::mixin=>{shape_id}
+10. meta::{identifier}<={value...}
+11. ()
+12. {simple_value}
+13. [{integer}]={value...}
+14. {{identifier}}={value...}
 ```
 
 * For each top-level shape:
@@ -45,6 +46,8 @@ and "`<=`" the *value assignment* operator.
   * For each trait applied to this shape:
     * append to the above string the value "`::trait`" and the trait's fully qualified name (2),
     * if the trait has a value, append the "`<=`" and follow the value production rules below (3).
+  * For each mixin applied to this shape:
+    * emit a line with the string "`::mixin`", "`=>`", and the mixin's fully qualified name (9).
   * For each member of the shape:
     * emit a line with the member identifier, "`=>`", and the target's fully qualified name (4),
       * for array-valued members the member name emitted is the singular form (error for errors, etc.).
@@ -60,18 +63,18 @@ and "`<=`" the *value assignment* operator.
 
 * For each value in the model metadata map:
   * use the string "`meta`" as if it where a shape name followed by "`::`"
-  * append the key name, the string "`<=`" and follow the value production rules below (9).
+  * append the key name, the string "`<=`" and follow the value production rules below (10).
 
-* For null values simply emit the string "`()`" (10).
-* For boolean, numeric, and string values emit their natural form (11).
+* For null values simply emit the string "`()`" (11).
+* For boolean, numeric, and string values emit their natural form (12).
   * Ensure string values are correctly quoted.
 * For arrays:
   * emit a line per index, with "`[`", the index as a zero-based integer, "`]`", the operator
-    "`<=`" and follow these same value production rules (12),
+    "`<=`" and follow these same value production rules (13),
   * an empty array is denoted with the string "`[]`".
 * For objects:
   * emit a line per key, with "`{`", the key name, "`}`", the operator "`<=`" and follow
-    these same value production rules (13),
+    these same value production rules (14),
   * an empty object is denoted with the string "`{}`".
 
 Finally, all lines must be sorted to ensure the overall output can be compared.
@@ -214,6 +217,7 @@ impl ModelWriter for LineOrientedWriter {
 
 const META_PREFIX: &str = "meta";
 const TRAIT_PREFIX: &str = "trait";
+const MIXIN_PREFIX: &str = "mixin";
 const SEGMENT_SEP: &str = "::";
 const TARGET_SEP: &str = "=>";
 const VALUE_SEP: &str = "<=";
@@ -263,6 +267,22 @@ fn shape_into_strings(shape: &TopLevelShape, strings: &mut Vec<String>) {
             }
             prefix
         }
+        ShapeKind::Enum(v) => {
+            let prefix = line_prefix("enum", shape);
+            strings.push(prefix.clone());
+            for member in v.members() {
+                member_into_strings(&prefix, member, strings);
+            }
+            prefix
+        }
+        ShapeKind::IntEnum(v) => {
+            let prefix = line_prefix("intEnum", shape);
+            strings.push(prefix.clone());
+            for member in v.members() {
+                member_into_strings(&prefix, member, strings);
+            }
+            prefix
+        }
         ShapeKind::Service(v) => {
             let prefix = line_prefix("service", shape);
             strings.push(prefix.clone());
@@ -352,6 +372,9 @@ fn shape_into_strings(shape: &TopLevelShape, strings: &mut Vec<String>) {
             prefix
         }
     };
+    for mixin in shape.mixins() {
+        strings.push(format!("{}{}", prefix, member_target(MIXIN_PREFIX, mixin)));
+    }
     let prefix = format!("{}{}{}{}", prefix, SEGMENT_SEP, TRAIT_PREFIX, SEGMENT_SEP);
     traits_into_strings(&prefix, shape.traits(), strings);
 }
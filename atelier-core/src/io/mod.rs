@@ -84,11 +84,14 @@ where
 
 ///
 /// Read a model from a file path using the given `ModelReader`. This is simply a
-/// short-cut that saves some repetitive boiler-plate.
+/// short-cut that saves some repetitive boiler-plate. Any source locations recorded by the reader
+/// will also include the file path.
 ///
 pub fn read_model_from_file(r: &mut impl ModelReader, path: PathBuf) -> ModelResult<Model> {
-    let mut file = File::open(path)?;
-    r.read(&mut file)
+    let mut file = File::open(&path)?;
+    let mut model = r.read(&mut file)?;
+    model.set_source_file(&path.to_string_lossy());
+    Ok(model)
 }
 
 ///
@@ -20,6 +20,9 @@ core traits for other crates. Specifically it provides:
    1. A model writer using Rust's [debug](io/debug/index.html) trait.
 1. A common [error](error/index.html) module to be used by all Atelier crates.
 
+With the optional "serde" feature enabled the model types, including diffs, implement serde's
+`Serialize` and `Deserialize` traits; identifiers and versions are serialized in their string form.
+
 # The Semantic Model API Example
 
 The following example demonstrates the core model API to create a model for a simple service. The
@@ -250,6 +253,8 @@ let model: Model = ModelBuilder::new(Version::V10, "example.motd")
     unused_qualifications,
     unused_results,
 )]
+// Required by the number of error kinds declared in `error_chain!`.
+#![recursion_limit = "256"]
 
 #[macro_use]
 extern crate error_chain;
@@ -275,8 +280,10 @@ use std::str::FromStr;
 ///
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Hash, Copy)]
 pub enum Version {
-    /// Version 1.0 (initial, and current)
+    /// Version 1.0 (initial, and the default where no version is specified)
     V10,
+    /// Version 2.0
+    V20,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -291,7 +298,10 @@ impl Default for Version {
 
 impl Display for Version {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "1.0")
+        match self {
+            Self::V10 => write!(f, "1.0"),
+            Self::V20 => write!(f, "2.0"),
+        }
     }
 }
 
@@ -301,19 +311,50 @@ impl FromStr for Version {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s == "1" || s == "1.0" {
             Ok(Self::V10)
+        } else if s == "2" || s == "2.0" {
+            Ok(Self::V20)
         } else {
             Err(error::ErrorKind::InvalidVersionNumber(Some(s.to_string())).into())
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Version {
     ///
-    /// Returns the most current version of the Smithy specification.
+    /// Returns the version of the Smithy specification assumed when a model does not specify
+    /// one; the specification requires this to be 1.0.
     ///
     pub fn current() -> Self {
         Self::V10
     }
+
+    ///
+    /// Returns the most recent version of the Smithy specification supported by this crate.
+    ///
+    pub fn latest() -> Self {
+        Self::V20
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -0,0 +1,681 @@
+/*!
+This module provides validation of node values against the shapes they are intended to conform to;
+for example an `@examples` payload against an operation's input structure, or a configuration
+document against a structure describing it.
+
+A value is checked for the correct type against the target shape, integers are checked to be within
+the range of byte, short, integer, and long shapes, structures and unions are checked for unknown
+and missing members, and the elements of lists, sets, and maps are checked against their member
+targets. The constraint traits `@length`, `@range`, `@pattern`, `@required`, `@enum`, and
+`@uniqueItems` are honoured where applied to either a member or its target shape.
+
+Each violation carries a path to the offending value, where the elements of a structure or map are
+separated by `.` and the index of a list element is given as `[i]`, for example `cities[2].name`.
+The path to the value passed in is the empty string.
+
+Shapes in the prelude namespace that are not defined in the model resolve to the prelude
+definitions, so that `smithy.api#String` and friends need not be present in the model.
+
+# Example
+
+```rust
+use atelier_core::builder::values::ObjectBuilder;
+use atelier_core::builder::{MemberBuilder, ModelBuilder, StructureBuilder};
+use atelier_core::model::conformance::check_value;
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+let model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+    .structure(
+        StructureBuilder::new("City")
+            .add_member(MemberBuilder::string("name").required().into())
+            .member("population", "Integer")
+            .into(),
+    )
+    .try_into()
+    .unwrap();
+
+let violations = check_value(
+    &model,
+    &ObjectBuilder::default().string("population", "many").into(),
+    &ShapeID::from_str("smithy.example#City").unwrap(),
+);
+let messages: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+assert_eq!(
+    messages,
+    vec![
+        "population: expected an integer, found a string",
+        "the required member name is missing",
+    ]
+);
+```
+*/
+
+use crate::model::shapes::{
+    AppliedTraits, HasTraits, MemberShape, ShapeKind, Simple, TopLevelShape,
+};
+use crate::model::values::{Number, Value};
+use crate::model::{HasIdentity, Identifier, Model, ShapeID};
+use crate::prelude::{
+    prelude_definition, prelude_namespace_id, prelude_shape_named, TRAIT_ENUM, TRAIT_EXAMPLEERROR,
+    TRAIT_IDREF, TRAIT_LENGTH, TRAIT_PATTERN, TRAIT_RANGE, TRAIT_REFERENCE, TRAIT_SPARSE,
+    TRAIT_UNIQUEITEMS,
+};
+use regex::Regex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single way in which a value does not conform to a shape, along with the path to the value in
+/// question.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    path: String,
+    message: String,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Check `value` against the shape `shape_id` in `model`, returning all the violations found. An
+/// empty result denotes a conforming value.
+///
+pub fn check_value(model: &Model, value: &Value, shape_id: &ShapeID) -> Vec<Violation> {
+    let mut checker = ValueChecker::new(model);
+    match resolve_shape(model, shape_id) {
+        None => checker.violation(
+            "",
+            &format!("the shape {} is not defined in the model", shape_id),
+        ),
+        Some(shape) => checker.check_root(value, shape),
+    }
+    checker.violations
+}
+
+///
+/// Returns `true` if `value` conforms to the shape `shape_id` in `model`, else `false`.
+///
+pub fn value_conforms(model: &Model, value: &Value, shape_id: &ShapeID) -> bool {
+    check_value(model, value, shape_id).is_empty()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl Violation {
+    /// The path to the offending value, the empty string denotes the value checked.
+    pub fn path(&self) -> &String {
+        &self.path
+    }
+
+    /// A description of the violation.
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct ValueChecker<'a> {
+    model: &'a Model,
+    violations: Vec<Violation>,
+    patterns: HashMap<String, Option<Regex>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Find the shape `shape_id`, either in the model or, for prelude shapes not defined in the model,
+/// in the prelude definitions.
+///
+pub(crate) fn resolve_shape<'a>(model: &'a Model, shape_id: &ShapeID) -> Option<&'a TopLevelShape> {
+    match model.shape(shape_id) {
+        Some(shape) if !shape.is_unresolved() => Some(shape),
+        _ if shape_id.namespace() == prelude_namespace_id() => prelude_definition(shape_id),
+        _ => None,
+    }
+}
+
+///
+/// Check `value` against the, already resolved, `shape`.
+///
+pub(crate) fn check_shape_value(
+    model: &Model,
+    value: &Value,
+    shape: &TopLevelShape,
+) -> Vec<Violation> {
+    let mut checker = ValueChecker::new(model);
+    checker.check_root(value, shape);
+    checker.violations
+}
+
+///
+/// Prelude members that hold shape identifiers, but where neither the member nor its target has
+/// the `@idRef` trait applied.
+///
+const PRELUDE_SHAPE_ID_MEMBERS: &[(&str, &str)] = &[
+    (TRAIT_REFERENCE, "resource"),
+    (TRAIT_REFERENCE, "service"),
+    (TRAIT_EXAMPLEERROR, "shapeId"),
+];
+
+///
+/// Return the strings within `value`, the value of the trait `trait_id`, that are shape
+/// identifiers according to the trait's definition. These are the strings that conform to a
+/// shape, or member, with the `@idRef` trait applied, or to one of the prelude members known to
+/// hold identifiers such as the `resource` of `@references`. A trait with no definition in the
+/// model or prelude has no such strings.
+///
+pub(crate) fn shape_id_values<'a>(
+    model: &Model,
+    trait_id: &ShapeID,
+    value: &'a Value,
+) -> Vec<&'a String> {
+    let mut strings: Vec<&'a String> = Default::default();
+    if let Some(shape) = resolve_shape(model, trait_id) {
+        collect_shape_id_values(model, shape, false, value, &mut strings);
+    }
+    strings
+}
+
+///
+/// The mutable form of `shape_id_values`, so that the identifiers may be rewritten in place.
+///
+pub(crate) fn shape_id_values_mut<'a>(
+    model: &Model,
+    trait_id: &ShapeID,
+    value: &'a mut Value,
+) -> Vec<&'a mut String> {
+    let mut strings: Vec<&'a mut String> = Default::default();
+    if let Some(shape) = resolve_shape(model, trait_id) {
+        collect_shape_id_values_mut(model, shape, false, value, &mut strings);
+    }
+    strings
+}
+
+fn collect_shape_id_values<'a>(
+    model: &Model,
+    shape: &TopLevelShape,
+    id_ref: bool,
+    value: &'a Value,
+    strings: &mut Vec<&'a String>,
+) {
+    match value {
+        Value::String(s) if id_ref || is_id_ref(shape.traits()) => strings.push(s),
+        Value::Array(values) => {
+            if let Some((id_ref, element)) = element_shape(model, shape, None) {
+                for value in values {
+                    collect_shape_id_values(model, element, id_ref, value, strings);
+                }
+            }
+        }
+        Value::Object(values) => {
+            for (key, value) in values {
+                if let Some((id_ref, element)) = element_shape(model, shape, Some(key)) {
+                    collect_shape_id_values(model, element, id_ref, value, strings);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_shape_id_values_mut<'a>(
+    model: &Model,
+    shape: &TopLevelShape,
+    id_ref: bool,
+    value: &'a mut Value,
+    strings: &mut Vec<&'a mut String>,
+) {
+    match value {
+        Value::String(s) if id_ref || is_id_ref(shape.traits()) => strings.push(s),
+        Value::Array(values) => {
+            if let Some((id_ref, element)) = element_shape(model, shape, None) {
+                for value in values {
+                    collect_shape_id_values_mut(model, element, id_ref, value, strings);
+                }
+            }
+        }
+        Value::Object(values) => {
+            for (key, value) in values {
+                if let Some((id_ref, element)) = element_shape(model, shape, Some(key)) {
+                    collect_shape_id_values_mut(model, element, id_ref, value, strings);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+///
+/// Return the target of the member of the aggregate `shape` for an element of a value, along
+/// with whether the member holds shape identifiers. The `key` is `None` for the elements of an
+/// array, and the key of the element in an object otherwise.
+///
+fn element_shape<'a>(
+    model: &'a Model,
+    shape: &TopLevelShape,
+    key: Option<&str>,
+) -> Option<(bool, &'a TopLevelShape)> {
+    let member: &MemberShape = match (shape.body(), key) {
+        (ShapeKind::List(list), None) | (ShapeKind::Set(list), None) => list.member(),
+        (ShapeKind::Map(map), Some(_)) => map.value(),
+        (ShapeKind::Structure(structure), Some(key)) | (ShapeKind::Union(structure), Some(key)) => {
+            structure.member(&Identifier::from_str(key).ok()?)?
+        }
+        _ => return None,
+    };
+    let id_ref = is_id_ref(member.traits())
+        || (shape.id().namespace() == prelude_namespace_id()
+            && PRELUDE_SHAPE_ID_MEMBERS
+                .iter()
+                .any(|(shape_name, member_name)| {
+                    shape.id().shape_name().to_string() == *shape_name
+                        && member.id().to_string() == *member_name
+                }));
+    Some((id_ref, resolve_shape(model, member.target())?))
+}
+
+fn is_id_ref(traits: &AppliedTraits) -> bool {
+    traits.contains_key(&prelude_shape_named(TRAIT_IDREF).unwrap())
+}
+
+impl<'a> ValueChecker<'a> {
+    fn new(model: &'a Model) -> Self {
+        Self {
+            model,
+            violations: Default::default(),
+            patterns: Default::default(),
+        }
+    }
+
+    fn violation(&mut self, path: &str, message: &str) {
+        self.violations.push(Violation {
+            path: path.to_string(),
+            message: message.to_string(),
+        });
+    }
+
+    fn expected(&mut self, path: &str, expected: &str, value: &Value) {
+        self.violation(
+            path,
+            &format!("expected {}, found {}", expected, value_kind(value)),
+        );
+    }
+
+    fn check_root(&mut self, value: &Value, shape: &TopLevelShape) {
+        self.check_shape(value, shape, "");
+        self.check_constraints(value, Some(shape), &Default::default(), "");
+    }
+
+    fn check_target(
+        &mut self,
+        value: &Value,
+        target: &ShapeID,
+        member_traits: &AppliedTraits,
+        path: &str,
+    ) {
+        let shape = resolve_shape(self.model, target);
+        if let Some(shape) = shape {
+            self.check_shape(value, shape, path);
+        }
+        self.check_constraints(value, shape, member_traits, path);
+    }
+
+    fn check_shape(&mut self, value: &Value, shape: &TopLevelShape, path: &str) {
+        match shape.body() {
+            ShapeKind::Simple(simple) => self.check_simple(value, simple, path),
+            ShapeKind::List(list) | ShapeKind::Set(list) => match value {
+                Value::Array(values) => {
+                    for (i, value) in values.iter().enumerate() {
+                        let path = format!("{}[{}]", path, i);
+                        if value.is_none() && is_sparse(shape) {
+                            continue;
+                        }
+                        self.check_target(
+                            value,
+                            list.member().target(),
+                            list.member().traits(),
+                            &path,
+                        );
+                    }
+                    if shape.is_set() && has_duplicates(values) {
+                        self.violation(path, "a set value must not contain duplicate items");
+                    }
+                }
+                _ => self.expected(path, "an array", value),
+            },
+            ShapeKind::Map(map) => match value {
+                Value::Object(values) => {
+                    for (key, value) in values {
+                        let path = child_path(path, key);
+                        self.check_target(
+                            &Value::String(key.clone()),
+                            map.key().target(),
+                            map.key().traits(),
+                            &path,
+                        );
+                        if value.is_none() && is_sparse(shape) {
+                            continue;
+                        }
+                        self.check_target(value, map.value().target(), map.value().traits(), &path);
+                    }
+                }
+                _ => self.expected(path, "an object", value),
+            },
+            ShapeKind::Structure(structure) | ShapeKind::Union(structure) => match value {
+                Value::Object(values) => {
+                    for (key, value) in values {
+                        match structure
+                            .members()
+                            .find(|member| &member.id().to_string() == key)
+                        {
+                            None => self.violation(
+                                &child_path(path, key),
+                                &format!("{} has no member named {}", shape.id(), key),
+                            ),
+                            Some(member) => self.check_target(
+                                value,
+                                member.target(),
+                                member.traits(),
+                                &child_path(path, key),
+                            ),
+                        }
+                    }
+                    if shape.is_structure() {
+                        for member in structure.members().filter(|member| member.is_required()) {
+                            if !values.contains_key(&member.id().to_string()) {
+                                self.violation(
+                                    path,
+                                    &format!("the required member {} is missing", member.id()),
+                                );
+                            }
+                        }
+                    } else if values.len() != 1 {
+                        self.violation(path, "a union value must have exactly one member");
+                    }
+                }
+                _ => self.expected(path, "an object", value),
+            },
+            ShapeKind::Enum(enumeration) | ShapeKind::IntEnum(enumeration) => {
+                let expected = if shape.is_enum() {
+                    value.is_string()
+                } else {
+                    matches!(value, Value::Number(v) if v.is_integer())
+                };
+                if !expected {
+                    self.expected(
+                        path,
+                        if shape.is_enum() {
+                            "a string"
+                        } else {
+                            "an integer"
+                        },
+                        value,
+                    )
+                } else if !enumeration
+                    .values(shape.is_int_enum())
+                    .any(|(_, enum_value)| &Value::from(enum_value) == value)
+                {
+                    self.violation(
+                        path,
+                        &format!("{} is not a value of the enum {}", value, shape.id()),
+                    )
+                }
+            }
+            ShapeKind::Service(_) | ShapeKind::Operation(_) | ShapeKind::Resource(_) => self
+                .violation(
+                    path,
+                    &format!("the shape {} cannot be used as a value", shape.id()),
+                ),
+            ShapeKind::Unresolved => {}
+        }
+    }
+
+    fn check_simple(&mut self, value: &Value, simple: &Simple, path: &str) {
+        match simple {
+            Simple::Blob | Simple::String => {
+                if !value.is_string() {
+                    self.expected(path, "a string", value)
+                }
+            }
+            Simple::Boolean => {
+                if !value.is_boolean() {
+                    self.expected(path, "a boolean", value)
+                }
+            }
+            Simple::Byte | Simple::Short | Simple::Integer | Simple::Long => {
+                let (kind, min, max) = match simple {
+                    Simple::Byte => ("byte", i8::MIN as i64, i8::MAX as i64),
+                    Simple::Short => ("short", i16::MIN as i64, i16::MAX as i64),
+                    Simple::Integer => ("integer", i32::MIN as i64, i32::MAX as i64),
+                    _ => ("long", i64::MIN, i64::MAX),
+                };
+                match value {
+                    Value::Number(v) if v.is_integer() => {
+                        if !matches!(integer_value(v), Some(v) if v >= min && v <= max) {
+                            self.violation(
+                                path,
+                                &format!("the value {} is outside the range of a {}", value, kind),
+                            )
+                        }
+                    }
+                    _ => self.expected(path, "an integer", value),
+                }
+            }
+            Simple::BigInteger => {
+                if !matches!(value, Value::Number(v) if v.is_integer()) {
+                    self.expected(path, "an integer", value)
+                }
+            }
+            Simple::Float | Simple::Double | Simple::BigDecimal => {
+                if !value.is_number() {
+                    self.expected(path, "a number", value)
+                }
+            }
+            Simple::Timestamp => {
+                if !(value.is_string() || value.is_number()) {
+                    self.expected(path, "a string or number", value)
+                }
+            }
+            Simple::Document => {}
+        }
+    }
+
+    ///
+    /// Check the constraint traits applied to the member and to its target; where both apply the
+    /// same trait the member's takes precedence.
+    ///
+    fn check_constraints(
+        &mut self,
+        value: &Value,
+        shape: Option<&TopLevelShape>,
+        member_traits: &AppliedTraits,
+        path: &str,
+    ) {
+        let constraint = |name: &str| {
+            let id = prelude_shape_named(name).unwrap();
+            match member_traits.get(&id) {
+                Some(value) => Some(value.clone()),
+                None => shape.and_then(|shape| shape.traits().get(&id).cloned()),
+            }
+        };
+        let is_blob = matches!(
+            shape.map(|shape| shape.body()),
+            Some(ShapeKind::Simple(Simple::Blob))
+        );
+
+        if let Some(Some(Value::Object(length))) = constraint(TRAIT_LENGTH) {
+            let actual = match value {
+                Value::String(v) if !is_blob => Some(v.chars().count()),
+                Value::Array(vs) => Some(vs.len()),
+                Value::Object(vs) => Some(vs.len()),
+                _ => None,
+            };
+            if let Some(actual) = actual {
+                let count = Number::Integer(actual as i64);
+                if let Some(min) = length.get("min").and_then(number_value) {
+                    if count.numeric_cmp(min) == Some(Ordering::Less) {
+                        self.violation(
+                            path,
+                            &format!("the length {} is less than the minimum {}", actual, min),
+                        );
+                    }
+                }
+                if let Some(max) = length.get("max").and_then(number_value) {
+                    if count.numeric_cmp(max) == Some(Ordering::Greater) {
+                        self.violation(
+                            path,
+                            &format!("the length {} is greater than the maximum {}", actual, max),
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(Some(Value::Object(range))) = constraint(TRAIT_RANGE) {
+            if let Some(actual) = number_value(value) {
+                if let Some(min) = range.get("min").and_then(number_value) {
+                    if actual.numeric_cmp(min) == Some(Ordering::Less) {
+                        self.violation(
+                            path,
+                            &format!("the value {} is less than the minimum {}", value, min),
+                        );
+                    }
+                }
+                if let Some(max) = range.get("max").and_then(number_value) {
+                    if actual.numeric_cmp(max) == Some(Ordering::Greater) {
+                        self.violation(
+                            path,
+                            &format!("the value {} is greater than the maximum {}", value, max),
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(Some(Value::String(pattern))) = constraint(TRAIT_PATTERN) {
+            if let Value::String(actual) = value {
+                let regex = self
+                    .patterns
+                    .entry(pattern.clone())
+                    .or_insert_with(|| Regex::new(&pattern).ok());
+                match regex {
+                    Some(regex) => {
+                        if !regex.is_match(actual) {
+                            self.violation(
+                                path,
+                                &format!("{} does not match the pattern {:?}", value, pattern),
+                            );
+                        }
+                    }
+                    None => self.violation(
+                        path,
+                        &format!(
+                            "the pattern {:?} is not a valid regular expression",
+                            pattern
+                        ),
+                    ),
+                }
+            }
+        }
+
+        if let Some(Some(Value::Array(definitions))) = constraint(TRAIT_ENUM) {
+            if value.is_string()
+                && !definitions.iter().any(|definition| match definition {
+                    Value::Object(definition) => definition.get("value") == Some(value),
+                    _ => false,
+                })
+            {
+                self.violation(
+                    path,
+                    &format!("{} is not one of the values of the enum trait", value),
+                );
+            }
+        }
+
+        if constraint(TRAIT_UNIQUEITEMS).is_some() {
+            if let Value::Array(values) = value {
+                if has_duplicates(values) {
+                    self.violation(path, "the array must not contain duplicate items");
+                }
+            }
+        }
+    }
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+        Value::Number(v) if v.is_integer() => "an integer",
+        Value::Number(_) => "a float",
+        Value::Boolean(_) => "a boolean",
+        Value::String(_) => "a string",
+        Value::None => "null",
+    }
+}
+
+fn integer_value(value: &Number) -> Option<i64> {
+    match value {
+        Number::Integer(v) => Some(*v),
+        Number::BigInteger(v) => i64::from_str(v).ok(),
+        _ => None,
+    }
+}
+
+fn number_value(value: &Value) -> Option<&Number> {
+    match value {
+        Value::Number(v) => Some(v),
+        _ => None,
+    }
+}
+
+fn has_duplicates(values: &[Value]) -> bool {
+    values
+        .iter()
+        .enumerate()
+        .any(|(i, value)| values[i + 1..].contains(value))
+}
+
+fn is_sparse(shape: &TopLevelShape) -> bool {
+    shape.has_trait(&prelude_shape_named(TRAIT_SPARSE).unwrap())
+}
@@ -0,0 +1,735 @@
+/*!
+This module provides a structured comparison of two models. Where `PartialEq`, and `NonTraitEq`,
+tell you *whether* two models differ a `ModelDiff` tells you *what* differs, as a list of typed
+changes from the older model to the newer one.
+
+Changes are reported in a stable order; model-level changes first, then by shape ID, and within a
+shape its type, members, traits, and then references to other shapes. Each change also has a
+single-line textual form, and the `Display` implementation for `ModelDiff` writes one change per
+line.
+
+# Example
+
+```rust
+use atelier_core::builder::{ModelBuilder, SimpleShapeBuilder, StructureBuilder};
+use atelier_core::model::diff::{Change, ModelDiff};
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+let old: Model = ModelBuilder::new(Version::V10, "smithy.example")
+    .structure(StructureBuilder::new("Thing").member("name", "String").into())
+    .try_into()
+    .unwrap();
+let new: Model = ModelBuilder::new(Version::V10, "smithy.example")
+    .structure(StructureBuilder::new("Thing").member("name", "ThingName").into())
+    .simple_shape(SimpleShapeBuilder::string("ThingName"))
+    .try_into()
+    .unwrap();
+
+let diff = ModelDiff::new(&old, &new);
+assert_eq!(diff.len(), 2);
+assert_eq!(
+    diff.added_shapes().collect::<Vec<&ShapeID>>(),
+    vec![&ShapeID::from_str("smithy.example#ThingName").unwrap()]
+);
+assert_eq!(
+    diff.to_string(),
+    "member retargeted: smithy.example#Thing$name (smithy.api#String -> smithy.example#ThingName)\n\
+     shape added: smithy.example#ThingName\n"
+);
+```
+*/
+
+use crate::model::neighbor::{NeighborIndex, Relationship, RelationshipKind};
+use crate::model::shapes::{AppliedTraits, HasTraits, ShapeKind, TopLevelShape};
+use crate::model::values::Value;
+use crate::model::{body_members, HasIdentity, Identifier, Model, ShapeID};
+use crate::syntax::{
+    SHAPE_ENUM, SHAPE_INT_ENUM, SHAPE_LIST, SHAPE_MAP, SHAPE_OPERATION, SHAPE_RESOURCE,
+    SHAPE_SERVICE, SHAPE_SET, SHAPE_STRUCTURE, SHAPE_UNION,
+};
+use crate::Version;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single change between an older and a newer model. Where a change affects a member, the
+/// `shape` or `member` value is the member's shape ID.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    /// The Smithy version of the model changed.
+    SmithyVersionChanged {
+        /// The Smithy version of the older model.
+        from: Version,
+        /// The Smithy version of the newer model.
+        to: Version,
+    },
+    /// A metadata key was added to the model.
+    MetadataAdded {
+        /// The metadata key that is only present in the newer model.
+        key: String,
+        /// The value of the added key in the newer model.
+        value: Value,
+    },
+    /// A metadata key was removed from the model.
+    MetadataRemoved {
+        /// The metadata key that is only present in the older model.
+        key: String,
+        /// The value the removed key had in the older model.
+        value: Value,
+    },
+    /// The value of a metadata key changed.
+    MetadataChanged {
+        /// The metadata key present in both models.
+        key: String,
+        /// The value of the key in the older model.
+        from: Value,
+        /// The value of the key in the newer model.
+        to: Value,
+    },
+    /// A top-level shape was added to the model.
+    ShapeAdded {
+        /// The ID of the top-level shape only present in the newer model.
+        shape: ShapeID,
+    },
+    /// A top-level shape was removed from the model.
+    ShapeRemoved {
+        /// The ID of the top-level shape only present in the older model.
+        shape: ShapeID,
+    },
+    /// The type of a top-level shape changed, no member or reference changes are reported for a
+    /// shape whose type changed.
+    ShapeTypeChanged {
+        /// The ID of the top-level shape whose type changed.
+        shape: ShapeID,
+        /// The type name, such as `structure`, of the shape in the older model.
+        from: String,
+        /// The type name, such as `union`, of the shape in the newer model.
+        to: String,
+    },
+    /// A member was added to a shape.
+    MemberAdded {
+        /// The ID of the member only present in the newer model.
+        member: ShapeID,
+        /// The shape the added member targets.
+        target: ShapeID,
+    },
+    /// A member was removed from a shape.
+    MemberRemoved {
+        /// The ID of the member only present in the older model.
+        member: ShapeID,
+        /// The shape the removed member targeted.
+        target: ShapeID,
+    },
+    /// The target of a member changed.
+    MemberRetargeted {
+        /// The ID of the member present in both models.
+        member: ShapeID,
+        /// The shape the member targets in the older model.
+        from: ShapeID,
+        /// The shape the member targets in the newer model.
+        to: ShapeID,
+    },
+    /// A trait was applied to a shape, or member.
+    TraitAdded {
+        /// The ID of the shape, or member, the trait is now applied to.
+        shape: ShapeID,
+        /// The ID of the added trait.
+        trait_id: ShapeID,
+        /// The value of the added trait, if any.
+        value: Option<Value>,
+    },
+    /// A trait was removed from a shape, or member.
+    TraitRemoved {
+        /// The ID of the shape, or member, the trait is no longer applied to.
+        shape: ShapeID,
+        /// The ID of the removed trait.
+        trait_id: ShapeID,
+        /// The value the removed trait had in the older model, if any.
+        value: Option<Value>,
+    },
+    /// The value of a trait applied to a shape, or member, changed.
+    TraitChanged {
+        /// The ID of the shape, or member, the trait is applied to.
+        shape: ShapeID,
+        /// The ID of the trait whose value changed.
+        trait_id: ShapeID,
+        /// The trait's value in the older model.
+        from: Option<Value>,
+        /// The trait's value in the newer model.
+        to: Option<Value>,
+    },
+    /// A reference from one shape to another was added; for example a mixin, an operation's input,
+    /// or a resource's read operation.
+    ReferenceAdded(Relationship),
+    /// A reference from one shape to another was removed.
+    ReferenceRemoved(Relationship),
+    /// An identifier was added to a resource.
+    IdentifierAdded {
+        /// The ID of the resource that gained the identifier.
+        resource: ShapeID,
+        /// The name of the added identifier.
+        name: Identifier,
+        /// The shape the added identifier targets.
+        target: ShapeID,
+    },
+    /// An identifier was removed from a resource.
+    IdentifierRemoved {
+        /// The ID of the resource that lost the identifier.
+        resource: ShapeID,
+        /// The name of the removed identifier.
+        name: Identifier,
+        /// The shape the removed identifier targeted.
+        target: ShapeID,
+    },
+    /// The target of a resource identifier changed.
+    IdentifierRetargeted {
+        /// The ID of the resource that declares the identifier.
+        resource: ShapeID,
+        /// The name of the identifier present in both models.
+        name: Identifier,
+        /// The shape the identifier targets in the older model.
+        from: ShapeID,
+        /// The shape the identifier targets in the newer model.
+        to: ShapeID,
+    },
+    /// The version of a service changed.
+    ServiceVersionChanged {
+        /// The ID of the service whose version changed.
+        service: ShapeID,
+        /// The service version string in the older model.
+        from: String,
+        /// The service version string in the newer model.
+        to: String,
+    },
+    /// A service's rename of a shape was added, removed, or changed.
+    ServiceRenameChanged {
+        /// The ID of the service declaring the rename.
+        service: ShapeID,
+        /// The ID of the renamed shape.
+        shape: ShapeID,
+        /// The local name given to the shape in the older model, if it was renamed.
+        from: Option<Identifier>,
+        /// The local name given to the shape in the newer model, if it is renamed.
+        to: Option<Identifier>,
+    },
+}
+
+///
+/// The complete set of changes between an older and a newer model.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModelDiff {
+    changes: Vec<Change>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SmithyVersionChanged { from, to } => {
+                write!(f, "smithy version changed: {} -> {}", from, to)
+            }
+            Self::MetadataAdded { key, value } => {
+                write!(f, "metadata added: {} = {}", key, value)
+            }
+            Self::MetadataRemoved { key, value } => {
+                write!(f, "metadata removed: {} = {}", key, value)
+            }
+            Self::MetadataChanged { key, from, to } => {
+                write!(f, "metadata changed: {} ({} -> {})", key, from, to)
+            }
+            Self::ShapeAdded { shape } => write!(f, "shape added: {}", shape),
+            Self::ShapeRemoved { shape } => write!(f, "shape removed: {}", shape),
+            Self::ShapeTypeChanged { shape, from, to } => {
+                write!(f, "shape type changed: {} ({} -> {})", shape, from, to)
+            }
+            Self::MemberAdded { member, target } => {
+                write!(f, "member added: {} -> {}", member, target)
+            }
+            Self::MemberRemoved { member, target } => {
+                write!(f, "member removed: {} -> {}", member, target)
+            }
+            Self::MemberRetargeted { member, from, to } => {
+                write!(f, "member retargeted: {} ({} -> {})", member, from, to)
+            }
+            Self::TraitAdded {
+                shape,
+                trait_id,
+                value,
+            } => write!(
+                f,
+                "trait added: {} @{}{}",
+                shape,
+                trait_id,
+                trait_value_str(value)
+            ),
+            Self::TraitRemoved {
+                shape,
+                trait_id,
+                value,
+            } => write!(
+                f,
+                "trait removed: {} @{}{}",
+                shape,
+                trait_id,
+                trait_value_str(value)
+            ),
+            Self::TraitChanged {
+                shape,
+                trait_id,
+                from,
+                to,
+            } => write!(
+                f,
+                "trait changed: {} @{} ({} -> {})",
+                shape,
+                trait_id,
+                optional_str(from),
+                optional_str(to)
+            ),
+            Self::ReferenceAdded(rel) => write!(f, "reference added: {}", rel),
+            Self::ReferenceRemoved(rel) => write!(f, "reference removed: {}", rel),
+            Self::IdentifierAdded {
+                resource,
+                name,
+                target,
+            } => write!(f, "identifier added: {} {} -> {}", resource, name, target),
+            Self::IdentifierRemoved {
+                resource,
+                name,
+                target,
+            } => write!(f, "identifier removed: {} {} -> {}", resource, name, target),
+            Self::IdentifierRetargeted {
+                resource,
+                name,
+                from,
+                to,
+            } => write!(
+                f,
+                "identifier retargeted: {} {} ({} -> {})",
+                resource, name, from, to
+            ),
+            Self::ServiceVersionChanged { service, from, to } => write!(
+                f,
+                "service version changed: {} ({} -> {})",
+                service, from, to
+            ),
+            Self::ServiceRenameChanged {
+                service,
+                shape,
+                from,
+                to,
+            } => write!(
+                f,
+                "service rename changed: {} {} ({} -> {})",
+                service,
+                shape,
+                optional_str(from),
+                optional_str(to)
+            ),
+        }
+    }
+}
+
+impl Change {
+    ///
+    /// Return the shape, or member, ID this change affects. Model-level changes, such as metadata,
+    /// return `None`.
+    ///
+    pub fn subject(&self) -> Option<&ShapeID> {
+        match self {
+            Self::SmithyVersionChanged { .. }
+            | Self::MetadataAdded { .. }
+            | Self::MetadataRemoved { .. }
+            | Self::MetadataChanged { .. } => None,
+            Self::ShapeAdded { shape }
+            | Self::ShapeRemoved { shape }
+            | Self::ShapeTypeChanged { shape, .. }
+            | Self::TraitAdded { shape, .. }
+            | Self::TraitRemoved { shape, .. }
+            | Self::TraitChanged { shape, .. } => Some(shape),
+            Self::MemberAdded { member, .. }
+            | Self::MemberRemoved { member, .. }
+            | Self::MemberRetargeted { member, .. } => Some(member),
+            Self::ReferenceAdded(rel) | Self::ReferenceRemoved(rel) => Some(rel.source()),
+            Self::IdentifierAdded { resource, .. }
+            | Self::IdentifierRemoved { resource, .. }
+            | Self::IdentifierRetargeted { resource, .. } => Some(resource),
+            Self::ServiceVersionChanged { service, .. }
+            | Self::ServiceRenameChanged { service, .. } => Some(service),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ModelDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+impl ModelDiff {
+    /// Compute the changes required to go from the `old` model to the `new` model.
+    pub fn new(old: &Model, new: &Model) -> Self {
+        let mut changes: Vec<Change> = Default::default();
+
+        if old.smithy_version() != new.smithy_version() {
+            changes.push(Change::SmithyVersionChanged {
+                from: *old.smithy_version(),
+                to: *new.smithy_version(),
+            });
+        }
+
+        diff_metadata(old, new, &mut changes);
+
+        let old_index = NeighborIndex::from(old);
+        let new_index = NeighborIndex::from(new);
+        let shape_ids: BTreeSet<&ShapeID> = old.shape_names().chain(new.shape_names()).collect();
+        for shape_id in shape_ids {
+            match (old.shape(shape_id), new.shape(shape_id)) {
+                (Some(_), None) => changes.push(Change::ShapeRemoved {
+                    shape: shape_id.clone(),
+                }),
+                (None, Some(_)) => changes.push(Change::ShapeAdded {
+                    shape: shape_id.clone(),
+                }),
+                (Some(old_shape), Some(new_shape)) => diff_shape(
+                    (old_shape, &old_index),
+                    (new_shape, &new_index),
+                    &mut changes,
+                ),
+                (None, None) => unreachable!(),
+            }
+        }
+
+        Self { changes }
+    }
+
+    /// Returns `true` if there are no changes between the two models, else `false`.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Return the number of changes between the two models.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Return an iterator over all the changes between the two models.
+    pub fn changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter()
+    }
+
+    /// Return an iterator over the IDs of all shapes added to the newer model.
+    pub fn added_shapes(&self) -> impl Iterator<Item = &ShapeID> {
+        self.changes.iter().filter_map(|change| match change {
+            Change::ShapeAdded { shape } => Some(shape),
+            _ => None,
+        })
+    }
+
+    /// Return an iterator over the IDs of all shapes removed from the older model.
+    pub fn removed_shapes(&self) -> impl Iterator<Item = &ShapeID> {
+        self.changes.iter().filter_map(|change| match change {
+            Change::ShapeRemoved { shape } => Some(shape),
+            _ => None,
+        })
+    }
+
+    ///
+    /// Return the IDs of all top-level shapes present in both models that have been modified in
+    /// any way, including changes to their members.
+    ///
+    pub fn modified_shapes(&self) -> Vec<ShapeID> {
+        let modified: BTreeSet<ShapeID> = self
+            .changes
+            .iter()
+            .filter(|change| {
+                !matches!(
+                    change,
+                    Change::ShapeAdded { .. } | Change::ShapeRemoved { .. }
+                )
+            })
+            .filter_map(|change| change.subject())
+            .map(|id| id.shape_only())
+            .collect();
+        modified.into_iter().collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn trait_value_str(value: &Option<Value>) -> String {
+    match value {
+        None => String::new(),
+        Some(value) => format!(" = {}", value),
+    }
+}
+
+fn optional_str<T: Display>(value: &Option<T>) -> String {
+    match value {
+        None => "none".to_string(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn type_name(body: &ShapeKind) -> String {
+    match body {
+        ShapeKind::Simple(s) => s.to_string(),
+        ShapeKind::List(_) => SHAPE_LIST.to_string(),
+        ShapeKind::Set(_) => SHAPE_SET.to_string(),
+        ShapeKind::Map(_) => SHAPE_MAP.to_string(),
+        ShapeKind::Structure(_) => SHAPE_STRUCTURE.to_string(),
+        ShapeKind::Union(_) => SHAPE_UNION.to_string(),
+        ShapeKind::Enum(_) => SHAPE_ENUM.to_string(),
+        ShapeKind::IntEnum(_) => SHAPE_INT_ENUM.to_string(),
+        ShapeKind::Service(_) => SHAPE_SERVICE.to_string(),
+        ShapeKind::Operation(_) => SHAPE_OPERATION.to_string(),
+        ShapeKind::Resource(_) => SHAPE_RESOURCE.to_string(),
+        ShapeKind::Unresolved => "?".to_string(),
+    }
+}
+
+fn diff_metadata(old: &Model, new: &Model, changes: &mut Vec<Change>) {
+    let keys: BTreeSet<&String> = old
+        .metadata()
+        .chain(new.metadata())
+        .map(|(key, _)| key)
+        .collect();
+    for key in keys {
+        match (old.metadata_value(key), new.metadata_value(key)) {
+            (Some(value), None) => changes.push(Change::MetadataRemoved {
+                key: key.clone(),
+                value: value.clone(),
+            }),
+            (None, Some(value)) => changes.push(Change::MetadataAdded {
+                key: key.clone(),
+                value: value.clone(),
+            }),
+            (Some(from), Some(to)) if from != to => changes.push(Change::MetadataChanged {
+                key: key.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+fn diff_shape(
+    (old, old_index): (&TopLevelShape, &NeighborIndex),
+    (new, new_index): (&TopLevelShape, &NeighborIndex),
+    changes: &mut Vec<Change>,
+) {
+    let shape_id = old.id();
+    let old_type = type_name(old.body());
+    let new_type = type_name(new.body());
+    if old_type != new_type {
+        changes.push(Change::ShapeTypeChanged {
+            shape: shape_id.clone(),
+            from: old_type,
+            to: new_type,
+        });
+        diff_traits(shape_id, old.traits(), new.traits(), changes);
+        return;
+    }
+
+    diff_members(old, new, changes);
+    diff_traits(shape_id, old.traits(), new.traits(), changes);
+
+    match (old.body(), new.body()) {
+        (ShapeKind::Service(old_service), ShapeKind::Service(new_service)) => {
+            if old_service.version() != new_service.version() {
+                changes.push(Change::ServiceVersionChanged {
+                    service: shape_id.clone(),
+                    from: old_service.version().clone(),
+                    to: new_service.version().clone(),
+                });
+            }
+            let old_renames: BTreeMap<&ShapeID, &Identifier> = old_service.renames().collect();
+            let new_renames: BTreeMap<&ShapeID, &Identifier> = new_service.renames().collect();
+            let renamed: BTreeSet<&ShapeID> = old_renames
+                .keys()
+                .chain(new_renames.keys())
+                .cloned()
+                .collect();
+            for renamed_id in renamed {
+                let from = old_renames.get(renamed_id).cloned();
+                let to = new_renames.get(renamed_id).cloned();
+                if from != to {
+                    changes.push(Change::ServiceRenameChanged {
+                        service: shape_id.clone(),
+                        shape: renamed_id.clone(),
+                        from: from.cloned(),
+                        to: to.cloned(),
+                    });
+                }
+            }
+        }
+        (ShapeKind::Resource(old_resource), ShapeKind::Resource(new_resource)) => {
+            let old_identifiers: BTreeMap<&Identifier, &ShapeID> =
+                old_resource.identifiers().collect();
+            let new_identifiers: BTreeMap<&Identifier, &ShapeID> =
+                new_resource.identifiers().collect();
+            let names: BTreeSet<&Identifier> = old_identifiers
+                .keys()
+                .chain(new_identifiers.keys())
+                .cloned()
+                .collect();
+            for name in names {
+                match (old_identifiers.get(name), new_identifiers.get(name)) {
+                    (Some(target), None) => changes.push(Change::IdentifierRemoved {
+                        resource: shape_id.clone(),
+                        name: name.clone(),
+                        target: (*target).clone(),
+                    }),
+                    (None, Some(target)) => changes.push(Change::IdentifierAdded {
+                        resource: shape_id.clone(),
+                        name: name.clone(),
+                        target: (*target).clone(),
+                    }),
+                    (Some(from), Some(to)) if from != to => {
+                        changes.push(Change::IdentifierRetargeted {
+                            resource: shape_id.clone(),
+                            name: name.clone(),
+                            from: (*from).clone(),
+                            to: (*to).clone(),
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+
+    diff_references(shape_id, old_index, new_index, changes);
+}
+
+fn diff_members(old: &TopLevelShape, new: &TopLevelShape, changes: &mut Vec<Change>) {
+    let shape_id = old.id();
+    let old_members: BTreeMap<&Identifier, _> = body_members(old.body())
+        .into_iter()
+        .map(|member| (member.id(), member))
+        .collect();
+    let new_members: BTreeMap<&Identifier, _> = body_members(new.body())
+        .into_iter()
+        .map(|member| (member.id(), member))
+        .collect();
+    let names: BTreeSet<&Identifier> = old_members
+        .keys()
+        .chain(new_members.keys())
+        .cloned()
+        .collect();
+    for name in names {
+        let member_id = shape_id.make_member(name.clone());
+        match (old_members.get(name), new_members.get(name)) {
+            (Some(member), None) => changes.push(Change::MemberRemoved {
+                member: member_id,
+                target: member.target().clone(),
+            }),
+            (None, Some(member)) => changes.push(Change::MemberAdded {
+                member: member_id,
+                target: member.target().clone(),
+            }),
+            (Some(old_member), Some(new_member)) => {
+                if old_member.target() != new_member.target() {
+                    changes.push(Change::MemberRetargeted {
+                        member: member_id.clone(),
+                        from: old_member.target().clone(),
+                        to: new_member.target().clone(),
+                    });
+                }
+                diff_traits(
+                    &member_id,
+                    old_member.traits(),
+                    new_member.traits(),
+                    changes,
+                );
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+}
+
+fn diff_traits(
+    shape_id: &ShapeID,
+    old: &AppliedTraits,
+    new: &AppliedTraits,
+    changes: &mut Vec<Change>,
+) {
+    let trait_ids: BTreeSet<&ShapeID> = old.keys().chain(new.keys()).collect();
+    for trait_id in trait_ids {
+        match (old.get(trait_id), new.get(trait_id)) {
+            (Some(value), None) => changes.push(Change::TraitRemoved {
+                shape: shape_id.clone(),
+                trait_id: trait_id.clone(),
+                value: value.clone(),
+            }),
+            (None, Some(value)) => changes.push(Change::TraitAdded {
+                shape: shape_id.clone(),
+                trait_id: trait_id.clone(),
+                value: value.clone(),
+            }),
+            (Some(from), Some(to)) if from != to => changes.push(Change::TraitChanged {
+                shape: shape_id.clone(),
+                trait_id: trait_id.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+fn diff_references(
+    shape_id: &ShapeID,
+    old_index: &NeighborIndex,
+    new_index: &NeighborIndex,
+    changes: &mut Vec<Change>,
+) {
+    let references = |index: &NeighborIndex| -> BTreeSet<Relationship> {
+        index
+            .relationships_from(shape_id)
+            .filter(|rel| {
+                !matches!(
+                    rel.kind(),
+                    RelationshipKind::Member
+                        | RelationshipKind::MemberTarget
+                        | RelationshipKind::Identifier
+                        | RelationshipKind::Trait
+                )
+            })
+            .cloned()
+            .collect()
+    };
+    let old_references = references(old_index);
+    let new_references = references(new_index);
+    for rel in old_references.difference(&new_references) {
+        changes.push(Change::ReferenceRemoved(rel.clone()));
+    }
+    for rel in new_references.difference(&old_references) {
+        changes.push(Change::ReferenceAdded(rel.clone()));
+    }
+}
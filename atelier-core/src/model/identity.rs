@@ -7,6 +7,11 @@ use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
 use std::str::FromStr;
 
+#[cfg(feature = "serde")]
+use serde::de::Error as _;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -84,6 +89,38 @@ where
     fn set_id(&mut self, id: T);
 }
 
+// ------------------------------------------------------------------------------------------------
+// Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Identifiers are serialized in their string form, and parsed from it when deserialized.
+///
+macro_rules! string_serde_impl {
+    ($type_name:ident) => {
+        #[cfg(feature = "serde")]
+        impl Serialize for $type_name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> Deserialize<'de> for $type_name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                Self::from_str(&s).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -121,6 +158,8 @@ impl FromStr for Identifier {
     }
 }
 
+string_serde_impl! { Identifier }
+
 impl PartialEq for Identifier {
     ///
     /// § 2.4.2. Shape ID member names
@@ -167,6 +206,8 @@ impl FromStr for NamespaceID {
     }
 }
 
+string_serde_impl! { NamespaceID }
+
 impl PartialEq for NamespaceID {
     ///
     /// § 2.4.2. Shape ID member names
@@ -254,6 +295,8 @@ impl FromStr for ShapeID {
     }
 }
 
+string_serde_impl! { ShapeID }
+
 impl ShapeID {
     ///
     /// Returns `true` if the provided string is a valid shape identifier representation, else `false`.
@@ -0,0 +1,116 @@
+use crate::model::ShapeID;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The location within a source representation from which a model element was read. Lines and
+/// columns are 1-based, the file name is only known when the model was read from a file.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    file: Option<String>,
+    line: usize,
+    column: usize,
+}
+
+///
+/// The source locations of the traits applied to a model element, keyed by trait identifier.
+///
+pub type TraitLocations = HashMap<ShapeID, SourceLocation>;
+
+///
+/// This trait is implemented by model elements that may record the location in a source
+/// representation they were read from, along with the location of each applied trait.
+///
+/// Note that source locations are informational only, they are never considered when comparing
+/// model elements for equality.
+///
+pub trait HasSourceLocation {
+    /// Returns `true` if the location of this model element is known, else `false`.
+    fn has_location(&self) -> bool {
+        self.location().is_some()
+    }
+
+    /// Return the location of this model element, if known.
+    fn location(&self) -> &Option<SourceLocation>;
+
+    /// Set the location of this model element.
+    fn set_location(&mut self, location: SourceLocation);
+
+    /// Set the location of this model element to `None`.
+    fn unset_location(&mut self);
+
+    /// Return the locations of all the traits applied to this model element.
+    fn trait_locations(&self) -> &TraitLocations;
+
+    /// Return the locations of all the traits applied to this model element in a mutable collection.
+    fn trait_locations_mut(&mut self) -> &mut TraitLocations;
+
+    /// Return the location of the application of the trait with the associated id, if known.
+    fn trait_location(&self, id: &ShapeID) -> Option<&SourceLocation> {
+        self.trait_locations().get(id)
+    }
+
+    /// Set the location of the application of the trait with the associated id.
+    fn set_trait_location(&mut self, id: ShapeID, location: SourceLocation) {
+        let _ = self.trait_locations_mut().insert(id, location);
+    }
+
+    /// Set the file name for the location of this model element, and all of its trait locations.
+    fn set_source_file(&mut self, file: &str) {
+        if let Some(location) = self.location() {
+            let mut location = location.clone();
+            location.set_file(file.to_string());
+            self.set_location(location);
+        }
+        for location in self.trait_locations_mut().values_mut() {
+            location.set_file(file.to_string());
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file, self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
+}
+
+impl SourceLocation {
+    /// Construct a new location, with no file name, from the provided line and column.
+    pub fn new(line: usize, column: usize) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+        }
+    }
+
+    /// Construct a new location from the provided file name, line, and column.
+    pub fn in_file(file: &str, line: usize, column: usize) -> Self {
+        Self {
+            file: Some(file.to_string()),
+            line,
+            column,
+        }
+    }
+
+    optional_member! { file, String }
+
+    required_member! { line, usize }
+
+    required_member! { column, usize }
+}
@@ -0,0 +1,393 @@
+/*!
+This module provides configurable conflict handling when merging models. `Model::merge` follows
+the Smithy specification and fails on the first conflict it finds, `Model::merge_with` instead takes
+a `MergePolicies` value which determines how each kind of conflict is handled and returns a
+`MergeReport` listing every conflict that was resolved.
+
+A conflict is only reported where the specification would otherwise require an error; shapes that
+are identical, traits and metadata values that are equal, and values that are both arrays are
+merged as usual and do not appear in the report.
+
+# Example
+
+```rust
+use atelier_core::builder::{ModelBuilder, StructureBuilder};
+use atelier_core::model::merge::{MergeConflict, MergePolicies, MergePolicy, MergeResolution};
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+let mut model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+    .structure(StructureBuilder::new("Thing").member("name", "String").into())
+    .try_into()
+    .unwrap();
+let other: Model = ModelBuilder::new(Version::V10, "smithy.example")
+    .structure(StructureBuilder::new("Thing").member("label", "String").into())
+    .try_into()
+    .unwrap();
+
+let mut policies = MergePolicies::default();
+policies.set_shape_policy(MergePolicy::KeepFirst);
+
+let report = model.merge_with(other, &policies).unwrap();
+let thing = ShapeID::from_str("smithy.example#Thing").unwrap();
+assert_eq!(
+    report.conflicts().collect::<Vec<_>>(),
+    vec![&(MergeConflict::Shape(thing.clone()), MergeResolution::KeptFirst)]
+);
+assert!(model.shape(&thing).unwrap().has_member(&"name".parse().unwrap()));
+```
+*/
+
+use crate::error::{Error, ErrorKind, Result as ModelResult};
+use crate::model::shapes::{
+    merge_traits, HasTraits, NonTraitEq, ShapeKind, TopLevelShape, TraitValue,
+};
+use crate::model::values::Value;
+use crate::model::{HasIdentity, HasSourceLocation, Model, ShapeID};
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A function used to resolve a conflict, it is called with the conflict and the first (existing)
+/// and last (incoming) values, and returns the value to keep.
+///
+pub type MergeResolver<T> = Arc<dyn Fn(&MergeConflict, &T, &T) -> ModelResult<T> + Send + Sync>;
+
+///
+/// The policy used to resolve a single kind of conflict.
+///
+#[derive(Clone, Default)]
+pub enum MergePolicy<T> {
+    /// Fail the merge with an error, this is the behavior required by the Smithy specification.
+    #[default]
+    Fail,
+    /// Keep the value already in the model, discarding the incoming one.
+    KeepFirst,
+    /// Keep the incoming value, replacing the one already in the model.
+    KeepLast,
+    /// Call the provided function to determine the value to keep.
+    Resolve(MergeResolver<T>),
+}
+
+///
+/// The set of policies used by `Model::merge_with`, one for each kind of conflict. The default
+/// value uses `MergePolicy::Fail` for all conflicts.
+///
+#[derive(Clone, Debug, Default)]
+pub struct MergePolicies {
+    shape_policy: MergePolicy<TopLevelShape>,
+    trait_policy: MergePolicy<TraitValue>,
+    metadata_policy: MergePolicy<Value>,
+}
+
+///
+/// Identifies a conflict found while merging.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MergeConflict {
+    /// Two shapes with the same identifier but a different type, members, or properties.
+    Shape(ShapeID),
+    /// Two different values for the same trait applied to the same shape.
+    Trait {
+        /// The shape the trait is applied to.
+        shape_id: ShapeID,
+        /// The conflicting trait.
+        trait_id: ShapeID,
+    },
+    /// Two different values, that are not both arrays, for the same metadata key.
+    Metadata(String),
+}
+
+///
+/// How a conflict was resolved.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MergeResolution {
+    /// The value already in the model was kept.
+    KeptFirst,
+    /// The incoming value replaced the one already in the model.
+    KeptLast,
+    /// The value was determined by a `MergePolicy::Resolve` function.
+    Resolved,
+}
+
+///
+/// The list of conflicts, and their resolution, found during a merge; in the order they were
+/// found.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MergeReport {
+    conflicts: Vec<(MergeConflict, MergeResolution)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<T> Debug for MergePolicy<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fail => write!(f, "Fail"),
+            Self::KeepFirst => write!(f, "KeepFirst"),
+            Self::KeepLast => write!(f, "KeepLast"),
+            Self::Resolve(_) => write!(f, "Resolve(..)"),
+        }
+    }
+}
+
+impl<T: Clone> MergePolicy<T> {
+    fn apply(
+        &self,
+        conflict: MergeConflict,
+        first: &T,
+        last: &T,
+        report: &mut MergeReport,
+    ) -> ModelResult<T> {
+        let (value, resolution) = match self {
+            Self::Fail => {
+                error!("merge conflict: {}", conflict);
+                return Err(conflict.into());
+            }
+            Self::KeepFirst => (first.clone(), MergeResolution::KeptFirst),
+            Self::KeepLast => (last.clone(), MergeResolution::KeptLast),
+            Self::Resolve(resolver) => {
+                (resolver(&conflict, first, last)?, MergeResolution::Resolved)
+            }
+        };
+        report.conflicts.push((conflict, resolution));
+        Ok(value)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl MergePolicies {
+    /// Construct a set of policies that keep the value already in the model for all conflicts.
+    pub fn keep_first() -> Self {
+        Self {
+            shape_policy: MergePolicy::KeepFirst,
+            trait_policy: MergePolicy::KeepFirst,
+            metadata_policy: MergePolicy::KeepFirst,
+        }
+    }
+
+    /// Construct a set of policies that keep the incoming value for all conflicts.
+    pub fn keep_last() -> Self {
+        Self {
+            shape_policy: MergePolicy::KeepLast,
+            trait_policy: MergePolicy::KeepLast,
+            metadata_policy: MergePolicy::KeepLast,
+        }
+    }
+
+    required_member! { shape_policy, MergePolicy<TopLevelShape> }
+
+    required_member! { trait_policy, MergePolicy<TraitValue> }
+
+    required_member! { metadata_policy, MergePolicy<Value> }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for MergeConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shape(shape_id) => write!(f, "shape conflict: {}", shape_id),
+            Self::Trait { shape_id, trait_id } => {
+                write!(f, "trait conflict: {} on {}", trait_id, shape_id)
+            }
+            Self::Metadata(key) => write!(f, "metadata conflict: {}", key),
+        }
+    }
+}
+
+impl From<MergeConflict> for Error {
+    fn from(conflict: MergeConflict) -> Self {
+        match conflict {
+            MergeConflict::Shape(shape_id) => ErrorKind::MergeShapeConflict(shape_id),
+            MergeConflict::Trait { trait_id, .. } => ErrorKind::MergeTraitConflict(trait_id),
+            MergeConflict::Metadata(key) => ErrorKind::MergeMetadataConflict(key),
+        }
+        .into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for MergeResolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::KeptFirst => "kept first",
+                Self::KeptLast => "kept last",
+                Self::Resolved => "resolved",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for MergeReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (conflict, resolution) in &self.conflicts {
+            writeln!(f, "{} ({})", conflict, resolution)?;
+        }
+        Ok(())
+    }
+}
+
+impl MergeReport {
+    /// Returns `true` if no conflicts were found, else `false`.
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Returns the number of conflicts found.
+    pub fn len(&self) -> usize {
+        self.conflicts.len()
+    }
+
+    /// Return an iterator over the conflicts found, and how each was resolved.
+    pub fn conflicts(&self) -> impl Iterator<Item = &(MergeConflict, MergeResolution)> {
+        self.conflicts.iter()
+    }
+
+    /// Add all the conflicts from `other` to the end of this report.
+    pub fn append(&mut self, other: MergeReport) {
+        self.conflicts.extend(other.conflicts);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Add the metadata key/value pair to `model`, see `Model::add_metadata` for the merge rules.
+///
+pub(crate) fn merge_metadata(
+    model: &mut Model,
+    key: String,
+    value: Value,
+    policies: &MergePolicies,
+    report: &mut MergeReport,
+) -> ModelResult<Option<Value>> {
+    Ok(match model.metadata.get(&key) {
+        Some(Value::Array(existing)) if value.is_array() => {
+            let mut merged = existing.clone();
+            merged.extend(value.as_array().unwrap().iter().cloned());
+            model.metadata.insert(key, Value::Array(merged))
+        }
+        // name conflict is ignored.
+        Some(existing) if *existing == value => None,
+        Some(existing) => {
+            let resolved = policies.metadata_policy.apply(
+                MergeConflict::Metadata(key.clone()),
+                existing,
+                &value,
+                report,
+            )?;
+            model.metadata.insert(key, resolved)
+        }
+        None => model.metadata.insert(key, value),
+    })
+}
+
+///
+/// Add the shape to `model`, see `Model::add_shape` for the merge rules.
+///
+pub(crate) fn merge_shape(
+    model: &mut Model,
+    shape: TopLevelShape,
+    policies: &MergePolicies,
+    report: &mut MergeReport,
+) -> ModelResult<()> {
+    if shape.id().is_member() && !matches!(shape.body(), ShapeKind::Unresolved) {
+        error!(
+            "Model::add_shape '{}' is a member ID; only allowed for unresolved shapes",
+            shape.id()
+        );
+        return Err(ErrorKind::ShapeIDExpected(shape.id().clone()).into());
+    } else if let Some(existing) = model.shapes.get_mut(shape.id()) {
+        // > 1. All conflicting shapes MUST have the same shape type.
+        // > 1. Conflicting aggregate shapes MUST contain the same members that target the same shapes.
+        // > 1. Conflicting service shapes MUST contain the same properties and target the same shapes.
+        match (
+            existing.body().is_unresolved(),
+            shape.body().is_unresolved(),
+        ) {
+            // TODO: This does not deal with unresolved member shapes, only top-level.
+            (false, false) => {
+                if existing.equal_without_traits(&shape) {
+                    merge_shape_traits(existing, &shape, policies, report)?;
+                } else {
+                    *existing = policies.shape_policy.apply(
+                        MergeConflict::Shape(existing.id().clone()),
+                        existing,
+                        &shape,
+                        report,
+                    )?;
+                }
+            }
+            (true, false) => {
+                existing.set_body(shape.body().clone());
+                for mixin in shape.mixins() {
+                    existing.add_mixin(mixin.clone());
+                }
+                if let Some(location) = shape.location() {
+                    existing.set_location(location.clone());
+                }
+                merge_shape_traits(existing, &shape, policies, report)?;
+            }
+            _ => {
+                merge_shape_traits(existing, &shape, policies, report)?;
+            }
+        }
+    } else {
+        let _ = model.shapes.insert(shape.id().clone(), shape);
+    }
+    Ok(())
+}
+
+fn merge_shape_traits(
+    to_shape: &mut TopLevelShape,
+    from_shape: &TopLevelShape,
+    policies: &MergePolicies,
+    report: &mut MergeReport,
+) -> ModelResult<()> {
+    for (id, value) in from_shape.traits() {
+        match to_shape.trait_named(id).cloned() {
+            Some(existing) if !id.is_member() => {
+                let merged = match merge_traits(id, &existing, value) {
+                    Ok(merged) => merged,
+                    Err(_) => policies.trait_policy.apply(
+                        MergeConflict::Trait {
+                            shape_id: to_shape.id().clone(),
+                            trait_id: id.clone(),
+                        },
+                        &existing,
+                        value,
+                        report,
+                    )?,
+                };
+                let _ = to_shape.traits_mut().insert(id.clone(), merged);
+            }
+            _ => to_shape.apply_with_value(id.clone(), value.clone())?,
+        }
+    }
+    for (id, location) in from_shape.trait_locations() {
+        if to_shape.trait_location(id).is_none() {
+            to_shape.set_trait_location(id.clone(), location.clone());
+        }
+    }
+    Ok(())
+}
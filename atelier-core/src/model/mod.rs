@@ -5,11 +5,19 @@ For more information, see [the Rust Atelier book](https://rust-atelier.dev/using
 */
 
 use crate::error::{ErrorKind, Result as ModelResult};
-use crate::model::shapes::{HasTraits, NonTraitEq, ShapeKind, TopLevelShape};
+use crate::model::merge::{merge_metadata, merge_shape, MergePolicies, MergeReport};
+use crate::model::shapes::{
+    AppliedTraits, HasTraits, MemberShape, Service, ShapeKind, TopLevelShape,
+};
 use crate::model::values::{Value, ValueMap};
+use crate::prelude::{PRELUDE_NAMESPACE, TRAIT_MIXIN};
+use crate::syntax::MEMBER_LOCAL_TRAITS;
 use crate::Version;
-use std::collections::{HashMap, HashSet};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::Debug;
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -23,6 +31,7 @@ use std::fmt::Debug;
 /// * Any metadata associated with the model (with the `metadata` statement).
 /// * A map of shapes declared by the model.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Model {
     pub(crate) smithy_version: Version,
@@ -89,6 +98,19 @@ impl Model {
     /// > until all model files are loaded.
     ///
     pub fn merge(&mut self, other: Model) -> ModelResult<()> {
+        self.merge_with(other, &Default::default()).map(|_| ())
+    }
+
+    ///
+    /// Merge the other model into this one, as for `merge`, however conflicts between shapes,
+    /// traits, and metadata values are handled according to the provided `policies` rather than
+    /// always resulting in an error. The returned report lists every conflict that was resolved.
+    ///
+    pub fn merge_with(
+        &mut self,
+        other: Model,
+        policies: &MergePolicies,
+    ) -> ModelResult<MergeReport> {
         // Ensure version match
         if other.smithy_version != self.smithy_version {
             return Err(ErrorKind::MergeVersionConflict(
@@ -98,15 +120,21 @@ impl Model {
             .into());
         }
 
-        for (key, value) in other.metadata {
-            let _ = self.add_metadata(key, value)?;
+        let mut report = MergeReport::default();
+
+        let mut metadata: Vec<(String, Value)> = other.metadata.into_iter().collect();
+        metadata.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        for (key, value) in metadata {
+            let _ = merge_metadata(self, key, value, policies, &mut report)?;
         }
 
-        for (_, shape) in other.shapes {
-            let _ = self.add_shape(shape)?;
+        let mut shapes: Vec<(ShapeID, TopLevelShape)> = other.shapes.into_iter().collect();
+        shapes.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        for (_, shape) in shapes {
+            merge_shape(self, shape, policies, &mut report)?;
         }
 
-        Ok(())
+        Ok(report)
     }
 
     // --------------------------------------------------------------------------------------------
@@ -125,7 +153,7 @@ impl Model {
 
     /// Returns `true` if this model's **metadata** collection has a shape with the provided key`, else `false`.
     pub fn has_metadata_value(&self, key: &str) -> bool {
-        !self.metadata.contains_key(key)
+        self.metadata.contains_key(key)
     }
 
     /// Returns the value in this model's **metadata** collection with the provide key.
@@ -153,26 +181,18 @@ impl Model {
     /// >    values are not equal, then the key is invalid and there is a metadata conflict error.
     ///
     pub fn add_metadata(&mut self, key: String, value: Value) -> ModelResult<Option<Value>> {
-        Ok(if let Some(self_value) = self.metadata_value(&key) {
-            if self_value.is_array() && value.is_array() {
-                let mut self_array = self_value.as_array().unwrap().clone();
-                let other_array = value.as_array().unwrap();
-                self_array.extend(other_array.iter().cloned());
-                self.metadata.insert(key.clone(), Value::Array(self_array))
-            } else if *self_value == value {
-                // name conflict is ignored.
-                None
-            } else {
-                return Err(ErrorKind::MergeMetadataConflict(key.clone()).into());
-            }
-        } else {
-            self.metadata.insert(key.clone(), value)
-        })
+        merge_metadata(
+            self,
+            key,
+            value,
+            &Default::default(),
+            &mut Default::default(),
+        )
     }
 
     /// Remove the value with the associated key, from this model's **metadata** collection.
     pub fn remove_metadata(&mut self, key: &str) -> Option<Value> {
-        self.metadata.remove(key)
+        self.metadata.shift_remove(key)
     }
 
     /// Return an iterator over all key/value pairs in this model's **metadata** collection.
@@ -189,7 +209,7 @@ impl Model {
 
     /// Returns `true` if this model's **shapes** collection has a shape with the provided `ShapeID`, else `false`.
     pub fn has_shape(&self, shape_id: &ShapeID) -> bool {
-        !self.shapes.contains_key(shape_id)
+        self.shapes.contains_key(shape_id)
     }
 
     /// Returns the shape in this model's **shapes** collection with the provided `ShapeID`.
@@ -215,41 +235,7 @@ impl Model {
     /// >    1. Conflicting service shapes MUST contain the same properties and target the same shapes.
     ///
     pub fn add_shape(&mut self, shape: TopLevelShape) -> ModelResult<()> {
-        if shape.id().is_member() && !matches!(shape.body(), ShapeKind::Unresolved) {
-            error!(
-                "Model::add_shape '{}' is a member ID; only allowed for unresolved shapes",
-                shape.id()
-            );
-            return Err(ErrorKind::ShapeIDExpected(shape.id().clone()).into());
-        } else if let Some(existing) = self.shape_mut(shape.id()) {
-            // > 1. All conflicting shapes MUST have the same shape type.
-            // > 1. Conflicting aggregate shapes MUST contain the same members that target the same shapes.
-            // > 1. Conflicting service shapes MUST contain the same properties and target the same shapes.
-            match (
-                existing.body().is_unresolved(),
-                shape.body().is_unresolved(),
-            ) {
-                // TODO: This does not deal with unresolved member shapes, only top-level.
-                (false, false) => {
-                    if existing.equal_without_traits(&shape) {
-                        copy_traits(existing, &shape)?;
-                    } else {
-                        error!("Model::add_shape {:?} != {:?}", existing, shape);
-                        return Err(ErrorKind::MergeShapeConflict(existing.id().clone()).into());
-                    }
-                }
-                (true, false) => {
-                    existing.set_body(shape.body().clone());
-                    copy_traits(existing, &shape)?;
-                }
-                _ => {
-                    copy_traits(existing, &shape)?;
-                }
-            }
-        } else {
-            let _ = self.shapes.insert(shape.id().clone(), shape);
-        }
-        Ok(())
+        merge_shape(self, shape, &Default::default(), &mut Default::default())
     }
 
     /// Remove any element, equal to the provided value, from this model's **shapes** collection.
@@ -287,6 +273,129 @@ impl Model {
 
     // --------------------------------------------------------------------------------------------
 
+    ///
+    /// Return the source location of the shape, or member, with the provided identifier; this
+    /// will return `None` if the shape or member cannot be found, or if its location is not known.
+    ///
+    pub fn location_of(&self, shape_id: &ShapeID) -> Option<&SourceLocation> {
+        let shape = self.shape(&shape_id.shape_only())?;
+        match shape_id.member_name() {
+            None => shape.location().as_ref(),
+            Some(member_name) => shape.member(member_name)?.location().as_ref(),
+        }
+    }
+
+    ///
+    /// Set the file name on all the source locations recorded in this model, this is used by
+    /// tools that read models from files where the reader itself only has access to the content.
+    ///
+    pub fn set_source_file(&mut self, file: &str) {
+        for shape in self.shapes.values_mut() {
+            shape.set_source_file(file);
+            for member in body_members_mut(shape.body_mut()) {
+                member.set_source_file(file);
+            }
+        }
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Return the effective members of the shape with the provided identifier; this includes all
+    /// members declared on the shape itself as well as all those inherited from its mixins. Mixins
+    /// are processed in declaration order, and where a shape re-declares an inherited member its
+    /// traits take precedence over those of the inherited member.
+    ///
+    /// This will return an error if the shape, or any of its mixins, cannot be found, if any
+    /// mixin is not marked with the `mixin` trait, or if a member is re-declared with a different
+    /// target.
+    ///
+    pub fn effective_members(&self, shape_id: &ShapeID) -> ModelResult<Vec<MemberShape>> {
+        self.effective_members_inner(shape_id, &mut Default::default())
+    }
+
+    ///
+    /// Return the effective traits of the shape with the provided identifier; this includes all
+    /// traits applied to the shape itself as well as all those inherited from its mixins. Traits
+    /// applied to the shape itself take precedence over inherited traits, and the `mixin` trait,
+    /// as well as any trait listed in a mixin's `localTraits`, is never inherited.
+    ///
+    /// This will return an error if the shape, or any of its mixins, cannot be found, or if any
+    /// mixin is not marked with the `mixin` trait.
+    ///
+    pub fn effective_traits(&self, shape_id: &ShapeID) -> ModelResult<AppliedTraits> {
+        self.effective_traits_inner(shape_id, &mut Default::default())
+    }
+
+    fn effective_members_inner(
+        &self,
+        shape_id: &ShapeID,
+        visiting: &mut Vec<ShapeID>,
+    ) -> ModelResult<Vec<MemberShape>> {
+        let shape = self.enter_mixin_shape(shape_id, visiting)?;
+        let mut members: Vec<MemberShape> = Default::default();
+        for mixin_id in shape.mixins() {
+            let _ = self.mixin_shape(shape_id, mixin_id)?;
+            for member in self.effective_members_inner(mixin_id, visiting)? {
+                merge_member(shape_id, &mut members, member)?;
+            }
+        }
+        for member in body_members(shape.body()) {
+            merge_member(shape_id, &mut members, member.clone())?;
+        }
+        let _ = visiting.pop();
+        Ok(members)
+    }
+
+    fn effective_traits_inner(
+        &self,
+        shape_id: &ShapeID,
+        visiting: &mut Vec<ShapeID>,
+    ) -> ModelResult<AppliedTraits> {
+        let shape = self.enter_mixin_shape(shape_id, visiting)?;
+        let mut traits: AppliedTraits = Default::default();
+        for mixin_id in shape.mixins() {
+            let mixin = self.mixin_shape(shape_id, mixin_id)?;
+            let local_traits = mixin_local_traits(mixin);
+            for (id, value) in self.effective_traits_inner(mixin_id, visiting)? {
+                if !local_traits.contains(&id) {
+                    let _ = traits.insert(id, value);
+                }
+            }
+        }
+        for (id, value) in shape.traits() {
+            let _ = traits.insert(id.clone(), value.clone());
+        }
+        let _ = visiting.pop();
+        Ok(traits)
+    }
+
+    fn enter_mixin_shape(
+        &self,
+        shape_id: &ShapeID,
+        visiting: &mut Vec<ShapeID>,
+    ) -> ModelResult<&TopLevelShape> {
+        if visiting.contains(shape_id) {
+            return Err(ErrorKind::MixinCycle(shape_id.clone()).into());
+        }
+        match self.shape(shape_id) {
+            None => Err(ErrorKind::UnknownShape(shape_id.to_string()).into()),
+            Some(shape) => {
+                visiting.push(shape_id.clone());
+                Ok(shape)
+            }
+        }
+    }
+
+    fn mixin_shape(&self, shape_id: &ShapeID, mixin_id: &ShapeID) -> ModelResult<&TopLevelShape> {
+        match self.shape(mixin_id) {
+            Some(mixin) if mixin.is_mixin() => Ok(mixin),
+            _ => Err(ErrorKind::InvalidMixin(shape_id.to_string(), mixin_id.to_string()).into()),
+        }
+    }
+
+    // --------------------------------------------------------------------------------------------
+
     ///
     /// This returns `true` if the model contains **no** unresolved shape identifiers (see
     /// `ShapeKind::Unresolved`). An incomplete model **should** always result in a validation
@@ -301,26 +410,137 @@ impl Model {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-#[inline]
-fn copy_traits(to_shape: &mut TopLevelShape, from_shape: &TopLevelShape) -> ModelResult<()> {
-    for (id, value) in from_shape.traits() {
-        to_shape.apply_with_value(id.clone(), value.clone())?;
+///
+/// Return all the shapes in `model` ordered by identifier, so that issues are reported in a
+/// stable order.
+///
+pub(crate) fn sorted_shapes(model: &Model) -> Vec<&TopLevelShape> {
+    let mut shapes: Vec<&TopLevelShape> = model.shapes().collect();
+    shapes.sort_by_key(|shape| shape.id());
+    shapes
+}
+
+pub(crate) fn body_members(body: &ShapeKind) -> Vec<&MemberShape> {
+    match body {
+        ShapeKind::List(v) | ShapeKind::Set(v) => vec![v.member()],
+        ShapeKind::Map(v) => vec![v.key(), v.value()],
+        ShapeKind::Structure(v) | ShapeKind::Union(v) => v.members().collect(),
+        ShapeKind::Enum(v) | ShapeKind::IntEnum(v) => v.members().collect(),
+        _ => Default::default(),
+    }
+}
+
+///
+/// Return the operations bound to a service, directly or through its resources and their child
+/// resources, in order.
+///
+pub(crate) fn service_operations<'a>(
+    model: &'a Model,
+    service: &'a Service,
+) -> BTreeSet<&'a ShapeID> {
+    let mut operations: BTreeSet<&ShapeID> = service.operations().collect();
+    let mut visited: BTreeSet<&ShapeID> = Default::default();
+    let mut pending: Vec<&ShapeID> = service.resources().collect();
+    while let Some(resource_id) = pending.pop() {
+        if !visited.insert(resource_id) {
+            continue;
+        }
+        if let Some(ShapeKind::Resource(resource)) = model.shape(resource_id).map(|s| s.body()) {
+            operations.extend(
+                [
+                    resource.create(),
+                    resource.put(),
+                    resource.read(),
+                    resource.update(),
+                    resource.delete(),
+                    resource.list(),
+                ]
+                .iter()
+                .filter_map(|operation_id| operation_id.as_ref()),
+            );
+            operations.extend(resource.operations());
+            operations.extend(resource.collection_operations());
+            pending.extend(resource.resources());
+        }
+    }
+    operations
+}
+
+fn body_members_mut(body: &mut ShapeKind) -> Vec<&mut MemberShape> {
+    match body {
+        ShapeKind::List(v) | ShapeKind::Set(v) => vec![&mut v.member],
+        ShapeKind::Map(v) => vec![&mut v.key, &mut v.value],
+        ShapeKind::Structure(v) | ShapeKind::Union(v) => v.members.values_mut().collect(),
+        ShapeKind::Enum(v) | ShapeKind::IntEnum(v) => v.members.values_mut().collect(),
+        _ => Default::default(),
+    }
+}
+
+fn merge_member(
+    shape_id: &ShapeID,
+    members: &mut Vec<MemberShape>,
+    member: MemberShape,
+) -> ModelResult<()> {
+    match members.iter_mut().find(|m| m.id() == member.id()) {
+        None => members.push(member),
+        Some(existing) => {
+            if existing.target() != member.target() {
+                return Err(ErrorKind::MergeShapeConflict(
+                    shape_id.make_member(member.id().clone()),
+                )
+                .into());
+            }
+            for (id, value) in member.traits() {
+                let _ = existing.traits_mut().insert(id.clone(), value.clone());
+            }
+        }
     }
     Ok(())
 }
 
+fn mixin_local_traits(mixin: &TopLevelShape) -> Vec<ShapeID> {
+    let mixin_trait = ShapeID::new_unchecked(PRELUDE_NAMESPACE, TRAIT_MIXIN, None);
+    let mut local_traits = vec![mixin_trait.clone()];
+    if let Some(Some(Value::Object(value))) = mixin.trait_named(&mixin_trait) {
+        if let Some(Value::Array(ids)) = value.get(MEMBER_LOCAL_TRAITS) {
+            local_traits.extend(
+                ids.iter()
+                    .filter_map(|id| id.as_string())
+                    .filter_map(|id| ShapeID::from_str(id).ok()),
+            );
+        }
+    }
+    local_traits
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
 
+pub mod conformance;
+
+pub mod diff;
+
 #[doc(hidden)]
 pub mod identity;
 pub use identity::{HasIdentity, Identifier, NamespaceID, ShapeID};
 
+#[doc(hidden)]
+pub mod location;
+pub use location::{HasSourceLocation, SourceLocation, TraitLocations};
+
+pub mod merge;
+
+pub mod neighbor;
+
+pub mod paginated;
+
 pub mod selector;
 
 pub mod shapes;
 
+pub mod traits;
+
 pub mod values;
 
 pub mod visitor;
@@ -0,0 +1,342 @@
+/*!
+This module provides an index of the relationships between shapes in a model. The index is built
+once from a `Model` and records each relationship in both directions, so that it is possible to
+ask both "what does this shape refer to" and "which shapes refer to this one" without re-walking
+the model.
+
+# Example
+
+```rust
+use atelier_core::builder::{ModelBuilder, OperationBuilder, StructureBuilder};
+use atelier_core::model::neighbor::{NeighborIndex, RelationshipKind};
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+let model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+    .operation(OperationBuilder::new("GetThing").input("GetThingInput").into())
+    .structure(StructureBuilder::new("GetThingInput").member("name", "String").into())
+    .try_into()
+    .unwrap();
+
+let index = NeighborIndex::from(&model);
+
+let input = ShapeID::from_str("smithy.example#GetThingInput").unwrap();
+let operation = ShapeID::from_str("smithy.example#GetThing").unwrap();
+assert!(index
+    .relationships_to(&input)
+    .any(|rel| rel.source() == &operation && rel.kind() == &RelationshipKind::Input));
+```
+*/
+
+use crate::model::shapes::{HasTraits, MemberShape, ShapeKind, TopLevelShape};
+use crate::model::{body_members, HasIdentity, Model, ShapeID};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The kind of a relationship between two shapes, the names follow the corresponding property or
+/// member names in the Smithy specification.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RelationshipKind {
+    /// An aggregate shape to one of its members, the target is the member's shape ID.
+    Member,
+    /// A member to the shape it targets.
+    MemberTarget,
+    /// A shape to one of the mixins it uses.
+    Mixin,
+    /// A service, or resource, to one of its `operations`.
+    Operation,
+    /// A service, or resource, to one of its `resources`.
+    Resource,
+    /// A resource to one of its `collectionOperations`.
+    CollectionOperation,
+    /// A resource to its `create` lifecycle operation.
+    Create,
+    /// A resource to its `put` lifecycle operation.
+    Put,
+    /// A resource to its `read` lifecycle operation.
+    Read,
+    /// A resource to its `update` lifecycle operation.
+    Update,
+    /// A resource to its `delete` lifecycle operation.
+    Delete,
+    /// A resource to its `list` lifecycle operation.
+    List,
+    /// A resource to the target of one of its `identifiers`.
+    Identifier,
+    /// An operation to its `input` structure.
+    Input,
+    /// An operation to its `output` structure.
+    Output,
+    /// An operation to one of its `errors`.
+    Error,
+    /// A shape, or member, to a trait applied to it.
+    Trait,
+}
+
+///
+/// A single, directed, relationship between a source shape and a target shape. Either may be a
+/// member shape ID, for example the source of a `MemberTarget` relationship.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Relationship {
+    source: ShapeID,
+    kind: RelationshipKind,
+    target: ShapeID,
+}
+
+///
+/// An index of all the relationships in a model, in both directions. Note that the index is a
+/// snapshot, it is not updated if the model it was built from subsequently changes.
+///
+#[derive(Clone, Debug, Default)]
+pub struct NeighborIndex {
+    relationships: Vec<Relationship>,
+    outgoing: HashMap<ShapeID, Vec<usize>>,
+    incoming: HashMap<ShapeID, Vec<usize>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for RelationshipKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Member => "member",
+                Self::MemberTarget => "target",
+                Self::Mixin => "mixin",
+                Self::Operation => "operation",
+                Self::Resource => "resource",
+                Self::CollectionOperation => "collectionOperation",
+                Self::Create => "create",
+                Self::Put => "put",
+                Self::Read => "read",
+                Self::Update => "update",
+                Self::Delete => "delete",
+                Self::List => "list",
+                Self::Identifier => "identifier",
+                Self::Input => "input",
+                Self::Output => "output",
+                Self::Error => "error",
+                Self::Trait => "trait",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Relationship {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -[{}]-> {}", self.source, self.kind, self.target)
+    }
+}
+
+impl Relationship {
+    /// Construct a new relationship of `kind` from `source` to `target`.
+    pub fn new(source: ShapeID, kind: RelationshipKind, target: ShapeID) -> Self {
+        Self {
+            source,
+            kind,
+            target,
+        }
+    }
+
+    /// Return the shape ID this relationship is from.
+    pub fn source(&self) -> &ShapeID {
+        &self.source
+    }
+
+    /// Return the kind of this relationship.
+    pub fn kind(&self) -> &RelationshipKind {
+        &self.kind
+    }
+
+    /// Return the shape ID this relationship is to.
+    pub fn target(&self) -> &ShapeID {
+        &self.target
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<&Model> for NeighborIndex {
+    fn from(model: &Model) -> Self {
+        let mut index = Self::default();
+        for shape in model.shapes() {
+            index.add_shape(shape);
+        }
+        index
+    }
+}
+
+impl NeighborIndex {
+    /// Returns `true` if there are no relationships in this index, else `false`.
+    pub fn is_empty(&self) -> bool {
+        self.relationships.is_empty()
+    }
+
+    /// Return the number of relationships in this index.
+    pub fn len(&self) -> usize {
+        self.relationships.len()
+    }
+
+    /// Return an iterator over all the relationships in this index.
+    pub fn relationships(&self) -> impl Iterator<Item = &Relationship> {
+        self.relationships.iter()
+    }
+
+    /// Return an iterator over all the relationships whose source is `shape_id`.
+    pub fn relationships_from<'a>(
+        &'a self,
+        shape_id: &ShapeID,
+    ) -> impl Iterator<Item = &'a Relationship> {
+        self.lookup(&self.outgoing, shape_id)
+    }
+
+    /// Return an iterator over all the relationships whose target is `shape_id`.
+    pub fn relationships_to<'a>(
+        &'a self,
+        shape_id: &ShapeID,
+    ) -> impl Iterator<Item = &'a Relationship> {
+        self.lookup(&self.incoming, shape_id)
+    }
+
+    /// Return an iterator over the relationships of `kind` whose source is `shape_id`.
+    pub fn relationships_from_of_kind<'a>(
+        &'a self,
+        shape_id: &ShapeID,
+        kind: RelationshipKind,
+    ) -> impl Iterator<Item = &'a Relationship> {
+        self.relationships_from(shape_id)
+            .filter(move |rel| rel.kind == kind)
+    }
+
+    /// Return an iterator over the relationships of `kind` whose target is `shape_id`.
+    pub fn relationships_to_of_kind<'a>(
+        &'a self,
+        shape_id: &ShapeID,
+        kind: RelationshipKind,
+    ) -> impl Iterator<Item = &'a Relationship> {
+        self.relationships_to(shape_id)
+            .filter(move |rel| rel.kind == kind)
+    }
+
+    /// Return an iterator over the targets of all relationships whose source is `shape_id`.
+    pub fn neighbors<'a>(&'a self, shape_id: &ShapeID) -> impl Iterator<Item = &'a ShapeID> {
+        self.relationships_from(shape_id).map(|rel| &rel.target)
+    }
+
+    ///
+    /// Return an iterator over the sources of all relationships whose target is `shape_id`. Note
+    /// that where the reference is made by a member the member's shape ID is returned, use
+    /// `ShapeID::shape_only` to find the enclosing shape.
+    ///
+    pub fn referenced_by<'a>(&'a self, shape_id: &ShapeID) -> impl Iterator<Item = &'a ShapeID> {
+        self.relationships_to(shape_id).map(|rel| &rel.source)
+    }
+
+    /// Returns `true` if any relationship in this index targets `shape_id`, else `false`.
+    pub fn is_referenced(&self, shape_id: &ShapeID) -> bool {
+        self.incoming.contains_key(shape_id)
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn lookup<'a>(
+        &'a self,
+        ids: &'a HashMap<ShapeID, Vec<usize>>,
+        shape_id: &ShapeID,
+    ) -> impl Iterator<Item = &'a Relationship> {
+        ids.get(shape_id)
+            .into_iter()
+            .flatten()
+            .map(move |i| &self.relationships[*i])
+    }
+
+    fn add(&mut self, source: &ShapeID, kind: RelationshipKind, target: &ShapeID) {
+        let i = self.relationships.len();
+        self.relationships
+            .push(Relationship::new(source.clone(), kind, target.clone()));
+        self.outgoing.entry(source.clone()).or_default().push(i);
+        self.incoming.entry(target.clone()).or_default().push(i);
+    }
+
+    fn add_all<'a>(
+        &mut self,
+        source: &ShapeID,
+        kind: RelationshipKind,
+        targets: impl Iterator<Item = &'a ShapeID>,
+    ) {
+        for target in targets {
+            self.add(source, kind, target);
+        }
+    }
+
+    fn add_traits(&mut self, source: &ShapeID, element: &impl HasTraits) {
+        self.add_all(source, RelationshipKind::Trait, element.traits().keys());
+    }
+
+    fn add_member(&mut self, parent: &ShapeID, member: &MemberShape) {
+        let member_id = parent.make_member(member.id().clone());
+        self.add(parent, RelationshipKind::Member, &member_id);
+        self.add(&member_id, RelationshipKind::MemberTarget, member.target());
+        self.add_traits(&member_id, member);
+    }
+
+    fn add_shape(&mut self, shape: &TopLevelShape) {
+        let id = shape.id();
+        self.add_traits(id, shape);
+        self.add_all(id, RelationshipKind::Mixin, shape.mixins());
+        for member in body_members(shape.body()) {
+            self.add_member(id, member);
+        }
+        match shape.body() {
+            ShapeKind::Service(service) => {
+                self.add_all(id, RelationshipKind::Operation, service.operations());
+                self.add_all(id, RelationshipKind::Resource, service.resources());
+            }
+            ShapeKind::Operation(operation) => {
+                self.add_all(id, RelationshipKind::Input, operation.input().iter());
+                self.add_all(id, RelationshipKind::Output, operation.output().iter());
+                self.add_all(id, RelationshipKind::Error, operation.errors());
+            }
+            ShapeKind::Resource(resource) => {
+                self.add_all(
+                    id,
+                    RelationshipKind::Identifier,
+                    resource.identifiers().map(|(_, target)| target),
+                );
+                self.add_all(id, RelationshipKind::Create, resource.create().iter());
+                self.add_all(id, RelationshipKind::Put, resource.put().iter());
+                self.add_all(id, RelationshipKind::Read, resource.read().iter());
+                self.add_all(id, RelationshipKind::Update, resource.update().iter());
+                self.add_all(id, RelationshipKind::Delete, resource.delete().iter());
+                self.add_all(id, RelationshipKind::List, resource.list().iter());
+                self.add_all(id, RelationshipKind::Operation, resource.operations());
+                self.add_all(
+                    id,
+                    RelationshipKind::CollectionOperation,
+                    resource.collection_operations(),
+                );
+                self.add_all(id, RelationshipKind::Resource, resource.resources());
+            }
+            _ => {}
+        }
+    }
+}
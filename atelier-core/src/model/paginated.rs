@@ -0,0 +1,309 @@
+/*!
+This module provides an index of the resolved pagination configuration for the paginated
+operations in a model. The `@paginated` trait may be applied to a service, to provide default
+values, as well as to each operation; the index merges these so that tools need not repeat the
+resolution rules.
+
+Each operation with the `@paginated` trait has an entry using only its own trait value, and an
+entry for each service it is bound to, directly or through a resource, in which the operation's
+values are merged over those of the service. The `inputToken` and `pageSize` names are resolved
+to members of the operation's input structure, and the `outputToken` and `items` paths are
+resolved, member by member, starting from the output structure. A service or operation whose
+`@paginated` trait value is not valid is treated as if the trait were not applied; the
+`CorrectPaginatedTraits` validator reports these values.
+
+# Example
+
+```rust
+use atelier_core::builder::traits::paginated;
+use atelier_core::builder::{
+    ModelBuilder, OperationBuilder, ServiceBuilder, ShapeTraits, StructureBuilder,
+};
+use atelier_core::model::paginated::PaginatedIndex;
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+    .service(
+        ServiceBuilder::new("Example", "2019-06-27")
+            .operation("ListThings")
+            .apply_trait(paginated(Some("token"), Some("nextToken"), None, None))
+            .into(),
+    )
+    .operation(
+        OperationBuilder::new("ListThings")
+            .input("ListThingsInput")
+            .output("ListThingsOutput")
+            .apply_trait(paginated(None, None, Some("things"), None))
+            .into(),
+    )
+    .structure(StructureBuilder::new("ListThingsInput").member("token", "String").into())
+    .structure(StructureBuilder::new("ListThingsOutput").member("nextToken", "String").into())
+    .try_into()
+    .unwrap();
+
+let index = PaginatedIndex::from(&model);
+
+let service = ShapeID::from_str("smithy.example#Example").unwrap();
+let operation = ShapeID::from_str("smithy.example#ListThings").unwrap();
+let info = index.service_pagination_info(&service, &operation).unwrap();
+assert_eq!(info.paginated().input_token(), &Some("token".to_string()));
+assert_eq!(info.paginated().items(), &Some("things".to_string()));
+assert_eq!(
+    info.input_token_member(),
+    &Some(ShapeID::from_str("smithy.example#ListThingsInput$token").unwrap())
+);
+assert_eq!(info.items_path(), None);
+```
+*/
+
+use crate::model::conformance::resolve_shape;
+use crate::model::shapes::{HasTraits, ShapeKind, TopLevelShape};
+use crate::model::traits::PaginatedTrait;
+use crate::model::{service_operations, HasIdentity, Identifier, Model, ShapeID};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The resolved pagination configuration of a single operation, either on its own or within the
+/// closure of a service.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaginationInfo {
+    service: Option<ShapeID>,
+    operation: ShapeID,
+    input: Option<ShapeID>,
+    output: Option<ShapeID>,
+    paginated: PaginatedTrait,
+    input_token: Option<ShapeID>,
+    page_size: Option<ShapeID>,
+    output_token: Option<Vec<ShapeID>>,
+    items: Option<Vec<ShapeID>>,
+}
+
+///
+/// An index of the pagination configuration of each paginated operation in a model. Note that the
+/// index is a snapshot, it is not updated if the model it was built from subsequently changes.
+///
+#[derive(Clone, Debug, Default)]
+pub struct PaginatedIndex {
+    index: BTreeMap<(Option<ShapeID>, ShapeID), PaginationInfo>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl PaginationInfo {
+    /// Return the service this configuration was resolved within, if any.
+    pub fn service(&self) -> &Option<ShapeID> {
+        &self.service
+    }
+
+    /// Return the paginated operation.
+    pub fn operation(&self) -> &ShapeID {
+        &self.operation
+    }
+
+    /// Return the operation's input structure, if any.
+    pub fn input(&self) -> &Option<ShapeID> {
+        &self.input
+    }
+
+    /// Return the operation's output structure, if any.
+    pub fn output(&self) -> &Option<ShapeID> {
+        &self.output
+    }
+
+    /// Return the merged value of the `@paginated` trait.
+    pub fn paginated(&self) -> &PaginatedTrait {
+        &self.paginated
+    }
+
+    /// Return the input member named by `inputToken`, if it resolves.
+    pub fn input_token_member(&self) -> &Option<ShapeID> {
+        &self.input_token
+    }
+
+    /// Return the input member named by `pageSize`, if it resolves.
+    pub fn page_size_member(&self) -> &Option<ShapeID> {
+        &self.page_size
+    }
+
+    ///
+    /// Return the members, starting from a member of the output structure, along the `outputToken`
+    /// path, if every part of the path resolves.
+    ///
+    pub fn output_token_path(&self) -> Option<&[ShapeID]> {
+        self.output_token.as_deref()
+    }
+
+    ///
+    /// Return the members, starting from a member of the output structure, along the `items` path,
+    /// if every part of the path resolves.
+    ///
+    pub fn items_path(&self) -> Option<&[ShapeID]> {
+        self.items.as_deref()
+    }
+
+    /// Return the member at the end of the `outputToken` path, if it resolves.
+    pub fn output_token_member(&self) -> Option<&ShapeID> {
+        self.output_token_path().and_then(|path| path.last())
+    }
+
+    /// Return the member at the end of the `items` path, if it resolves.
+    pub fn items_member(&self) -> Option<&ShapeID> {
+        self.items_path().and_then(|path| path.last())
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn new(
+        model: &Model,
+        service: Option<&ShapeID>,
+        operation: &TopLevelShape,
+        paginated: PaginatedTrait,
+    ) -> Self {
+        let (input, output) = match operation.body() {
+            ShapeKind::Operation(operation) => {
+                (operation.input().clone(), operation.output().clone())
+            }
+            _ => (None, None),
+        };
+        let member_path = |start: &Option<ShapeID>, path: &Option<String>| match (start, path) {
+            (Some(start), Some(path)) => member_path(model, start, path),
+            _ => None,
+        };
+        let input_member = |name: &Option<String>| {
+            member_path(&input, name)
+                .filter(|path| path.len() == 1)
+                .and_then(|mut path| path.pop())
+        };
+        Self {
+            service: service.cloned(),
+            operation: operation.id().clone(),
+            input_token: input_member(paginated.input_token()),
+            page_size: input_member(paginated.page_size()),
+            output_token: member_path(&output, paginated.output_token()),
+            items: member_path(&output, paginated.items()),
+            input,
+            output,
+            paginated,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<&Model> for PaginatedIndex {
+    fn from(model: &Model) -> Self {
+        let mut index = Self::default();
+        let mut operations: BTreeMap<&ShapeID, (&TopLevelShape, PaginatedTrait)> =
+            Default::default();
+        for shape in model.shapes().filter(|shape| shape.is_operation()) {
+            if let Ok(Some(paginated)) = shape.typed_trait::<PaginatedTrait>() {
+                let _ = index.index.insert(
+                    (None, shape.id().clone()),
+                    PaginationInfo::new(model, None, shape, paginated.clone()),
+                );
+                let _ = operations.insert(shape.id(), (shape, paginated));
+            }
+        }
+        for shape in model.shapes() {
+            if let ShapeKind::Service(service) = shape.body() {
+                let defaults = shape
+                    .typed_trait::<PaginatedTrait>()
+                    .ok()
+                    .flatten()
+                    .unwrap_or_default();
+                for operation_id in service_operations(model, service) {
+                    if let Some((operation, paginated)) = operations.get(operation_id) {
+                        let _ = index.index.insert(
+                            (Some(shape.id().clone()), operation_id.clone()),
+                            PaginationInfo::new(
+                                model,
+                                Some(shape.id()),
+                                operation,
+                                defaults.merged_with(paginated),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+        index
+    }
+}
+
+impl PaginatedIndex {
+    /// Returns `true` if there are no paginated operations in this index, else `false`.
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Return the number of entries, operation-level and per-service, in this index.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns `true` if the operation `operation_id` has the `@paginated` trait applied.
+    pub fn is_paginated(&self, operation_id: &ShapeID) -> bool {
+        self.index.contains_key(&(None, operation_id.clone()))
+    }
+
+    /// Return the configuration of `operation_id` using only its own `@paginated` trait.
+    pub fn pagination_info(&self, operation_id: &ShapeID) -> Option<&PaginationInfo> {
+        self.index.get(&(None, operation_id.clone()))
+    }
+
+    ///
+    /// Return the configuration of `operation_id` within the service `service_id`, this is `None`
+    /// if the operation is not paginated or is not bound to the service.
+    ///
+    pub fn service_pagination_info(
+        &self,
+        service_id: &ShapeID,
+        operation_id: &ShapeID,
+    ) -> Option<&PaginationInfo> {
+        self.index
+            .get(&(Some(service_id.clone()), operation_id.clone()))
+    }
+
+    ///
+    /// Return an iterator over all the entries in this index, ordered by service and then
+    /// operation; the operation-level entries come first.
+    ///
+    pub fn pagination_infos(&self) -> impl Iterator<Item = &PaginationInfo> {
+        self.index.values()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Resolve the `.` separated `path` of member names, starting from the structure `start`, into a
+/// list of member shape IDs.
+///
+fn member_path(model: &Model, start: &ShapeID, path: &str) -> Option<Vec<ShapeID>> {
+    let mut members: Vec<ShapeID> = Default::default();
+    let mut current = start.clone();
+    for name in path.split('.') {
+        let name = Identifier::from_str(name).ok()?;
+        let shape = resolve_shape(model, &current)?;
+        let member = match shape.body() {
+            ShapeKind::Structure(structure) => structure.member(&name)?,
+            _ => return None,
+        };
+        members.push(shape.id().make_member(name));
+        current = member.target().clone();
+    }
+    Some(members)
+}
@@ -1,7 +1,10 @@
+use crate::model::location::{HasSourceLocation, SourceLocation, TraitLocations};
 use crate::model::shapes::{AppliedTraits, HasTraits, NonTraitEq};
 use crate::model::values::Value;
 use crate::model::{HasIdentity, Identifier, ShapeID};
 use crate::syntax::{MEMBER_KEY, MEMBER_MEMBER, MEMBER_VALUE};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 // ------------------------------------------------------------------------------------------------
@@ -12,17 +15,24 @@ use std::collections::HashMap;
 /// Represents a member shape, part of an aggregate top-level shape. The `target` is the target
 /// type for this member. Note that members have an identifier and may also have traits.
 ///
-#[derive(Clone, Debug, PartialEq)]
+/// As with top-level shapes, source locations are not considered when comparing members for
+/// equality.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
 pub struct MemberShape {
     id: Identifier,
     traits: AppliedTraits,
     target: ShapeID,
+    location: Option<SourceLocation>,
+    trait_locations: TraitLocations,
 }
 
 ///
 /// Corresponds to the Smithy List and Set top-level shapes. It has a single member, named `member`
 /// which determines the shape type for each member of the list.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ListOrSet {
     pub(crate) member: MemberShape,
@@ -32,6 +42,7 @@ pub struct ListOrSet {
 /// Corresponds to the Smithy Map top-level shape. It has two members, `key` and `value` which
 /// determine the shape types for each element within the map.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Map {
     pub(crate) key: MemberShape,
@@ -42,6 +53,7 @@ pub struct Map {
 /// Corresponds to the Smithy Structure or Union top-level shapes. It has two members, `key` and
 /// `value` which determine the shape types for each element within the map.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct StructureOrUnion {
     pub(crate) members: HashMap<Identifier, MemberShape>,
@@ -59,6 +71,14 @@ impl NonTraitEq for MemberShape {
 
 has_traits_impl! { MemberShape . traits }
 
+has_source_location_impl! { MemberShape }
+
+impl PartialEq for MemberShape {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.traits == other.traits && self.target == other.target
+    }
+}
+
 impl HasIdentity<Identifier> for MemberShape {
     fn id(&self) -> &Identifier {
         &self.id
@@ -81,7 +101,13 @@ impl MemberShape {
         target: ShapeID,
         traits: HashMap<ShapeID, Option<Value>>,
     ) -> Self {
-        Self { id, traits, target }
+        Self {
+            id,
+            traits,
+            target,
+            location: None,
+            trait_locations: Default::default(),
+        }
     }
 
     /// Return the shape identifier which is the target type for this member.
@@ -0,0 +1,203 @@
+use crate::model::shapes::{HasTraits, MemberShape};
+use crate::model::values::{Number, Value};
+use crate::model::{HasIdentity, Identifier, ShapeID};
+use crate::prelude::{PRELUDE_NAMESPACE, SHAPE_UNIT, TRAIT_ENUMVALUE};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The value of a single member of an enumeration; `enum` shapes have string values and `intEnum`
+/// shapes have integer values.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnumValue {
+    /// The value of a member of an `enum` shape.
+    String(String),
+    /// The value of a member of an `intEnum` shape.
+    Integer(i32),
+}
+
+///
+/// Corresponds to the Smithy Enum and IntEnum top-level shapes. As in the Smithy semantic model
+/// each member targets the prelude `Unit` shape, and the value for the member is held in the
+/// `enumValue` trait applied to it. Whether this is an enum or intEnum is determined by the
+/// enclosing `ShapeKind`.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Enumeration {
+    pub(crate) members: HashMap<Identifier, MemberShape>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for EnumValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::String(v) => write!(f, "{}", v),
+            Self::Integer(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl From<&str> for EnumValue {
+    fn from(v: &str) -> Self {
+        Self::String(v.to_string())
+    }
+}
+
+impl From<String> for EnumValue {
+    fn from(v: String) -> Self {
+        Self::String(v)
+    }
+}
+
+impl From<i32> for EnumValue {
+    fn from(v: i32) -> Self {
+        Self::Integer(v)
+    }
+}
+
+impl From<EnumValue> for Value {
+    fn from(v: EnumValue) -> Self {
+        match v {
+            EnumValue::String(v) => Value::String(v),
+            EnumValue::Integer(v) => Value::Number(Number::Integer(v as i64)),
+        }
+    }
+}
+
+impl TryFrom<&Value> for EnumValue {
+    type Error = ();
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(v) => Ok(Self::String(v.clone())),
+            Value::Number(Number::Integer(v)) => {
+                i32::try_from(*v).map(Self::Integer).map_err(|_| ())
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+impl EnumValue {
+    is_as! { string, String, String }
+    is_as! { integer, Integer, i32 }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Enumeration {
+    /// Construct a new, empty, enum or intEnum.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new enum or intEnum with the provided group of members. Note that member
+    /// identifiers must be unique, so duplicates in the slice will be overridden.
+    pub fn with_members(members: &[MemberShape]) -> Self {
+        let mut new = Self::new();
+        new.add_members(members);
+        new
+    }
+
+    /// Return the shape identifier that all enumeration members target, `smithy.api#Unit`.
+    pub fn member_target() -> ShapeID {
+        ShapeID::new_unchecked(PRELUDE_NAMESPACE, SHAPE_UNIT, None)
+    }
+
+    /// Returns `true` if this enum or intEnum has _any_ members, else `false`.
+    pub fn has_members(&self) -> bool {
+        !self.members.is_empty()
+    }
+
+    /// Returns `true` if this enum or intEnum has a member with the given name, else `false`.
+    pub fn has_member(&self, member_name: &Identifier) -> bool {
+        self.members.contains_key(member_name)
+    }
+
+    /// Returns the member in the enum or intEnum with the given name, else `None`.
+    pub fn member(&self, member_name: &Identifier) -> Option<&MemberShape> {
+        self.members.get(member_name)
+    }
+
+    /// Remove the member in the enum or intEnum with the given name.
+    pub fn remove_member(&mut self, member_name: &Identifier) -> Option<MemberShape> {
+        self.members.remove(member_name)
+    }
+
+    /// Return an iterator over all members in this enum or intEnum.
+    pub fn members(&self) -> impl Iterator<Item = &MemberShape> {
+        self.members.values()
+    }
+
+    /// Add a new member, with the provided value, to this enum or intEnum.
+    pub fn add_member(&mut self, member_name: Identifier, value: EnumValue) -> Option<MemberShape> {
+        let mut member = MemberShape::new(member_name, Self::member_target());
+        let _ = member
+            .traits_mut()
+            .insert(enum_value_trait_id(), Some(value.into()));
+        self.add_a_member(member)
+    }
+
+    /// Add the provided member to this enum or intEnum.
+    pub fn add_a_member(&mut self, member: MemberShape) -> Option<MemberShape> {
+        self.members.insert(member.id().clone(), member)
+    }
+
+    fn add_members(&mut self, members: &[MemberShape]) {
+        for member in members {
+            let _ = self.add_a_member(member.clone());
+        }
+    }
+
+    ///
+    /// Return the value of the member with the given name, `int_enum` is `true` if this is the
+    /// body of a `ShapeKind::IntEnum`. If the member of an `enum` has no `enumValue` trait applied,
+    /// the value defaults to the member's name. An `intEnum` member without a value is invalid,
+    /// so `None` is returned, and should be reported by validation.
+    ///
+    pub fn member_value(&self, member_name: &Identifier, int_enum: bool) -> Option<EnumValue> {
+        self.member(member_name)
+            .and_then(|member| member_value(member, int_enum))
+    }
+
+    ///
+    /// Return an iterator over all members in this enum or intEnum, paired with their values,
+    /// `int_enum` is `true` if this is the body of a `ShapeKind::IntEnum`.
+    ///
+    pub fn values(&self, int_enum: bool) -> impl Iterator<Item = (&Identifier, EnumValue)> {
+        self.members.values().filter_map(move |member| {
+            member_value(member, int_enum).map(|value| (member.id(), value))
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn enum_value_trait_id() -> ShapeID {
+    ShapeID::new_unchecked(PRELUDE_NAMESPACE, TRAIT_ENUMVALUE, None)
+}
+
+fn member_value(member: &MemberShape, int_enum: bool) -> Option<EnumValue> {
+    match member.trait_named(&enum_value_trait_id()) {
+        Some(Some(value)) => EnumValue::try_from(value).ok(),
+        Some(None) => None,
+        None if int_enum => None,
+        None => Some(EnumValue::String(member.id().to_string())),
+    }
+}
@@ -10,14 +10,18 @@ enumeration, `ShapeBody`, to represent each of the productions referenced by `sh
 
 use crate::error::{ErrorKind, Result};
 use crate::model::identity::HasIdentity;
+use crate::model::location::{HasSourceLocation, SourceLocation, TraitLocations};
+use crate::model::traits::TypedTrait;
 use crate::model::{values::Value, Identifier, ShapeID};
 use crate::prelude::{
     PRELUDE_NAMESPACE, TRAIT_BOX, TRAIT_DEPRECATED, TRAIT_DOCUMENTATION, TRAIT_ERROR,
-    TRAIT_EXTERNALDOCUMENTATION, TRAIT_IDEMPOTENT, TRAIT_LENGTH, TRAIT_NOREPLACE, TRAIT_PAGINATED,
-    TRAIT_PATTERN, TRAIT_PRIVATE, TRAIT_READONLY, TRAIT_REFERENCES, TRAIT_REQUIRED,
-    TRAIT_REQUIRESLENGTH, TRAIT_SENSITIVE, TRAIT_SINCE, TRAIT_STREAMING, TRAIT_TAGS, TRAIT_TITLE,
-    TRAIT_TRAIT, TRAIT_UNIQUEITEMS, TRAIT_UNSTABLE,
+    TRAIT_EXTERNALDOCUMENTATION, TRAIT_IDEMPOTENT, TRAIT_INTERNAL, TRAIT_LENGTH, TRAIT_MIXIN,
+    TRAIT_NOREPLACE, TRAIT_PAGINATED, TRAIT_PATTERN, TRAIT_PRIVATE, TRAIT_READONLY,
+    TRAIT_REFERENCES, TRAIT_REQUIRED, TRAIT_REQUIRESLENGTH, TRAIT_SENSITIVE, TRAIT_SINCE,
+    TRAIT_STREAMING, TRAIT_TAGS, TRAIT_TITLE, TRAIT_TRAIT, TRAIT_UNIQUEITEMS, TRAIT_UNSTABLE,
 };
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::Deref;
@@ -93,6 +97,29 @@ pub trait HasTraits {
     ///
     fn apply_with_value(&mut self, a_trait: ShapeID, value: TraitValue) -> Result<()>;
 
+    ///
+    /// Returns the typed value of the prelude trait `T` if it is applied to this model element. An
+    /// error is returned if the applied value cannot be converted into a `T`.
+    ///
+    fn typed_trait<T: TypedTrait>(&self) -> Result<Option<T>>
+    where
+        Self: Sized,
+    {
+        match self.trait_named(&T::trait_id()) {
+            None => Ok(None),
+            Some(None) => T::try_from(&Value::Object(Default::default())).map(Some),
+            Some(Some(value)) => T::try_from(value).map(Some),
+        }
+    }
+
+    /// Apply the typed prelude trait `a_trait` to this model element.
+    fn apply_typed_trait<T: TypedTrait>(&mut self, a_trait: T) -> Result<()>
+    where
+        Self: Sized,
+    {
+        self.apply_with_value(T::trait_id(), Some(a_trait.into()))
+    }
+
     /// Add all these elements to this member's collection.
     fn append_traits(&mut self, traits: &AppliedTraits) -> Result<()> {
         for (id, value) in traits {
@@ -155,6 +182,16 @@ pub trait HasTraits {
         self.has_trait(&prelude_name(TRAIT_IDEMPOTENT))
     }
 
+    /// Returns `true` if the model element has the prelude trait `internal` applied.
+    fn is_internal(&self) -> bool {
+        self.has_trait(&prelude_name(TRAIT_INTERNAL))
+    }
+
+    /// Returns `true` if the model element has the prelude trait `mixin` applied.
+    fn is_mixin(&self) -> bool {
+        self.has_trait(&prelude_name(TRAIT_MIXIN))
+    }
+
     /// Returns `true` if the model element has the prelude trait `no_replace` applied.
     fn is_no_replace(&self) -> bool {
         self.has_trait(&prelude_name(TRAIT_NOREPLACE))
@@ -231,17 +268,25 @@ pub trait HasTraits {
 /// the `ShapeKind` enumeration. Aggregate shapes may have members of type `MemberShape`, but a
 /// model only directly contains top-level shapes.
 ///
-#[derive(Clone, Debug, PartialEq)]
+/// Note that the source locations of the shape, and its traits, are not considered when comparing
+/// shapes for equality.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
 pub struct TopLevelShape {
     id: ShapeID,
     traits: HashMap<ShapeID, Option<Value>>,
+    mixins: Vec<ShapeID>,
     body: ShapeKind,
+    location: Option<SourceLocation>,
+    trait_locations: TraitLocations,
 }
 
 ///
 /// This enumeration represents the set of shape types supported by Smithy.
 ///
 #[allow(clippy::large_enum_variant)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub enum ShapeKind {
     /// An shape holding atomic, or primitive values.
@@ -256,6 +301,10 @@ pub enum ShapeKind {
     Structure(StructureOrUnion),
     /// A structure consisting of pairs of shape ids; the name of the member and it's type.
     Union(StructureOrUnion),
+    /// A set of named string values, each member's value is held in the `enumValue` trait.
+    Enum(Enumeration),
+    /// A set of named integer values, each member's value is held in the `enumValue` trait.
+    IntEnum(Enumeration),
     /// A shape representing some deployed software service.
     Service(Service),
     /// A shape representing some resource managed by a software service, or a sub-resource of
@@ -303,6 +352,32 @@ macro_rules! has_traits_impl {
     };
 }
 
+macro_rules! has_source_location_impl {
+    ($struct_name:ident) => {
+        impl HasSourceLocation for $struct_name {
+            fn location(&self) -> &Option<SourceLocation> {
+                &self.location
+            }
+
+            fn set_location(&mut self, location: SourceLocation) {
+                self.location = Some(location);
+            }
+
+            fn unset_location(&mut self) {
+                self.location = None;
+            }
+
+            fn trait_locations(&self) -> &TraitLocations {
+                &self.trait_locations
+            }
+
+            fn trait_locations_mut(&mut self) -> &mut TraitLocations {
+                &mut self.trait_locations
+            }
+        }
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -338,6 +413,8 @@ impl ShapeKind {
     is_as! { map, Map, Map}
     is_as! { structure, Structure, StructureOrUnion}
     is_as! { union, Union, StructureOrUnion}
+    is_as! { enum, Enum, Enumeration }
+    is_as! { int_enum, IntEnum, Enumeration }
     is_as! { service, Service, Service }
     is_as! { operation, Operation, Operation }
     is_as! { resource, Resource, Resource }
@@ -349,6 +426,7 @@ impl ShapeKind {
 impl NonTraitEq for TopLevelShape {
     fn equal_without_traits(&self, other: &Self) -> bool {
         self.id() == other.id()
+            && self.mixins == other.mixins
             && match (self.body(), other.body()) {
                 (ShapeKind::Simple(l), ShapeKind::Simple(r)) => l == r,
                 (ShapeKind::List(l), ShapeKind::List(r)) => {
@@ -360,20 +438,20 @@ impl NonTraitEq for TopLevelShape {
                 }
                 (ShapeKind::Structure(l), ShapeKind::Structure(r)) => {
                     l.members.keys().count() == r.members.keys().count()
-                        && l.members.keys().all(|k| {
-                            l.member(k)
-                                .unwrap()
-                                .equal_without_traits(r.member(k).unwrap())
+                        && l.members.keys().all(|k| match r.member(k) {
+                            Some(r_member) => l.member(k).unwrap().equal_without_traits(r_member),
+                            None => false,
                         })
                 }
                 (ShapeKind::Union(l), ShapeKind::Union(r)) => {
                     l.members.keys().count() == r.members.keys().count()
-                        && l.members.keys().all(|k| {
-                            l.member(k)
-                                .unwrap()
-                                .equal_without_traits(r.member(k).unwrap())
+                        && l.members.keys().all(|k| match r.member(k) {
+                            Some(r_member) => l.member(k).unwrap().equal_without_traits(r_member),
+                            None => false,
                         })
                 }
+                (ShapeKind::Enum(l), ShapeKind::Enum(r))
+                | (ShapeKind::IntEnum(l), ShapeKind::IntEnum(r)) => l == r,
                 (ShapeKind::Service(l), ShapeKind::Service(r)) => l == r,
                 (ShapeKind::Operation(l), ShapeKind::Operation(r)) => l == r,
                 (ShapeKind::Resource(l), ShapeKind::Resource(r)) => l == r,
@@ -395,6 +473,17 @@ impl HasIdentity<ShapeID> for TopLevelShape {
 
 has_traits_impl! { TopLevelShape . traits }
 
+has_source_location_impl! { TopLevelShape }
+
+impl PartialEq for TopLevelShape {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.traits == other.traits
+            && self.mixins == other.mixins
+            && self.body == other.body
+    }
+}
+
 lazy_static! {
     static ref MEMBER_MEMBER: Identifier = Identifier::from_str("member").unwrap();
     static ref MEMBER_KEY: Identifier = Identifier::from_str("key").unwrap();
@@ -409,7 +498,10 @@ impl TopLevelShape {
         Self {
             id,
             traits: Default::default(),
+            mixins: Default::default(),
             body,
+            location: None,
+            trait_locations: Default::default(),
         }
     }
 
@@ -421,7 +513,14 @@ impl TopLevelShape {
         body: ShapeKind,
         traits: HashMap<ShapeID, Option<Value>>,
     ) -> Self {
-        Self { id, traits, body }
+        Self {
+            id,
+            traits,
+            mixins: Default::default(),
+            body,
+            location: None,
+            trait_locations: Default::default(),
+        }
     }
 
     ///
@@ -447,12 +546,54 @@ impl TopLevelShape {
 
     // --------------------------------------------------------------------------------------------
 
+    ///
+    /// Returns `true` if this shape applies _any_ mixins, else `false`.
+    ///
+    pub fn has_mixins(&self) -> bool {
+        !self.mixins.is_empty()
+    }
+
+    ///
+    /// Returns `true` if this shape applies the mixin with the provided identifier, else `false`.
+    ///
+    pub fn has_mixin(&self, mixin: &ShapeID) -> bool {
+        self.mixins.contains(mixin)
+    }
+
+    ///
+    /// Return an iterator over the identifiers of all mixins applied to this shape, in the order
+    /// in which they were declared.
+    ///
+    pub fn mixins(&self) -> impl Iterator<Item = &ShapeID> {
+        self.mixins.iter()
+    }
+
+    ///
+    /// Add a mixin, by identifier, to the end of this shape's list of mixins.
+    ///
+    pub fn add_mixin(&mut self, mixin: ShapeID) {
+        if !self.has_mixin(&mixin) {
+            self.mixins.push(mixin);
+        }
+    }
+
+    ///
+    /// Remove all mixins from this shape.
+    ///
+    pub fn remove_mixins(&mut self) {
+        self.mixins.clear()
+    }
+
+    // --------------------------------------------------------------------------------------------
+
     delegate! { is_simple, inner = body }
     delegate! { is_list, inner = body }
     delegate! { is_set, inner = body }
     delegate! { is_map, inner = body }
     delegate! { is_structure, inner = body }
     delegate! { is_union, inner = body }
+    delegate! { is_enum, inner = body }
+    delegate! { is_int_enum, inner = body }
     delegate! { is_service, inner = body }
     delegate! { is_operation, inner = body }
     delegate! { is_resource, inner = body }
@@ -513,6 +654,8 @@ impl TopLevelShape {
             }
             ShapeKind::Structure(v) => v.member(member),
             ShapeKind::Union(v) => v.member(member),
+            ShapeKind::Enum(v) => v.member(member),
+            ShapeKind::IntEnum(v) => v.member(member),
             ShapeKind::Service(_) => None,
             ShapeKind::Operation(_) => None,
             ShapeKind::Resource(_) => None,
@@ -580,6 +723,10 @@ pub use simple::Simple;
 pub mod aggregate;
 pub use aggregate::{ListOrSet, Map, MemberShape, StructureOrUnion};
 
+#[doc(hidden)]
+pub mod enumeration;
+pub use enumeration::{EnumValue, Enumeration};
+
 #[doc(hidden)]
 pub mod service;
 pub use service::{Operation, Resource, Service};
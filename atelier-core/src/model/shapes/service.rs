@@ -1,5 +1,7 @@
 use crate::model::shapes::TopLevelShape;
 use crate::model::{HasIdentity, Identifier, ShapeID};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 // ------------------------------------------------------------------------------------------------
@@ -9,6 +11,7 @@ use std::collections::HashMap;
 ///
 /// Corresponds to the Smithy Service top-level shape.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Service {
     version: String,
@@ -20,6 +23,7 @@ pub struct Service {
 ///
 /// Corresponds to the Smithy Operation top-level shape.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Operation {
     input: Option<ShapeID>,
@@ -30,6 +34,7 @@ pub struct Operation {
 ///
 /// Corresponds to the Smithy Resource top-level shape.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Resource {
     identifiers: HashMap<Identifier, ShapeID>,
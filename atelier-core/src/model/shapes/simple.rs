@@ -4,6 +4,8 @@ use crate::syntax::{
     SHAPE_DOUBLE, SHAPE_FLOAT, SHAPE_INTEGER, SHAPE_LONG, SHAPE_SHORT, SHAPE_STRING,
     SHAPE_TIMESTAMP,
 };
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
@@ -14,6 +16,7 @@ use std::str::FromStr;
 ///
 /// Corresponds to the simple shape within Smithy, these are atomic values.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Simple {
     /// Corresponds to the `simple_type_name` production's "blob" terminal.
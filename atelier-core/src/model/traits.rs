@@ -0,0 +1,1614 @@
+/*!
+Strongly-typed representations of the traits defined in the Smithy prelude.
+
+Applied traits are held in a model as a map from trait ID to an optional `Value`; the types in this
+module provide a structured view of those values for the prelude traits. Each type implements
+`TypedTrait`, which provides a fallible conversion from a `Value` and a conversion back into one,
+along with the ID of the trait it represents. The `HasTraits` trait provides the helpers
+`typed_trait` and `apply_typed_trait` to read and write these directly on shapes and members.
+
+Annotation traits, such as `@required`, are represented as unit structures, traits whose value is a
+single string, list, or map, as tuple structures, and traits whose value is a structure as
+structures with accessors for each member.
+
+# Example
+
+```rust
+use atelier_core::builder::traits::documentation;
+use atelier_core::builder::values::ObjectBuilder;
+use atelier_core::builder::{ModelBuilder, OperationBuilder, ShapeTraits, TraitBuilder};
+use atelier_core::model::shapes::HasTraits;
+use atelier_core::model::traits::{DocumentationTrait, HttpTrait};
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+let model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+    .operation(
+        OperationBuilder::new("GetCity")
+            .apply_trait(TraitBuilder::with_value(
+                "http",
+                ObjectBuilder::default()
+                    .string("method", "GET")
+                    .string("uri", "/cities/{name}")
+                    .into(),
+            ))
+            .apply_trait(documentation("Returns a city."))
+            .into(),
+    )
+    .try_into()
+    .unwrap();
+
+let operation = model
+    .shape(&ShapeID::from_str("smithy.example#GetCity").unwrap())
+    .unwrap();
+
+let http = operation.typed_trait::<HttpTrait>().unwrap().unwrap();
+assert_eq!(http.method(), "GET");
+assert_eq!(http.uri(), "/cities/{name}");
+assert_eq!(http.code(), &None);
+
+let documentation = operation.typed_trait::<DocumentationTrait>().unwrap().unwrap();
+assert_eq!(documentation.value(), "Returns a city.");
+```
+*/
+
+use crate::builder::traits::ErrorSource;
+use crate::error::{Error, ErrorKind, Result};
+use crate::model::shapes::EnumValue;
+use crate::model::values::{Number, Value, ValueMap};
+use crate::model::ShapeID;
+use crate::prelude::{
+    PRELUDE_NAMESPACE, TRAIT_AUTH, TRAIT_AUTHDEFINITION, TRAIT_BOX, TRAIT_CORS, TRAIT_DEPRECATED,
+    TRAIT_DOCUMENTATION, TRAIT_ENDPOINT, TRAIT_ENUM, TRAIT_ENUMVALUE, TRAIT_ERROR,
+    TRAIT_EVENTHEADER, TRAIT_EVENTPAYLOAD, TRAIT_EXAMPLES, TRAIT_EXTERNALDOCUMENTATION,
+    TRAIT_HOSTLABEL, TRAIT_HTTP, TRAIT_HTTPAPIKEYAUTH, TRAIT_HTTPBASICAUTH, TRAIT_HTTPBEARERAUTH,
+    TRAIT_HTTPCHECKSUMREQUIRED, TRAIT_HTTPDIGESTAUTH, TRAIT_HTTPERROR, TRAIT_HTTPHEADER,
+    TRAIT_HTTPLABEL, TRAIT_HTTPPAYLOAD, TRAIT_HTTPPREFIXHEADERS, TRAIT_HTTPQUERY,
+    TRAIT_IDEMPOTENCYTOKEN, TRAIT_IDEMPOTENT, TRAIT_IDREF, TRAIT_INTERNAL, TRAIT_JSONNAME,
+    TRAIT_LENGTH, TRAIT_MEDIATYPE, TRAIT_MIXIN, TRAIT_NOREPLACE, TRAIT_OPTIONALAUTH,
+    TRAIT_PAGINATED, TRAIT_PATTERN, TRAIT_PRIVATE, TRAIT_PROTOCOLDEFINITION, TRAIT_RANGE,
+    TRAIT_READONLY, TRAIT_RECOMMENDED, TRAIT_REFERENCES, TRAIT_REQUIRED, TRAIT_REQUIRESLENGTH,
+    TRAIT_RESOURCEIDENTIFIER, TRAIT_RETRYABLE, TRAIT_SENSITIVE, TRAIT_SINCE, TRAIT_STREAMING,
+    TRAIT_SUPPRESS, TRAIT_TAGS, TRAIT_TIMESTAMPFORMAT, TRAIT_TITLE, TRAIT_TRAIT, TRAIT_UNIQUEITEMS,
+    TRAIT_UNSTABLE, TRAIT_XMLATTRIBUTE, TRAIT_XMLFLATTENED, TRAIT_XMLNAME, TRAIT_XMLNAMESPACE,
+};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by the typed representation of a trait, this provides the trait's ID as well as
+/// conversion to and from the `Value` applied to a shape.
+///
+pub trait TypedTrait: for<'a> TryFrom<&'a Value, Error = Error> + Into<Value> {
+    /// The name of this trait within the prelude namespace.
+    const TRAIT_NAME: &'static str;
+
+    /// The absolute shape ID of this trait.
+    fn trait_id() -> ShapeID {
+        ShapeID::new_unchecked(PRELUDE_NAMESPACE, Self::TRAIT_NAME, None)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Macros
+// ------------------------------------------------------------------------------------------------
+
+macro_rules! typed_trait_impl {
+    ($struct_name:ident, $trait_name:ident) => {
+        impl TypedTrait for $struct_name {
+            const TRAIT_NAME: &'static str = $trait_name;
+        }
+    };
+}
+
+macro_rules! annotation_trait {
+    ($struct_name:ident, $trait_name:ident, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $struct_name;
+
+        typed_trait_impl! { $struct_name, $trait_name }
+
+        impl TryFrom<&Value> for $struct_name {
+            type Error = Error;
+
+            fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+                let _ = object_value($trait_name, value, &[])?;
+                Ok(Self)
+            }
+        }
+
+        impl From<$struct_name> for Value {
+            fn from(_: $struct_name) -> Self {
+                Value::Object(Default::default())
+            }
+        }
+    };
+}
+
+macro_rules! string_trait {
+    ($struct_name:ident, $trait_name:ident, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $struct_name(String);
+
+        typed_trait_impl! { $struct_name, $trait_name }
+
+        impl TryFrom<&Value> for $struct_name {
+            type Error = Error;
+
+            fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+                Ok(Self(string_value($trait_name, value)?))
+            }
+        }
+
+        impl From<$struct_name> for Value {
+            fn from(v: $struct_name) -> Self {
+                Value::String(v.0)
+            }
+        }
+
+        impl $struct_name {
+            /// Construct a new trait value.
+            pub fn new(value: &str) -> Self {
+                Self(value.to_string())
+            }
+
+            /// Return the value of this trait.
+            pub fn value(&self) -> &String {
+                &self.0
+            }
+        }
+    };
+}
+
+macro_rules! list_trait {
+    ($struct_name:ident, $trait_name:ident, $item_type:ty, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct $struct_name(Vec<$item_type>);
+
+        typed_trait_impl! { $struct_name, $trait_name }
+
+        impl TryFrom<&Value> for $struct_name {
+            type Error = Error;
+
+            fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+                Ok(Self(list_value($trait_name, value)?))
+            }
+        }
+
+        impl From<$struct_name> for Value {
+            fn from(v: $struct_name) -> Self {
+                Value::Array(v.0.into_iter().map(Value::from).collect())
+            }
+        }
+
+        impl From<Vec<$item_type>> for $struct_name {
+            fn from(values: Vec<$item_type>) -> Self {
+                Self(values)
+            }
+        }
+
+        impl $struct_name {
+            /// Return an iterator over the values of this trait.
+            pub fn values(&self) -> impl Iterator<Item = &$item_type> {
+                self.0.iter()
+            }
+
+            /// Add a value to this trait.
+            pub fn add_value(&mut self, value: $item_type) {
+                self.0.push(value)
+            }
+        }
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Types -- Annotation Traits
+// ------------------------------------------------------------------------------------------------
+
+annotation_trait! { BoxTrait, TRAIT_BOX, "The prelude trait `box`." }
+annotation_trait! { EventHeaderTrait, TRAIT_EVENTHEADER, "The prelude trait `eventHeader`." }
+annotation_trait! { EventPayloadTrait, TRAIT_EVENTPAYLOAD, "The prelude trait `eventPayload`." }
+annotation_trait! { HostLabelTrait, TRAIT_HOSTLABEL, "The prelude trait `hostLabel`." }
+annotation_trait! { HttpBasicAuthTrait, TRAIT_HTTPBASICAUTH, "The prelude trait `httpBasicAuth`." }
+annotation_trait! { HttpBearerAuthTrait, TRAIT_HTTPBEARERAUTH, "The prelude trait `httpBearerAuth`." }
+annotation_trait! { HttpChecksumRequiredTrait, TRAIT_HTTPCHECKSUMREQUIRED, "The prelude trait `httpChecksumRequired`." }
+annotation_trait! { HttpDigestAuthTrait, TRAIT_HTTPDIGESTAUTH, "The prelude trait `httpDigestAuth`." }
+annotation_trait! { HttpLabelTrait, TRAIT_HTTPLABEL, "The prelude trait `httpLabel`." }
+annotation_trait! { HttpPayloadTrait, TRAIT_HTTPPAYLOAD, "The prelude trait `httpPayload`." }
+annotation_trait! { IdempotencyTokenTrait, TRAIT_IDEMPOTENCYTOKEN, "The prelude trait `idempotencyToken`." }
+annotation_trait! { IdempotentTrait, TRAIT_IDEMPOTENT, "The prelude trait `idempotent`." }
+annotation_trait! { InternalTrait, TRAIT_INTERNAL, "The prelude trait `internal`." }
+annotation_trait! { NoReplaceTrait, TRAIT_NOREPLACE, "The prelude trait `noReplace`." }
+annotation_trait! { OptionalAuthTrait, TRAIT_OPTIONALAUTH, "The prelude trait `optionalAuth`." }
+annotation_trait! { PrivateTrait, TRAIT_PRIVATE, "The prelude trait `private`." }
+annotation_trait! { ReadonlyTrait, TRAIT_READONLY, "The prelude trait `readonly`." }
+annotation_trait! { RequiredTrait, TRAIT_REQUIRED, "The prelude trait `required`." }
+annotation_trait! { RequiresLengthTrait, TRAIT_REQUIRESLENGTH, "The prelude trait `requiresLength`." }
+annotation_trait! { SensitiveTrait, TRAIT_SENSITIVE, "The prelude trait `sensitive`." }
+annotation_trait! { StreamingTrait, TRAIT_STREAMING, "The prelude trait `streaming`." }
+annotation_trait! { UniqueItemsTrait, TRAIT_UNIQUEITEMS, "The prelude trait `uniqueItems`." }
+annotation_trait! { UnstableTrait, TRAIT_UNSTABLE, "The prelude trait `unstable`." }
+annotation_trait! { XmlAttributeTrait, TRAIT_XMLATTRIBUTE, "The prelude trait `xmlAttribute`." }
+annotation_trait! { XmlFlattenedTrait, TRAIT_XMLFLATTENED, "The prelude trait `xmlFlattened`." }
+
+// ------------------------------------------------------------------------------------------------
+// Public Types -- String, List, and Map Traits
+// ------------------------------------------------------------------------------------------------
+
+string_trait! { DocumentationTrait, TRAIT_DOCUMENTATION, "The prelude trait `documentation`." }
+string_trait! { HttpHeaderTrait, TRAIT_HTTPHEADER, "The prelude trait `httpHeader`." }
+string_trait! { HttpPrefixHeadersTrait, TRAIT_HTTPPREFIXHEADERS, "The prelude trait `httpPrefixHeaders`." }
+string_trait! { HttpQueryTrait, TRAIT_HTTPQUERY, "The prelude trait `httpQuery`." }
+string_trait! { JsonNameTrait, TRAIT_JSONNAME, "The prelude trait `jsonName`." }
+string_trait! { MediaTypeTrait, TRAIT_MEDIATYPE, "The prelude trait `mediaType`." }
+string_trait! { PatternTrait, TRAIT_PATTERN, "The prelude trait `pattern`." }
+string_trait! { ResourceIdentifierTrait, TRAIT_RESOURCEIDENTIFIER, "The prelude trait `resourceIdentifier`." }
+string_trait! { SinceTrait, TRAIT_SINCE, "The prelude trait `since`." }
+string_trait! { TimestampFormatTrait, TRAIT_TIMESTAMPFORMAT, "The prelude trait `timestampFormat`." }
+string_trait! { TitleTrait, TRAIT_TITLE, "The prelude trait `title`." }
+string_trait! { XmlNameTrait, TRAIT_XMLNAME, "The prelude trait `xmlName`." }
+
+list_trait! { AuthTrait, TRAIT_AUTH, ShapeID, "The prelude trait `auth`, a list of authentication scheme trait IDs." }
+list_trait! { EnumTrait, TRAIT_ENUM, EnumDefinition, "The prelude trait `enum`." }
+list_trait! { ExamplesTrait, TRAIT_EXAMPLES, Example, "The prelude trait `examples`." }
+list_trait! { ReferencesTrait, TRAIT_REFERENCES, Reference, "The prelude trait `references`." }
+list_trait! { SuppressTrait, TRAIT_SUPPRESS, String, "The prelude trait `suppress`, a list of validator IDs." }
+list_trait! { TagsTrait, TRAIT_TAGS, String, "The prelude trait `tags`." }
+
+///
+/// The prelude trait `error`, denoting the source of the error.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorTrait(ErrorSource);
+
+///
+/// The prelude trait `httpError`, the HTTP status code of an error.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HttpErrorTrait(u16);
+
+///
+/// The prelude trait `enumValue`, the value of a member of an `enum` or `intEnum` shape.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumValueTrait(EnumValue);
+
+///
+/// The prelude trait `externalDocumentation`, a map of names to URLs.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExternalDocumentationTrait {
+    links: HashMap<String, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Types -- Structure Traits
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The prelude trait `cors`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CorsTrait {
+    origin: Option<String>,
+    max_age: Option<i64>,
+    additional_allowed_headers: Vec<String>,
+    additional_exposed_headers: Vec<String>,
+}
+
+///
+/// The prelude trait `deprecated`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeprecatedTrait {
+    message: Option<String>,
+    since: Option<String>,
+}
+
+///
+/// The prelude trait `endpoint`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct EndpointTrait {
+    host_prefix: String,
+}
+
+///
+/// The prelude trait `http`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpTrait {
+    method: String,
+    uri: String,
+    code: Option<u16>,
+}
+
+///
+/// The prelude trait `httpApiKeyAuth`, the `in` member of the trait is represented by `location`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpApiKeyAuthTrait {
+    name: String,
+    location: String,
+    scheme: Option<String>,
+}
+
+///
+/// The prelude trait `idRef`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IdRefTrait {
+    selector: Option<String>,
+    fail_when_missing: bool,
+    error_message: Option<String>,
+}
+
+///
+/// The prelude trait `length`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LengthTrait {
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+///
+/// The prelude trait `mixin`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MixinTrait {
+    local_traits: Vec<ShapeID>,
+}
+
+///
+/// The prelude trait `paginated`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PaginatedTrait {
+    input_token: Option<String>,
+    output_token: Option<String>,
+    items: Option<String>,
+    page_size: Option<String>,
+}
+
+///
+/// The prelude trait `range`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RangeTrait {
+    min: Option<Number>,
+    max: Option<Number>,
+}
+
+///
+/// The prelude trait `recommended`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecommendedTrait {
+    reason: Option<String>,
+}
+
+///
+/// The prelude trait `retryable`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RetryableTrait {
+    throttling: bool,
+}
+
+///
+/// The prelude trait `trait`, applied to a shape to make it a trait definition.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TraitTrait {
+    selector: Option<String>,
+    structurally_exclusive: Option<String>,
+    conflicts: Vec<ShapeID>,
+}
+
+///
+/// The prelude trait `authDefinition`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuthDefinitionTrait {
+    traits: Vec<ShapeID>,
+}
+
+///
+/// The prelude trait `protocolDefinition`.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProtocolDefinitionTrait {
+    traits: Vec<ShapeID>,
+    no_inline_document_support: bool,
+}
+
+///
+/// The prelude trait `xmlNamespace`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct XmlNamespaceTrait {
+    uri: String,
+    prefix: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single value definition within the `enum` trait.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumDefinition {
+    value: String,
+    name: Option<String>,
+    documentation: Option<String>,
+    tags: Vec<String>,
+    deprecated: bool,
+}
+
+///
+/// A single example within the `examples` trait.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+    title: String,
+    documentation: Option<String>,
+    input: Option<Value>,
+    output: Option<Value>,
+    error: Option<ExampleError>,
+}
+
+///
+/// The error returned by an example within the `examples` trait.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExampleError {
+    shape_id: ShapeID,
+    content: Option<Value>,
+}
+
+///
+/// A single reference within the `references` trait.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reference {
+    resource: ShapeID,
+    ids: HashMap<String, String>,
+    service: Option<ShapeID>,
+    rel: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { ErrorTrait, TRAIT_ERROR }
+
+impl TryFrom<&Value> for ErrorTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        Ok(Self(ErrorSource::from_str(&string_value(
+            TRAIT_ERROR,
+            value,
+        )?)?))
+    }
+}
+
+impl From<ErrorTrait> for Value {
+    fn from(v: ErrorTrait) -> Self {
+        Value::String(v.0.to_string())
+    }
+}
+
+impl ErrorTrait {
+    /// Construct a new trait value.
+    pub fn new(source: ErrorSource) -> Self {
+        Self(source)
+    }
+
+    /// Return the source of the error.
+    pub fn source(&self) -> &ErrorSource {
+        &self.0
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { HttpErrorTrait, TRAIT_HTTPERROR }
+
+impl TryFrom<&Value> for HttpErrorTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        Ok(Self(status_code(TRAIT_HTTPERROR, value)?))
+    }
+}
+
+impl From<HttpErrorTrait> for Value {
+    fn from(v: HttpErrorTrait) -> Self {
+        Value::from(v.0 as i64)
+    }
+}
+
+impl HttpErrorTrait {
+    /// Construct a new trait value.
+    pub fn new(code: u16) -> Self {
+        Self(code)
+    }
+
+    /// Return the HTTP status code.
+    pub fn code(&self) -> u16 {
+        self.0
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { EnumValueTrait, TRAIT_ENUMVALUE }
+
+impl TryFrom<&Value> for EnumValueTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        EnumValue::try_from(value)
+            .map(Self)
+            .map_err(|_| invalid(TRAIT_ENUMVALUE))
+    }
+}
+
+impl From<EnumValueTrait> for Value {
+    fn from(v: EnumValueTrait) -> Self {
+        v.0.into()
+    }
+}
+
+impl EnumValueTrait {
+    /// Construct a new trait value.
+    pub fn new(value: EnumValue) -> Self {
+        Self(value)
+    }
+
+    /// Return the value of this trait.
+    pub fn value(&self) -> &EnumValue {
+        &self.0
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { ExternalDocumentationTrait, TRAIT_EXTERNALDOCUMENTATION }
+
+impl TryFrom<&Value> for ExternalDocumentationTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            links: string_map(TRAIT_EXTERNALDOCUMENTATION, value)?,
+        })
+    }
+}
+
+impl From<ExternalDocumentationTrait> for Value {
+    fn from(v: ExternalDocumentationTrait) -> Self {
+        Value::Object(
+            v.links
+                .into_iter()
+                .map(|(k, v)| (k, Value::String(v)))
+                .collect(),
+        )
+    }
+}
+
+impl ExternalDocumentationTrait {
+    hash_member! { links, link, String, url, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { CorsTrait, TRAIT_CORS }
+
+impl TryFrom<&Value> for CorsTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(
+            TRAIT_CORS,
+            value,
+            &[
+                "origin",
+                "maxAge",
+                "additionalAllowedHeaders",
+                "additionalExposedHeaders",
+            ],
+        )?;
+        Ok(Self {
+            origin: optional_string(TRAIT_CORS, object, "origin")?,
+            max_age: optional_integer(TRAIT_CORS, object, "maxAge")?,
+            additional_allowed_headers: optional_list(
+                TRAIT_CORS,
+                object,
+                "additionalAllowedHeaders",
+            )?,
+            additional_exposed_headers: optional_list(
+                TRAIT_CORS,
+                object,
+                "additionalExposedHeaders",
+            )?,
+        })
+    }
+}
+
+impl From<CorsTrait> for Value {
+    fn from(v: CorsTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_optional(&mut object, "origin", v.origin);
+        insert_optional(&mut object, "maxAge", v.max_age);
+        insert_list(
+            &mut object,
+            "additionalAllowedHeaders",
+            v.additional_allowed_headers,
+        );
+        insert_list(
+            &mut object,
+            "additionalExposedHeaders",
+            v.additional_exposed_headers,
+        );
+        Value::Object(object)
+    }
+}
+
+impl CorsTrait {
+    optional_member! { origin, String }
+
+    optional_member! { max_age, i64 }
+
+    array_member! { additional_allowed_headers, additional_allowed_header, String }
+
+    array_member! { additional_exposed_headers, additional_exposed_header, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { DeprecatedTrait, TRAIT_DEPRECATED }
+
+impl TryFrom<&Value> for DeprecatedTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_DEPRECATED, value, &["message", "since"])?;
+        Ok(Self {
+            message: optional_string(TRAIT_DEPRECATED, object, "message")?,
+            since: optional_string(TRAIT_DEPRECATED, object, "since")?,
+        })
+    }
+}
+
+impl From<DeprecatedTrait> for Value {
+    fn from(v: DeprecatedTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_optional(&mut object, "message", v.message);
+        insert_optional(&mut object, "since", v.since);
+        Value::Object(object)
+    }
+}
+
+impl DeprecatedTrait {
+    optional_member! { message, String }
+
+    optional_member! { since, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { EndpointTrait, TRAIT_ENDPOINT }
+
+impl TryFrom<&Value> for EndpointTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_ENDPOINT, value, &["hostPrefix"])?;
+        Ok(Self {
+            host_prefix: required_string(TRAIT_ENDPOINT, object, "hostPrefix")?,
+        })
+    }
+}
+
+impl From<EndpointTrait> for Value {
+    fn from(v: EndpointTrait) -> Self {
+        let mut object = ValueMap::default();
+        let _ = object.insert("hostPrefix".to_string(), v.host_prefix.into());
+        Value::Object(object)
+    }
+}
+
+impl EndpointTrait {
+    /// Construct a new trait value.
+    pub fn new(host_prefix: &str) -> Self {
+        Self {
+            host_prefix: host_prefix.to_string(),
+        }
+    }
+
+    required_member! { host_prefix, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { HttpTrait, TRAIT_HTTP }
+
+impl TryFrom<&Value> for HttpTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_HTTP, value, &["method", "uri", "code"])?;
+        Ok(Self {
+            method: required_string(TRAIT_HTTP, object, "method")?,
+            uri: required_string(TRAIT_HTTP, object, "uri")?,
+            code: match object.get("code") {
+                None => None,
+                Some(value) => Some(status_code(TRAIT_HTTP, value)?),
+            },
+        })
+    }
+}
+
+impl From<HttpTrait> for Value {
+    fn from(v: HttpTrait) -> Self {
+        let mut object = ValueMap::default();
+        let _ = object.insert("method".to_string(), v.method.into());
+        let _ = object.insert("uri".to_string(), v.uri.into());
+        insert_optional(&mut object, "code", v.code.map(i64::from));
+        Value::Object(object)
+    }
+}
+
+impl HttpTrait {
+    /// Construct a new trait value.
+    pub fn new(method: &str, uri: &str) -> Self {
+        Self {
+            method: method.to_string(),
+            uri: uri.to_string(),
+            code: None,
+        }
+    }
+
+    required_member! { method, String }
+
+    required_member! { uri, String }
+
+    optional_member! { code, u16 }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { HttpApiKeyAuthTrait, TRAIT_HTTPAPIKEYAUTH }
+
+impl TryFrom<&Value> for HttpApiKeyAuthTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_HTTPAPIKEYAUTH, value, &["name", "in", "scheme"])?;
+        Ok(Self {
+            name: required_string(TRAIT_HTTPAPIKEYAUTH, object, "name")?,
+            location: required_string(TRAIT_HTTPAPIKEYAUTH, object, "in")?,
+            scheme: optional_string(TRAIT_HTTPAPIKEYAUTH, object, "scheme")?,
+        })
+    }
+}
+
+impl From<HttpApiKeyAuthTrait> for Value {
+    fn from(v: HttpApiKeyAuthTrait) -> Self {
+        let mut object = ValueMap::default();
+        let _ = object.insert("name".to_string(), v.name.into());
+        let _ = object.insert("in".to_string(), v.location.into());
+        insert_optional(&mut object, "scheme", v.scheme);
+        Value::Object(object)
+    }
+}
+
+impl HttpApiKeyAuthTrait {
+    /// Construct a new trait value.
+    pub fn new(name: &str, location: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            location: location.to_string(),
+            scheme: None,
+        }
+    }
+
+    required_member! { name, String }
+
+    required_member! { location, String }
+
+    optional_member! { scheme, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { IdRefTrait, TRAIT_IDREF }
+
+impl TryFrom<&Value> for IdRefTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(
+            TRAIT_IDREF,
+            value,
+            &["selector", "failWhenMissing", "errorMessage"],
+        )?;
+        Ok(Self {
+            selector: optional_string(TRAIT_IDREF, object, "selector")?,
+            fail_when_missing: optional_boolean(TRAIT_IDREF, object, "failWhenMissing")?,
+            error_message: optional_string(TRAIT_IDREF, object, "errorMessage")?,
+        })
+    }
+}
+
+impl From<IdRefTrait> for Value {
+    fn from(v: IdRefTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_optional(&mut object, "selector", v.selector);
+        insert_flag(&mut object, "failWhenMissing", v.fail_when_missing);
+        insert_optional(&mut object, "errorMessage", v.error_message);
+        Value::Object(object)
+    }
+}
+
+impl IdRefTrait {
+    optional_member! { selector, String }
+
+    boolean_member! { fail_when_missing }
+
+    optional_member! { error_message, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { LengthTrait, TRAIT_LENGTH }
+
+impl TryFrom<&Value> for LengthTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_LENGTH, value, &["min", "max"])?;
+        let bound = |key| match optional_integer(TRAIT_LENGTH, object, key)? {
+            None => Ok(None),
+            Some(v) => usize::try_from(v)
+                .map(Some)
+                .map_err(|_| invalid(TRAIT_LENGTH)),
+        };
+        Ok(Self {
+            min: bound("min")?,
+            max: bound("max")?,
+        })
+    }
+}
+
+impl From<LengthTrait> for Value {
+    fn from(v: LengthTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_optional(&mut object, "min", v.min.map(|v| v as i64));
+        insert_optional(&mut object, "max", v.max.map(|v| v as i64));
+        Value::Object(object)
+    }
+}
+
+impl LengthTrait {
+    optional_member! { min, usize }
+
+    optional_member! { max, usize }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { MixinTrait, TRAIT_MIXIN }
+
+impl TryFrom<&Value> for MixinTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_MIXIN, value, &["localTraits"])?;
+        Ok(Self {
+            local_traits: optional_list(TRAIT_MIXIN, object, "localTraits")?,
+        })
+    }
+}
+
+impl From<MixinTrait> for Value {
+    fn from(v: MixinTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_list(&mut object, "localTraits", v.local_traits);
+        Value::Object(object)
+    }
+}
+
+impl MixinTrait {
+    array_member! { local_traits, local_trait, ShapeID }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { PaginatedTrait, TRAIT_PAGINATED }
+
+impl TryFrom<&Value> for PaginatedTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(
+            TRAIT_PAGINATED,
+            value,
+            &["inputToken", "outputToken", "items", "pageSize"],
+        )?;
+        Ok(Self {
+            input_token: optional_string(TRAIT_PAGINATED, object, "inputToken")?,
+            output_token: optional_string(TRAIT_PAGINATED, object, "outputToken")?,
+            items: optional_string(TRAIT_PAGINATED, object, "items")?,
+            page_size: optional_string(TRAIT_PAGINATED, object, "pageSize")?,
+        })
+    }
+}
+
+impl From<PaginatedTrait> for Value {
+    fn from(v: PaginatedTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_optional(&mut object, "inputToken", v.input_token);
+        insert_optional(&mut object, "outputToken", v.output_token);
+        insert_optional(&mut object, "items", v.items);
+        insert_optional(&mut object, "pageSize", v.page_size);
+        Value::Object(object)
+    }
+}
+
+impl PaginatedTrait {
+    optional_member! { input_token, String }
+
+    optional_member! { output_token, String }
+
+    optional_member! { items, String }
+
+    optional_member! { page_size, String }
+
+    ///
+    /// Return a new value where each member present in `other` replaces the corresponding member
+    /// of this value; for example the operation-level trait merged over the service-level one.
+    ///
+    pub fn merged_with(&self, other: &Self) -> Self {
+        Self {
+            input_token: other
+                .input_token
+                .clone()
+                .or_else(|| self.input_token.clone()),
+            output_token: other
+                .output_token
+                .clone()
+                .or_else(|| self.output_token.clone()),
+            items: other.items.clone().or_else(|| self.items.clone()),
+            page_size: other.page_size.clone().or_else(|| self.page_size.clone()),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { RangeTrait, TRAIT_RANGE }
+
+impl TryFrom<&Value> for RangeTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_RANGE, value, &["min", "max"])?;
+        let bound = |key| match object.get(key) {
+            None => Ok(None),
+            Some(Value::Number(v)) => Ok(Some(v.clone())),
+            Some(_) => Err(invalid(TRAIT_RANGE)),
+        };
+        Ok(Self {
+            min: bound("min")?,
+            max: bound("max")?,
+        })
+    }
+}
+
+impl From<RangeTrait> for Value {
+    fn from(v: RangeTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_optional(&mut object, "min", v.min);
+        insert_optional(&mut object, "max", v.max);
+        Value::Object(object)
+    }
+}
+
+impl RangeTrait {
+    optional_member! { min, Number }
+
+    optional_member! { max, Number }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { RecommendedTrait, TRAIT_RECOMMENDED }
+
+impl TryFrom<&Value> for RecommendedTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_RECOMMENDED, value, &["reason"])?;
+        Ok(Self {
+            reason: optional_string(TRAIT_RECOMMENDED, object, "reason")?,
+        })
+    }
+}
+
+impl From<RecommendedTrait> for Value {
+    fn from(v: RecommendedTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_optional(&mut object, "reason", v.reason);
+        Value::Object(object)
+    }
+}
+
+impl RecommendedTrait {
+    optional_member! { reason, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { RetryableTrait, TRAIT_RETRYABLE }
+
+impl TryFrom<&Value> for RetryableTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_RETRYABLE, value, &["throttling"])?;
+        Ok(Self {
+            throttling: optional_boolean(TRAIT_RETRYABLE, object, "throttling")?,
+        })
+    }
+}
+
+impl From<RetryableTrait> for Value {
+    fn from(v: RetryableTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_flag(&mut object, "throttling", v.throttling);
+        Value::Object(object)
+    }
+}
+
+impl RetryableTrait {
+    boolean_member! { throttling }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { TraitTrait, TRAIT_TRAIT }
+
+impl TryFrom<&Value> for TraitTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(
+            TRAIT_TRAIT,
+            value,
+            &["selector", "structurallyExclusive", "conflicts"],
+        )?;
+        Ok(Self {
+            selector: optional_string(TRAIT_TRAIT, object, "selector")?,
+            structurally_exclusive: optional_string(TRAIT_TRAIT, object, "structurallyExclusive")?,
+            conflicts: optional_list(TRAIT_TRAIT, object, "conflicts")?,
+        })
+    }
+}
+
+impl From<TraitTrait> for Value {
+    fn from(v: TraitTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_optional(&mut object, "selector", v.selector);
+        insert_optional(
+            &mut object,
+            "structurallyExclusive",
+            v.structurally_exclusive,
+        );
+        insert_list(&mut object, "conflicts", v.conflicts);
+        Value::Object(object)
+    }
+}
+
+impl TraitTrait {
+    optional_member! { selector, String }
+
+    optional_member! { structurally_exclusive, String }
+
+    array_member! { conflicts, conflict, ShapeID }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { AuthDefinitionTrait, TRAIT_AUTHDEFINITION }
+
+impl TryFrom<&Value> for AuthDefinitionTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_AUTHDEFINITION, value, &["traits"])?;
+        Ok(Self {
+            traits: optional_list(TRAIT_AUTHDEFINITION, object, "traits")?,
+        })
+    }
+}
+
+impl From<AuthDefinitionTrait> for Value {
+    fn from(v: AuthDefinitionTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_list(&mut object, "traits", v.traits);
+        Value::Object(object)
+    }
+}
+
+impl AuthDefinitionTrait {
+    array_member! { traits, a_trait, ShapeID }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { ProtocolDefinitionTrait, TRAIT_PROTOCOLDEFINITION }
+
+impl TryFrom<&Value> for ProtocolDefinitionTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(
+            TRAIT_PROTOCOLDEFINITION,
+            value,
+            &["traits", "noInlineDocumentSupport"],
+        )?;
+        Ok(Self {
+            traits: optional_list(TRAIT_PROTOCOLDEFINITION, object, "traits")?,
+            no_inline_document_support: optional_boolean(
+                TRAIT_PROTOCOLDEFINITION,
+                object,
+                "noInlineDocumentSupport",
+            )?,
+        })
+    }
+}
+
+impl From<ProtocolDefinitionTrait> for Value {
+    fn from(v: ProtocolDefinitionTrait) -> Self {
+        let mut object = ValueMap::default();
+        insert_list(&mut object, "traits", v.traits);
+        insert_flag(
+            &mut object,
+            "noInlineDocumentSupport",
+            v.no_inline_document_support,
+        );
+        Value::Object(object)
+    }
+}
+
+impl ProtocolDefinitionTrait {
+    array_member! { traits, a_trait, ShapeID }
+
+    boolean_member! { no_inline_document_support }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+typed_trait_impl! { XmlNamespaceTrait, TRAIT_XMLNAMESPACE }
+
+impl TryFrom<&Value> for XmlNamespaceTrait {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_XMLNAMESPACE, value, &["uri", "prefix"])?;
+        Ok(Self {
+            uri: required_string(TRAIT_XMLNAMESPACE, object, "uri")?,
+            prefix: optional_string(TRAIT_XMLNAMESPACE, object, "prefix")?,
+        })
+    }
+}
+
+impl From<XmlNamespaceTrait> for Value {
+    fn from(v: XmlNamespaceTrait) -> Self {
+        let mut object = ValueMap::default();
+        let _ = object.insert("uri".to_string(), v.uri.into());
+        insert_optional(&mut object, "prefix", v.prefix);
+        Value::Object(object)
+    }
+}
+
+impl XmlNamespaceTrait {
+    /// Construct a new trait value.
+    pub fn new(uri: &str) -> Self {
+        Self {
+            uri: uri.to_string(),
+            prefix: None,
+        }
+    }
+
+    required_member! { uri, String }
+
+    optional_member! { prefix, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&Value> for EnumDefinition {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(
+            TRAIT_ENUM,
+            value,
+            &["value", "name", "documentation", "tags", "deprecated"],
+        )?;
+        Ok(Self {
+            value: required_string(TRAIT_ENUM, object, "value")?,
+            name: optional_string(TRAIT_ENUM, object, "name")?,
+            documentation: optional_string(TRAIT_ENUM, object, "documentation")?,
+            tags: optional_list(TRAIT_ENUM, object, "tags")?,
+            deprecated: optional_boolean(TRAIT_ENUM, object, "deprecated")?,
+        })
+    }
+}
+
+impl From<EnumDefinition> for Value {
+    fn from(v: EnumDefinition) -> Self {
+        let mut object = ValueMap::default();
+        let _ = object.insert("value".to_string(), v.value.into());
+        insert_optional(&mut object, "name", v.name);
+        insert_optional(&mut object, "documentation", v.documentation);
+        insert_list(&mut object, "tags", v.tags);
+        insert_flag(&mut object, "deprecated", v.deprecated);
+        Value::Object(object)
+    }
+}
+
+impl EnumDefinition {
+    /// Construct a new enum definition.
+    pub fn new(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+            name: None,
+            documentation: None,
+            tags: Default::default(),
+            deprecated: false,
+        }
+    }
+
+    required_member! { value, String }
+
+    optional_member! { name, String }
+
+    optional_member! { documentation, String }
+
+    array_member! { tags, tag, String }
+
+    boolean_member! { deprecated }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&Value> for Example {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(
+            TRAIT_EXAMPLES,
+            value,
+            &["title", "documentation", "input", "output", "error"],
+        )?;
+        Ok(Self {
+            title: required_string(TRAIT_EXAMPLES, object, "title")?,
+            documentation: optional_string(TRAIT_EXAMPLES, object, "documentation")?,
+            input: object.get("input").cloned(),
+            output: object.get("output").cloned(),
+            error: match object.get("error") {
+                None => None,
+                Some(error) => Some(ExampleError::try_from(error)?),
+            },
+        })
+    }
+}
+
+impl From<Example> for Value {
+    fn from(v: Example) -> Self {
+        let mut object = ValueMap::default();
+        let _ = object.insert("title".to_string(), v.title.into());
+        insert_optional(&mut object, "documentation", v.documentation);
+        insert_optional(&mut object, "input", v.input);
+        insert_optional(&mut object, "output", v.output);
+        insert_optional(&mut object, "error", v.error);
+        Value::Object(object)
+    }
+}
+
+impl Example {
+    /// Construct a new example.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            documentation: None,
+            input: None,
+            output: None,
+            error: None,
+        }
+    }
+
+    required_member! { title, String }
+
+    optional_member! { documentation, String }
+
+    optional_member! { input, Value }
+
+    optional_member! { output, Value }
+
+    optional_member! { error, ExampleError }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&Value> for ExampleError {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(TRAIT_EXAMPLES, value, &["shapeId", "content"])?;
+        Ok(Self {
+            shape_id: shape_id(
+                TRAIT_EXAMPLES,
+                &required_string(TRAIT_EXAMPLES, object, "shapeId")?,
+            )?,
+            content: object.get("content").cloned(),
+        })
+    }
+}
+
+impl From<ExampleError> for Value {
+    fn from(v: ExampleError) -> Self {
+        let mut object = ValueMap::default();
+        let _ = object.insert("shapeId".to_string(), v.shape_id.into());
+        insert_optional(&mut object, "content", v.content);
+        Value::Object(object)
+    }
+}
+
+impl ExampleError {
+    /// Construct a new example error.
+    pub fn new(shape_id: ShapeID) -> Self {
+        Self {
+            shape_id,
+            content: None,
+        }
+    }
+
+    required_member! { shape_id, ShapeID }
+
+    optional_member! { content, Value }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TryFrom<&Value> for Reference {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        let object = object_value(
+            TRAIT_REFERENCES,
+            value,
+            &["resource", "ids", "service", "rel"],
+        )?;
+        Ok(Self {
+            resource: shape_id(
+                TRAIT_REFERENCES,
+                &required_string(TRAIT_REFERENCES, object, "resource")?,
+            )?,
+            ids: match object.get("ids") {
+                None => Default::default(),
+                Some(ids) => string_map(TRAIT_REFERENCES, ids)?,
+            },
+            service: match optional_string(TRAIT_REFERENCES, object, "service")? {
+                None => None,
+                Some(service) => Some(shape_id(TRAIT_REFERENCES, &service)?),
+            },
+            rel: optional_string(TRAIT_REFERENCES, object, "rel")?,
+        })
+    }
+}
+
+impl From<Reference> for Value {
+    fn from(v: Reference) -> Self {
+        let mut object = ValueMap::default();
+        let _ = object.insert("resource".to_string(), v.resource.into());
+        if !v.ids.is_empty() {
+            let _ = object.insert(
+                "ids".to_string(),
+                Value::Object(
+                    v.ids
+                        .into_iter()
+                        .map(|(k, v)| (k, Value::String(v)))
+                        .collect(),
+                ),
+            );
+        }
+        insert_optional(&mut object, "service", v.service);
+        insert_optional(&mut object, "rel", v.rel);
+        Value::Object(object)
+    }
+}
+
+impl Reference {
+    /// Construct a new reference to `resource`.
+    pub fn new(resource: ShapeID) -> Self {
+        Self {
+            resource,
+            ids: Default::default(),
+            service: None,
+            rel: None,
+        }
+    }
+
+    required_member! { resource, ShapeID }
+
+    hash_member! { ids, id, String, member, String }
+
+    optional_member! { service, ShapeID }
+
+    optional_member! { rel, String }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by the types that may be items in a list trait value.
+///
+trait ListItem: Sized {
+    fn from_item(trait_name: &str, value: &Value) -> Result<Self>;
+}
+
+impl ListItem for String {
+    fn from_item(trait_name: &str, value: &Value) -> Result<Self> {
+        string_value(trait_name, value)
+    }
+}
+
+impl ListItem for ShapeID {
+    fn from_item(trait_name: &str, value: &Value) -> Result<Self> {
+        shape_id(trait_name, &string_value(trait_name, value)?)
+    }
+}
+
+impl ListItem for EnumDefinition {
+    fn from_item(_: &str, value: &Value) -> Result<Self> {
+        Self::try_from(value)
+    }
+}
+
+impl ListItem for Example {
+    fn from_item(_: &str, value: &Value) -> Result<Self> {
+        Self::try_from(value)
+    }
+}
+
+impl ListItem for Reference {
+    fn from_item(_: &str, value: &Value) -> Result<Self> {
+        Self::try_from(value)
+    }
+}
+
+fn invalid(trait_name: &str) -> Error {
+    ErrorKind::InvalidTraitValue(trait_name.to_string()).into()
+}
+
+fn object_value<'a>(trait_name: &str, value: &'a Value, keys: &[&str]) -> Result<&'a ValueMap> {
+    match value {
+        Value::Object(object) if object.keys().all(|key| keys.contains(&key.as_str())) => {
+            Ok(object)
+        }
+        _ => Err(invalid(trait_name)),
+    }
+}
+
+fn string_value(trait_name: &str, value: &Value) -> Result<String> {
+    match value {
+        Value::String(v) => Ok(v.clone()),
+        _ => Err(invalid(trait_name)),
+    }
+}
+
+fn list_value<T: ListItem>(trait_name: &str, value: &Value) -> Result<Vec<T>> {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .map(|value| T::from_item(trait_name, value))
+            .collect(),
+        _ => Err(invalid(trait_name)),
+    }
+}
+
+fn string_map(trait_name: &str, value: &Value) -> Result<HashMap<String, String>> {
+    match value {
+        Value::Object(object) => object
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), string_value(trait_name, v)?)))
+            .collect(),
+        _ => Err(invalid(trait_name)),
+    }
+}
+
+fn status_code(trait_name: &str, value: &Value) -> Result<u16> {
+    match value {
+        Value::Number(Number::Integer(v)) => u16::try_from(*v).map_err(|_| invalid(trait_name)),
+        _ => Err(invalid(trait_name)),
+    }
+}
+
+fn shape_id(trait_name: &str, value: &str) -> Result<ShapeID> {
+    ShapeID::from_str(value).map_err(|_| invalid(trait_name))
+}
+
+fn required_string(trait_name: &str, object: &ValueMap, key: &str) -> Result<String> {
+    optional_string(trait_name, object, key)?.ok_or_else(|| invalid(trait_name))
+}
+
+fn optional_string(trait_name: &str, object: &ValueMap, key: &str) -> Result<Option<String>> {
+    object
+        .get(key)
+        .map(|value| string_value(trait_name, value))
+        .transpose()
+}
+
+fn optional_integer(trait_name: &str, object: &ValueMap, key: &str) -> Result<Option<i64>> {
+    match object.get(key) {
+        None => Ok(None),
+        Some(Value::Number(Number::Integer(v))) => Ok(Some(*v)),
+        Some(_) => Err(invalid(trait_name)),
+    }
+}
+
+fn optional_boolean(trait_name: &str, object: &ValueMap, key: &str) -> Result<bool> {
+    match object.get(key) {
+        None => Ok(false),
+        Some(Value::Boolean(v)) => Ok(*v),
+        Some(_) => Err(invalid(trait_name)),
+    }
+}
+
+fn optional_list<T: ListItem>(trait_name: &str, object: &ValueMap, key: &str) -> Result<Vec<T>> {
+    match object.get(key) {
+        None => Ok(Default::default()),
+        Some(value) => list_value(trait_name, value),
+    }
+}
+
+fn insert_optional<V: Into<Value>>(object: &mut ValueMap, key: &str, value: Option<V>) {
+    if let Some(value) = value {
+        let _ = object.insert(key.to_string(), value.into());
+    }
+}
+
+fn insert_list<V: Into<Value>>(object: &mut ValueMap, key: &str, values: Vec<V>) {
+    if !values.is_empty() {
+        let _ = object.insert(
+            key.to_string(),
+            Value::Array(values.into_iter().map(V::into).collect()),
+        );
+    }
+}
+
+fn insert_flag(object: &mut ValueMap, key: &str, value: bool) {
+    if value {
+        let _ = object.insert(key.to_string(), Value::Boolean(value));
+    }
+}
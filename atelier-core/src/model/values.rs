@@ -4,9 +4,14 @@ within shapes, but only shape IDs, as well as the values provided to trait appli
 statements.
 */
 
+use crate::error::{Error, ErrorKind};
 use crate::model::ShapeID;
-use std::collections::HashMap;
+use indexmap::IndexMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -16,16 +21,27 @@ use std::fmt::{Display, Formatter};
 /// The Smithy specification deals with numbers that are representable in JSON, such a production
 /// does not distinguish between integer, decimal, or floating point values however Rust cares.
 ///
+/// Values that cannot be represented by an `i64` or `f64` without loss of precision, for example
+/// the default value of a `bigInteger` or the bounds of a `@range` on a `bigDecimal`, are kept as
+/// the text of the original literal in the `BigInteger` and `BigDecimal` variants. The `FromStr`
+/// implementation will select the appropriate variant for a numeric literal.
+///
 /// Corresponds to the `number` production in §2.5,
 ///   [Node values](https://awslabs.github.io/smithy/1.0/spec/core/lexical-structure.html#node-values),
 ///   of the Smithy 1.0 Specification.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub enum Number {
     /// An integer value
     Integer(i64),
     /// A floating point value
     Float(f64),
+    /// An integer value too large for an `i64`, as the text of the original literal.
+    BigInteger(String),
+    /// A decimal value that cannot be held in an `f64` without loss of precision, as the text
+    /// of the original literal.
+    BigDecimal(String),
 }
 
 ///
@@ -37,6 +53,7 @@ pub enum Number {
 ///   [Node values](https://awslabs.github.io/smithy/1.0/spec/core/lexical-structure.html#node-values),
 ///   of the Smithy 1.0 Specification.
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub enum Value {
     /// An array (Smithy list or set) of other node values.
@@ -53,8 +70,23 @@ pub enum Value {
     None,
 }
 
-/// The type of an Object value.
-pub type ValueMap = HashMap<String, Value>;
+/// The type of an Object value, this retains the order in which keys were inserted.
+pub type ValueMap = IndexMap<String, Value>;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A finite number in the form `0.digits × 10^exponent`, with no leading or trailing zeros in
+/// `digits`; zero has no digits. This allows numbers of any size to be compared exactly.
+///
+#[derive(Debug, PartialEq, Eq)]
+struct Decimal {
+    negative: bool,
+    digits: Vec<u8>,
+    exponent: i64,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
@@ -101,7 +133,161 @@ impl Display for Number {
         match self {
             Self::Integer(n) => write!(f, "{}", n),
             Self::Float(n) => write!(f, "{}", n),
+            Self::BigInteger(n) | Self::BigDecimal(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+impl FromStr for Number {
+    type Err = Error;
+
+    ///
+    /// Parse a JSON/Smithy numeric literal; integers that do not fit in an `i64` become
+    /// `BigInteger`, and decimals with more than 15 significant digits, or outside the range of
+    /// an `f64`, become `BigDecimal`.
+    ///
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::from(ErrorKind::InvalidNumber(s.to_string()));
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            None => (s, None),
+            Some(index) => (&s[..index], Some(&s[index + 1..])),
+        };
+        let unsigned = mantissa.strip_prefix('-').unwrap_or(mantissa);
+        let (int, frac) = match unsigned.split_once('.') {
+            None => (unsigned, None),
+            Some((int, frac)) => (int, Some(frac)),
+        };
+        let is_digits = |v: &str| !v.is_empty() && v.chars().all(|c| c.is_ascii_digit());
+        let exponent_valid = exponent
+            .map(|e| is_digits(e.strip_prefix(['-', '+']).unwrap_or(e)))
+            .unwrap_or(true);
+        if !is_digits(int) || !frac.map(is_digits).unwrap_or(true) || !exponent_valid {
+            return Err(invalid());
+        }
+
+        if frac.is_none() && exponent.is_none() {
+            Ok(match i64::from_str(s) {
+                Ok(v) => Self::Integer(v),
+                Err(_) => Self::BigInteger(s.to_string()),
+            })
+        } else {
+            let digits: String = int
+                .chars()
+                .chain(frac.unwrap_or_default().chars())
+                .collect();
+            let significant = digits.trim_start_matches('0').trim_end_matches('0');
+            let v = f64::from_str(s).map_err(|_| invalid())?;
+            if significant.len() > 15
+                || !v.is_finite()
+                || (!significant.is_empty() && v.abs() < f64::MIN_POSITIVE)
+            {
+                Ok(Self::BigDecimal(s.to_string()))
+            } else {
+                Ok(Self::Float(v))
+            }
+        }
+    }
+}
+
+impl Number {
+    /// Returns `true` if this is either an `Integer` or a `BigInteger` value, else `false`.
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Self::Integer(_) | Self::BigInteger(_))
+    }
+
+    ///
+    /// Returns this number as an `f64`, this is exact for `Float` values but may lose precision
+    /// for all other variants.
+    ///
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            Self::Integer(v) => *v as f64,
+            Self::Float(v) => *v,
+            Self::BigInteger(v) | Self::BigDecimal(v) => f64::from_str(v).unwrap_or(f64::NAN),
+        }
+    }
+
+    ///
+    /// Compare the numeric values of this number and `other` without any loss of precision, so
+    /// that, for example, an `Integer` and a `BigInteger` are ordered by value. Returns `None` if
+    /// either value is a `Float` that is not finite.
+    ///
+    pub fn numeric_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Decimal::from_number(self)?.cmp(&Decimal::from_number(other)?))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = |d: &Self| match (d.digits.is_empty(), d.negative) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        match sign(self).cmp(&sign(other)) {
+            Ordering::Equal => {
+                let magnitude = self
+                    .exponent
+                    .cmp(&other.exponent)
+                    .then_with(|| self.digits.cmp(&other.digits));
+                if self.negative {
+                    magnitude.reverse()
+                } else {
+                    magnitude
+                }
+            }
+            ordering => ordering,
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Decimal {
+    fn from_number(number: &Number) -> Option<Self> {
+        match number {
+            Number::Integer(v) => Self::parse(&v.to_string()),
+            Number::Float(v) if v.is_finite() => Self::parse(&format!("{:e}", v)),
+            Number::Float(_) => None,
+            Number::BigInteger(v) | Number::BigDecimal(v) => Self::parse(v),
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            Some(index) => (&s[..index], i64::from_str(&s[index + 1..]).ok()?),
+            None => (s, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        let mut digits: Vec<u8> = Vec::with_capacity(integer.len() + fraction.len());
+        for c in integer.chars().chain(fraction.chars()) {
+            digits.push(c.to_digit(10)? as u8);
+        }
+        let mut exponent = exponent.checked_add(integer.len() as i64)?;
+        let leading = digits.iter().take_while(|d| **d == 0).count();
+        let _ = digits.drain(..leading);
+        exponent -= leading as i64;
+        while digits.last() == Some(&0) {
+            let _ = digits.pop();
         }
+        Some(Self {
+            negative: negative && !digits.is_empty(),
+            exponent: if digits.is_empty() { 0 } else { exponent },
+            digits,
+        })
     }
 }
 
@@ -191,8 +377,8 @@ impl From<&[Value]> for Value {
     }
 }
 
-impl From<HashMap<String, Value>> for Value {
-    fn from(v: HashMap<String, Value>) -> Self {
+impl From<ValueMap> for Value {
+    fn from(v: ValueMap) -> Self {
         Self::Object(v)
     }
 }
@@ -227,7 +413,7 @@ impl Display for Value {
 impl Value {
     is_as! { array, Array, Vec<Value> }
 
-    is_as! { object, Object, HashMap<String, Value> }
+    is_as! { object, Object, ValueMap }
 
     is_as! { number, Number, Number }
 
@@ -1,13 +1,15 @@
 /*!
-This module provides a trait and public function to help implement model visitors.
+This module provides traits and public functions to help implement model visitors, both those that
+read a model and those that rewrite a model in place.
 
 For more information, see [the Rust Atelier book](https://rust-atelier.dev/using/visitor.html).
 
 */
 
+use crate::error::{Error, ErrorKind};
 use crate::model::shapes::{
-    AppliedTraits, HasTraits, ListOrSet, Map, Operation, Resource, Service, ShapeKind, Simple,
-    StructureOrUnion,
+    AppliedTraits, Enumeration, HasTraits, ListOrSet, Map, MemberShape, Operation, Resource,
+    Service, ShapeKind, Simple, StructureOrUnion, TopLevelShape,
 };
 use crate::model::values::Value;
 use crate::model::{HasIdentity, Identifier, Model, ShapeID};
@@ -16,6 +18,7 @@ use crate::syntax::{
     MEMBER_LIST, MEMBER_OPERATIONS, MEMBER_OUTPUT, MEMBER_PUT, MEMBER_READ, MEMBER_RESOURCES,
     MEMBER_UPDATE,
 };
+use std::collections::HashMap;
 
 // ------------------------------------------------------------------------------------------------
 // Macros
@@ -28,17 +31,21 @@ macro_rules! visit_fn {
         fn $fn_name(
             &mut self,
             id: &ShapeID,
-            traits: &AppliedTraits,
-            shape: &$shape_type,
-        ) -> Result<(), Self::Error> {
-            Ok(())
+            traits: &mut AppliedTraits,
+            shape: &mut $shape_type,
+        ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+            Ok(VisitAction::Keep)
         }
     };
     (mut $fn_name:ident, $doc:expr) => {
         #[doc = $doc]
         #[allow(unused_variables)]
-        fn $fn_name(&mut self, id: &ShapeID, traits: &AppliedTraits) -> Result<(), Self::Error> {
-            Ok(())
+        fn $fn_name(
+            &mut self,
+            id: &ShapeID,
+            traits: &mut AppliedTraits,
+        ) -> Result<VisitAction<TopLevelShape>, Self::Error> {
+            Ok(VisitAction::Keep)
         }
     };
     ($fn_name:ident, $shape_type:ty, $doc:expr) => {
@@ -61,6 +68,7 @@ macro_rules! visit_fn {
         }
     };
 }
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -88,6 +96,8 @@ pub trait ModelVisitor {
     visit_fn! { map, Map, "Called for each `ShapeKind::Map` in this model's **shapes** collection." }
     visit_fn! { structure, StructureOrUnion, "Called for each `ShapeKind::Structure` in this model's **shapes** collection." }
     visit_fn! { union, StructureOrUnion, "Called for each `ShapeKind::Union` in this model's **shapes** collection." }
+    visit_fn! { enum_shape, Enumeration, "Called for each `ShapeKind::Enum` in this model's **shapes** collection." }
+    visit_fn! { int_enum, Enumeration, "Called for each `ShapeKind::IntEnum` in this model's **shapes** collection." }
     visit_fn! { service, Service, "Called for each `ShapeKind::Service` in this model's **shapes** collection." }
     visit_fn! { operation, Operation, "Called for each `ShapeKind::Operation` in this model's **shapes** collection." }
     visit_fn! { resource, Resource, "Called for each `ShapeKind::Resource` in this model's **shapes** collection." }
@@ -131,6 +141,94 @@ pub trait MemberVisitor {
     }
 }
 
+///
+/// A trait implemented by tools that wish to rewrite parts of a model in place, it is the mutable
+/// counterpart of `ModelVisitor` and is used with `walk_model_mut`.
+///
+/// Each method is passed the element it visits mutably, and any changes made to it are kept. The
+/// method then returns a `VisitAction` to keep, replace, or remove the element. Shapes may be added
+/// to the model by returning them from `added_shapes`, which is called once all the existing
+/// shapes have been visited.
+///
+/// Each method in the trait will return `Ok(VisitAction::Keep)` by default so a particular
+/// implementation can choose which methods to override.
+///
+pub trait ModelVisitorMut {
+    /// The error which will be returned by this visitor.
+    type Error;
+
+    /// Called once for each key in the model's metadata.
+    #[allow(unused_variables)]
+    fn metadata(
+        &mut self,
+        key: &str,
+        value: &mut Value,
+    ) -> Result<VisitAction<Value>, Self::Error> {
+        Ok(VisitAction::Keep)
+    }
+
+    visit_fn! { mut simple_shape, Simple, "Called for each `ShapeKind::Simple` in this model's **shapes** collection." }
+    visit_fn! { mut list, ListOrSet, "Called for each `ShapeKind::List` in this model's **shapes** collection." }
+    visit_fn! { mut set, ListOrSet, "Called for each `ShapeKind::Set` in this model's **shapes** collection." }
+    visit_fn! { mut map, Map, "Called for each `ShapeKind::Map` in this model's **shapes** collection." }
+    visit_fn! { mut structure, StructureOrUnion, "Called for each `ShapeKind::Structure` in this model's **shapes** collection." }
+    visit_fn! { mut union, StructureOrUnion, "Called for each `ShapeKind::Union` in this model's **shapes** collection." }
+    visit_fn! { mut enum_shape, Enumeration, "Called for each `ShapeKind::Enum` in this model's **shapes** collection." }
+    visit_fn! { mut int_enum, Enumeration, "Called for each `ShapeKind::IntEnum` in this model's **shapes** collection." }
+    visit_fn! { mut service, Service, "Called for each `ShapeKind::Service` in this model's **shapes** collection." }
+    visit_fn! { mut operation, Operation, "Called for each `ShapeKind::Operation` in this model's **shapes** collection." }
+    visit_fn! { mut resource, Resource, "Called for each `ShapeKind::Resource` in this model's **shapes** collection." }
+    visit_fn! { mut unresolved_id, "Called for each `ShapeKind::Unresolved` shape identifier in this model's **shapes** collection." }
+
+    ///
+    /// Called for each member of a list, set, map, structure, union, or enumeration shape that
+    /// was kept by the corresponding shape method. The members of list, set, and map shapes are
+    /// required and so returning `VisitAction::Remove` for one of them is ignored.
+    ///
+    #[allow(unused_variables)]
+    fn member(
+        &mut self,
+        parent_shape_id: &ShapeID,
+        member: &mut MemberShape,
+    ) -> Result<VisitAction<MemberShape>, Self::Error> {
+        Ok(VisitAction::Keep)
+    }
+
+    ///
+    /// Called for each trait applied to a kept shape or member, `shape_id` is the identifier of
+    /// the shape, or member, the trait is applied to. Replacing the element replaces the trait's
+    /// value.
+    ///
+    #[allow(unused_variables)]
+    fn applied_trait(
+        &mut self,
+        shape_id: &ShapeID,
+        trait_id: &ShapeID,
+        value: &mut Option<Value>,
+    ) -> Result<VisitAction<Option<Value>>, Self::Error> {
+        Ok(VisitAction::Keep)
+    }
+
+    /// Called once all shapes have been visited, any shapes returned are added to the model and
+    /// replace any existing shapes with the same identifier.
+    fn added_shapes(&mut self) -> Result<Vec<TopLevelShape>, Self::Error> {
+        Ok(Default::default())
+    }
+}
+
+///
+/// The action a `ModelVisitorMut` method requests for the element it was called with.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum VisitAction<T> {
+    /// Keep the element, including any changes made to it in place.
+    Keep,
+    /// Replace the element with the one provided.
+    Replace(T),
+    /// Remove the element.
+    Remove,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -216,6 +314,32 @@ where
                     }
                 }
             }
+            ShapeKind::Enum(body) => {
+                visitor.enum_shape(shape.id(), shape.traits(), body)?;
+                if let Some(member_visitor) = visitor.member_visitor() {
+                    for member in body.members.values() {
+                        member_visitor.member(
+                            shape.id(),
+                            member.id(),
+                            member.target(),
+                            Some(member.traits()),
+                        )?;
+                    }
+                }
+            }
+            ShapeKind::IntEnum(body) => {
+                visitor.int_enum(shape.id(), shape.traits(), body)?;
+                if let Some(member_visitor) = visitor.member_visitor() {
+                    for member in body.members.values() {
+                        member_visitor.member(
+                            shape.id(),
+                            member.id(),
+                            member.target(),
+                            Some(member.traits()),
+                        )?;
+                    }
+                }
+            }
             ShapeKind::Service(body) => {
                 visitor.service(shape.id(), &shape.traits(), &body)?;
                 if let Some(member_visitor) = visitor.member_visitor() {
@@ -349,3 +473,222 @@ where
 
     Ok(())
 }
+
+///
+/// Walk the provided model calling out to the visitor, which may rewrite the model in place.
+/// Metadata keys and shapes are visited in sorted order; for each shape the shape method is called
+/// first, followed by any traits applied to the shape, and then its members along with their
+/// traits. A replaced or removed element is not visited further.
+///
+/// If a shape is replaced by one with a different identifier the model is updated accordingly,
+/// however if that identifier is already used by another shape, visited or not, the walk fails
+/// with `ErrorKind::ReplacedShapeConflict`; the same is true for the members of a shape. If the
+/// walk fails the model retains the changes made so far, but not the failing element's.
+///
+pub fn walk_model_mut<V>(model: &mut Model, visitor: &mut V) -> Result<(), V::Error>
+where
+    V: ModelVisitorMut,
+    V::Error: From<Error>,
+{
+    let mut keys: Vec<String> = model.metadata.keys().cloned().collect();
+    keys.sort();
+    for key in keys {
+        if let Some(value) = model.metadata.get_mut(&key) {
+            match visitor.metadata(&key, value)? {
+                VisitAction::Keep => {}
+                VisitAction::Replace(new_value) => *value = new_value,
+                VisitAction::Remove => {
+                    let _ = model.metadata.shift_remove(&key);
+                }
+            }
+        }
+    }
+
+    let mut shape_ids: Vec<ShapeID> = model.shapes.keys().cloned().collect();
+    shape_ids.sort();
+    let mut rewritten: HashMap<ShapeID, TopLevelShape> = HashMap::with_capacity(shape_ids.len());
+    for shape_id in shape_ids {
+        if let Some(mut shape) = model.shapes.remove(&shape_id) {
+            match walk_shape_mut(&mut shape, visitor) {
+                Ok(VisitAction::Keep) => {
+                    let _ = rewritten.insert(shape_id, shape);
+                }
+                Ok(VisitAction::Replace(new_shape)) => {
+                    let new_id = new_shape.id().clone();
+                    if new_id != shape_id
+                        && (rewritten.contains_key(&new_id) || model.shapes.contains_key(&new_id))
+                    {
+                        let _ = model.shapes.insert(shape_id, shape);
+                        model.shapes.extend(rewritten);
+                        return Err(Error::from(ErrorKind::ReplacedShapeConflict(new_id)).into());
+                    }
+                    let _ = rewritten.insert(new_id, new_shape);
+                }
+                Ok(VisitAction::Remove) => {}
+                Err(e) => {
+                    let _ = model.shapes.insert(shape_id, shape);
+                    model.shapes.extend(rewritten);
+                    return Err(e);
+                }
+            }
+        }
+    }
+    model.shapes = rewritten;
+
+    for shape in visitor.added_shapes()? {
+        let _ = model.shapes.insert(shape.id().clone(), shape);
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn walk_shape_mut<V>(
+    shape: &mut TopLevelShape,
+    visitor: &mut V,
+) -> Result<VisitAction<TopLevelShape>, V::Error>
+where
+    V: ModelVisitorMut,
+    V::Error: From<Error>,
+{
+    let shape_id = shape.id().clone();
+    let mut traits = std::mem::take(shape.traits_mut());
+    let action = match shape.body_mut() {
+        ShapeKind::Simple(body) => visitor.simple_shape(&shape_id, &mut traits, body),
+        ShapeKind::List(body) => visitor.list(&shape_id, &mut traits, body),
+        ShapeKind::Set(body) => visitor.set(&shape_id, &mut traits, body),
+        ShapeKind::Map(body) => visitor.map(&shape_id, &mut traits, body),
+        ShapeKind::Structure(body) => visitor.structure(&shape_id, &mut traits, body),
+        ShapeKind::Union(body) => visitor.union(&shape_id, &mut traits, body),
+        ShapeKind::Enum(body) => visitor.enum_shape(&shape_id, &mut traits, body),
+        ShapeKind::IntEnum(body) => visitor.int_enum(&shape_id, &mut traits, body),
+        ShapeKind::Service(body) => visitor.service(&shape_id, &mut traits, body),
+        ShapeKind::Operation(body) => visitor.operation(&shape_id, &mut traits, body),
+        ShapeKind::Resource(body) => visitor.resource(&shape_id, &mut traits, body),
+        ShapeKind::Unresolved => visitor.unresolved_id(&shape_id, &mut traits),
+    };
+    *shape.traits_mut() = traits;
+    match action? {
+        VisitAction::Keep => {}
+        action => return Ok(action),
+    }
+
+    walk_traits_mut(&shape_id, shape.traits_mut(), visitor)?;
+
+    match shape.body_mut() {
+        ShapeKind::List(body) | ShapeKind::Set(body) => {
+            walk_required_member_mut(&shape_id, &mut body.member, visitor)?;
+        }
+        ShapeKind::Map(body) => {
+            walk_required_member_mut(&shape_id, &mut body.key, visitor)?;
+            walk_required_member_mut(&shape_id, &mut body.value, visitor)?;
+        }
+        ShapeKind::Structure(body) | ShapeKind::Union(body) => {
+            walk_members_mut(&shape_id, &mut body.members, visitor)?;
+        }
+        ShapeKind::Enum(body) | ShapeKind::IntEnum(body) => {
+            walk_members_mut(&shape_id, &mut body.members, visitor)?;
+        }
+        _ => {}
+    }
+
+    Ok(VisitAction::Keep)
+}
+
+fn walk_members_mut<V>(
+    shape_id: &ShapeID,
+    members: &mut HashMap<Identifier, MemberShape>,
+    visitor: &mut V,
+) -> Result<(), V::Error>
+where
+    V: ModelVisitorMut,
+    V::Error: From<Error>,
+{
+    let mut member_ids: Vec<Identifier> = members.keys().cloned().collect();
+    member_ids.sort();
+    for member_id in member_ids {
+        if let Some(mut member) = members.remove(&member_id) {
+            let action = walk_member_mut(shape_id, &mut member, visitor);
+            match action {
+                Ok(VisitAction::Keep) => {
+                    let _ = members.insert(member_id, member);
+                }
+                Ok(VisitAction::Replace(new_member)) => {
+                    let new_id = new_member.id().clone();
+                    if new_id != member_id && members.contains_key(&new_id) {
+                        let _ = members.insert(member_id, member);
+                        let conflict =
+                            ErrorKind::ReplacedShapeConflict(shape_id.make_member(new_id));
+                        return Err(Error::from(conflict).into());
+                    }
+                    let _ = members.insert(new_id, new_member);
+                }
+                Ok(VisitAction::Remove) => {}
+                Err(e) => {
+                    let _ = members.insert(member_id, member);
+                    return Err(e);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn walk_required_member_mut<V>(
+    shape_id: &ShapeID,
+    member: &mut MemberShape,
+    visitor: &mut V,
+) -> Result<(), V::Error>
+where
+    V: ModelVisitorMut,
+{
+    if let VisitAction::Replace(new_member) = walk_member_mut(shape_id, member, visitor)? {
+        *member = new_member;
+    }
+    Ok(())
+}
+
+fn walk_member_mut<V>(
+    shape_id: &ShapeID,
+    member: &mut MemberShape,
+    visitor: &mut V,
+) -> Result<VisitAction<MemberShape>, V::Error>
+where
+    V: ModelVisitorMut,
+{
+    match visitor.member(shape_id, member)? {
+        VisitAction::Keep => {
+            let member_id = shape_id.make_member(member.id().clone());
+            walk_traits_mut(&member_id, member.traits_mut(), visitor)?;
+            Ok(VisitAction::Keep)
+        }
+        action => Ok(action),
+    }
+}
+
+fn walk_traits_mut<V>(
+    shape_id: &ShapeID,
+    traits: &mut AppliedTraits,
+    visitor: &mut V,
+) -> Result<(), V::Error>
+where
+    V: ModelVisitorMut,
+{
+    let mut trait_ids: Vec<ShapeID> = traits.keys().cloned().collect();
+    trait_ids.sort();
+    for trait_id in trait_ids {
+        if let Some(value) = traits.get_mut(&trait_id) {
+            match visitor.applied_trait(shape_id, &trait_id, value)? {
+                VisitAction::Keep => {}
+                VisitAction::Replace(new_value) => *value = new_value,
+                VisitAction::Remove => {
+                    let _ = traits.remove(&trait_id);
+                }
+            }
+        }
+    }
+    Ok(())
+}
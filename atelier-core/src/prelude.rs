@@ -1,10 +1,21 @@
 /*!
-Provides constant valued names from the prelude model described in the Smithy specification.
+Provides constant valued names from the prelude model described in the Smithy specification, as
+well as the prelude itself as a `Model`.
 */
 
 use std::collections::HashSet;
 
+use crate::builder::traits::{length, length_min, pattern, range};
+use crate::builder::values::{ArrayBuilder, ObjectBuilder};
+use crate::builder::{
+    ListBuilder, MapBuilder, MemberBuilder, ModelBuilder, ShapeTraits, SimpleShapeBuilder,
+    StructureBuilder, TraitBuilder,
+};
 use crate::error::Result;
+use crate::model::shapes::{Simple, TopLevelShape};
+use crate::model::Model;
+use crate::Version;
+use std::convert::TryInto;
 
 // ------------------------------------------------------------------------------------------------
 // Macros
@@ -56,6 +67,33 @@ pub fn prelude_shape_named(name: &str) -> Result<ShapeID> {
     ))
 }
 
+///
+/// Return the Smithy prelude as a model; this contains the definitions of all the prelude shapes
+/// and traits, including the `@trait` selectors, conflicts, and structural exclusivity of each
+/// trait. The model has the latest supported Smithy version, see `prelude_model_for_version` to
+/// merge the prelude with models of other versions.
+///
+pub fn prelude_model() -> &'static Model {
+    &PRELUDE_MODEL
+}
+
+///
+/// Return a copy of the Smithy prelude model with the given Smithy version, suitable for merging
+/// into a model of that version with `Model::merge`.
+///
+pub fn prelude_model_for_version(version: Version) -> Model {
+    let mut model = PRELUDE_MODEL.clone();
+    model.smithy_version = version;
+    model
+}
+
+///
+/// Return the definition of the prelude shape `shape_id`, if there is one.
+///
+pub(crate) fn prelude_definition(shape_id: &ShapeID) -> Option<&'static TopLevelShape> {
+    PRELUDE_MODEL.shape(shape_id)
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Names
 // ------------------------------------------------------------------------------------------------
@@ -72,6 +110,10 @@ lazy_static! {
     static ref PRELUDE_NAMESPACE_ID: NamespaceID = NamespaceID::new_unchecked(PRELUDE_NAMESPACE);
 }
 
+lazy_static! {
+    static ref PRELUDE_MODEL: Model = make_prelude_model();
+}
+
 lazy_static! {
     static ref PRELUDE_SHAPES: HashSet<&'static str> = [
         SHAPE_STRING,
@@ -94,6 +136,7 @@ lazy_static! {
         SHAPE_PRIMITIVEFLOAT,
         SHAPE_DOUBLE,
         SHAPE_PRIMITIVEDOUBLE,
+        SHAPE_UNIT,
     ]
     .iter()
     .cloned()
@@ -126,9 +169,12 @@ lazy_static! {
         TRAIT_HOSTLABEL,
         TRAIT_NONEMPTYSTRING,
         TRAIT_PRIVATE,
+        TRAIT_INTERNAL,
         TRAIT_TITLE,
         TRAIT_REQUIRED,
         TRAIT_ENUM,
+        TRAIT_ENUMVALUE,
+        TRAIT_MIXIN,
         TRAIT_IDEMPOTENCYTOKEN,
         TRAIT_TAGS,
         TRAIT_HTTPAPIKEYLOCATIONS,
@@ -174,6 +220,13 @@ lazy_static! {
         TRAIT_AUTHTRAITREFERENCE,
         TRAIT_XMLNAMESPACE,
         TRAIT_LENGTH,
+        TRAIT_EXAMPLEERROR,
+        TRAIT_HTTPRESPONSECODE,
+        TRAIT_SPARSE,
+        TRAIT_INPUT,
+        TRAIT_OUTPUT,
+        TRAIT_UNITTYPE,
+        TRAIT_DEFAULT,
     ]
     .iter()
     .cloned()
@@ -302,6 +355,12 @@ string_const!(
     "The identifier for the simple shape `PrimitiveDouble`"
 );
 
+string_const!(
+    SHAPE_UNIT,
+    "Unit",
+    "The identifier for the unit structure shape `Unit`"
+);
+
 // ------------------------------------------------------------------------------------------------
 
 string_const!(
@@ -442,6 +501,12 @@ string_const!(
     " The identifier for the string `NonEmptyString`"
 );
 
+string_const!(
+    TRAIT_INTERNAL,
+    "internal",
+    " The identifier for the structure trait `internal`"
+);
+
 string_const!(
     TRAIT_PRIVATE,
     "private",
@@ -472,6 +537,18 @@ string_const!(
     " The identifier for the list trait `enum`"
 );
 
+string_const!(
+    TRAIT_ENUMVALUE,
+    "enumValue",
+    " The identifier for the document trait `enumValue`"
+);
+
+string_const!(
+    TRAIT_MIXIN,
+    "mixin",
+    " The identifier for the structure trait `mixin`"
+);
+
 string_const!(
     TRAIT_IDEMPOTENCYTOKEN,
     "idempotencyToken",
@@ -735,3 +812,686 @@ string_const!(
     "length",
     " The identifier for the structure trait `length`"
 );
+
+string_const!(
+    TRAIT_EXAMPLEERROR,
+    "ExampleError",
+    " The identifier for the structure `ExampleError`"
+);
+
+string_const!(
+    TRAIT_HTTPRESPONSECODE,
+    "httpResponseCode",
+    " The identifier for the structure trait `httpResponseCode`"
+);
+
+string_const!(
+    TRAIT_SPARSE,
+    "sparse",
+    " The identifier for the structure trait `sparse`"
+);
+
+string_const!(
+    TRAIT_INPUT,
+    "input",
+    " The identifier for the structure trait `input`"
+);
+
+string_const!(
+    TRAIT_OUTPUT,
+    "output",
+    " The identifier for the structure trait `output`"
+);
+
+string_const!(
+    TRAIT_UNITTYPE,
+    "unitType",
+    " The identifier for the structure trait `unitType`"
+);
+
+string_const!(
+    TRAIT_DEFAULT,
+    "default",
+    " The identifier for the document trait `default`"
+);
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Build the prelude model, the shapes and trait definitions follow those in the Smithy
+/// specification's prelude.
+///
+fn make_prelude_model() -> Model {
+    let mut builder = ModelBuilder::new(Version::latest(), PRELUDE_NAMESPACE);
+
+    // --------------------------------------------------------------------------------------------
+    // Simple shapes
+    // --------------------------------------------------------------------------------------------
+
+    for (name, simple) in &[
+        (SHAPE_BLOB, Simple::Blob),
+        (SHAPE_BOOLEAN, Simple::Boolean),
+        (SHAPE_PRIMITIVEBOOLEAN, Simple::Boolean),
+        (SHAPE_STRING, Simple::String),
+        (SHAPE_TIMESTAMP, Simple::Timestamp),
+        (SHAPE_BYTE, Simple::Byte),
+        (SHAPE_PRIMITIVEBYTE, Simple::Byte),
+        (SHAPE_SHORT, Simple::Short),
+        (SHAPE_PRIMITIVESHORT, Simple::Short),
+        (SHAPE_INTEGER, Simple::Integer),
+        (SHAPE_PRIMITIVEINTEGER, Simple::Integer),
+        (SHAPE_LONG, Simple::Long),
+        (SHAPE_PRIMITIVELONG, Simple::Long),
+        (SHAPE_FLOAT, Simple::Float),
+        (SHAPE_PRIMITIVEFLOAT, Simple::Float),
+        (SHAPE_DOUBLE, Simple::Double),
+        (SHAPE_PRIMITIVEDOUBLE, Simple::Double),
+        (SHAPE_BIGINTEGER, Simple::BigInteger),
+        (SHAPE_BIGDECIMAL, Simple::BigDecimal),
+        (SHAPE_DOCUMENT, Simple::Document),
+    ] {
+        let _ = builder.simple_shape(SimpleShapeBuilder::new(name, simple.clone()));
+    }
+    let _ = builder.structure(
+        StructureBuilder::new(SHAPE_UNIT)
+            .apply_trait(unit_type())
+            .into(),
+    );
+
+    // --------------------------------------------------------------------------------------------
+    // Shapes used by trait definitions
+    // --------------------------------------------------------------------------------------------
+
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::string(TRAIT_NONEMPTYSTRING)
+            .apply_trait(length_min(1))
+            .into(),
+    );
+    let _ = builder.list(ListBuilder::new(
+        TRAIT_NONEMPTYSTRINGLIST,
+        TRAIT_NONEMPTYSTRING,
+    ));
+    let _ = builder.map(MapBuilder::new(
+        TRAIT_NONEMPTYSTRINGMAP,
+        TRAIT_NONEMPTYSTRING,
+        TRAIT_NONEMPTYSTRING,
+    ));
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::string(TRAIT_TRAITSHAPEID)
+            .apply_trait(TraitBuilder::with_value(
+                TRAIT_IDREF,
+                ObjectBuilder::default()
+                    .boolean("failWhenMissing", true)
+                    .string("selector", "[trait|trait]")
+                    .into(),
+            ))
+            .into(),
+    );
+    let _ = builder.list(ListBuilder::new(TRAIT_TRAITSHAPEIDLIST, TRAIT_TRAITSHAPEID));
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::string(TRAIT_AUTHTRAITREFERENCE)
+            .apply_trait(TraitBuilder::with_value(
+                TRAIT_IDREF,
+                ObjectBuilder::default()
+                    .boolean("failWhenMissing", true)
+                    .string("selector", "[trait|authDefinition]")
+                    .into(),
+            ))
+            .into(),
+    );
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::string(TRAIT_STRUCTURALLYEXCLUSIVE)
+            .apply_trait(string_enum(&["member", "target"]))
+            .into(),
+    );
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::string(TRAIT_HTTPAPIKEYLOCATIONS)
+            .apply_trait(string_enum(&["header", "query"]))
+            .into(),
+    );
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::string(TRAIT_ENUMCONSTANTBODYNAME)
+            .apply_trait(pattern("^[a-zA-Z_]+[a-zA-Z_0-9]*$"))
+            .into(),
+    );
+    structure(
+        &mut builder,
+        TRAIT_ENUMDEFINITION,
+        &[
+            required("value", TRAIT_NONEMPTYSTRING),
+            member("name", TRAIT_ENUMCONSTANTBODYNAME),
+            member("documentation", SHAPE_STRING),
+            member("tags", TRAIT_NONEMPTYSTRINGLIST),
+            member("deprecated", SHAPE_BOOLEAN),
+        ],
+        None,
+    );
+    structure(
+        &mut builder,
+        TRAIT_REFERENCE,
+        &[
+            required("resource", TRAIT_NONEMPTYSTRING),
+            member("ids", TRAIT_NONEMPTYSTRINGMAP),
+            member("service", TRAIT_NONEMPTYSTRING),
+            member("rel", SHAPE_STRING),
+        ],
+        None,
+    );
+    structure(
+        &mut builder,
+        TRAIT_EXAMPLEERROR,
+        &[
+            member("shapeId", TRAIT_NONEMPTYSTRING),
+            member("content", SHAPE_DOCUMENT),
+        ],
+        None,
+    );
+    structure(
+        &mut builder,
+        TRAIT_EXAMPLE,
+        &[
+            required("title", SHAPE_STRING),
+            member("documentation", SHAPE_STRING),
+            member("input", SHAPE_DOCUMENT),
+            member("output", SHAPE_DOCUMENT),
+            member("error", TRAIT_EXAMPLEERROR),
+        ],
+        None,
+    );
+
+    // --------------------------------------------------------------------------------------------
+    // Annotation traits
+    // --------------------------------------------------------------------------------------------
+
+    let http_bindings = &[
+        TRAIT_HTTPLABEL,
+        TRAIT_HTTPQUERY,
+        TRAIT_HTTPHEADER,
+        TRAIT_HTTPPREFIXHEADERS,
+        TRAIT_HTTPPAYLOAD,
+        TRAIT_HTTPRESPONSECODE,
+    ];
+    for (name, definition) in vec![
+        (
+            TRAIT_BOX,
+            a_trait(":test(boolean, byte, short, integer, long, float, double, member > :test(boolean, byte, short, integer, long, float, double))"),
+        ),
+        (TRAIT_REQUIRED, a_trait("structure > member")),
+        (
+            TRAIT_SENSITIVE,
+            a_trait(":not(:test(service, operation, resource))"),
+        ),
+        (
+            TRAIT_READONLY,
+            a_trait_with("operation", None, &[TRAIT_IDEMPOTENT]),
+        ),
+        (
+            TRAIT_IDEMPOTENT,
+            a_trait_with("operation", None, &[TRAIT_READONLY]),
+        ),
+        (TRAIT_PRIVATE, a_trait("*")),
+        (TRAIT_INTERNAL, a_trait("*")),
+        (TRAIT_STREAMING, a_trait(":is(blob, union)")),
+        (TRAIT_REQUIRESLENGTH, a_trait("blob[trait|streaming]")),
+        (
+            TRAIT_HTTPLABEL,
+            a_trait_with(
+                "structure > member[trait|required] :test(> :test(string, number, boolean, timestamp))",
+                None,
+                &except(http_bindings, TRAIT_HTTPLABEL),
+            ),
+        ),
+        (
+            TRAIT_HTTPPAYLOAD,
+            a_trait_with(
+                "structure > :test(member > :test(string, blob, structure, union, document))",
+                Some("member"),
+                &except(http_bindings, TRAIT_HTTPPAYLOAD),
+            ),
+        ),
+        (
+            TRAIT_HTTPRESPONSECODE,
+            a_trait_with(
+                "structure > :test(member > integer)",
+                Some("member"),
+                &except(http_bindings, TRAIT_HTTPRESPONSECODE),
+            ),
+        ),
+        (
+            TRAIT_HOSTLABEL,
+            a_trait("structure > :test(member[trait|required] > string)"),
+        ),
+        (
+            TRAIT_IDEMPOTENCYTOKEN,
+            a_trait("structure > :test(member > string)"),
+        ),
+        (
+            TRAIT_XMLATTRIBUTE,
+            a_trait_with(
+                "structure > :test(member > :test(boolean, number, string, timestamp))",
+                None,
+                &[TRAIT_XMLNAMESPACE],
+            ),
+        ),
+        (
+            TRAIT_XMLFLATTENED,
+            a_trait(":is(structure, union) > :test(member > :test(collection, map))"),
+        ),
+        (
+            TRAIT_UNIQUEITEMS,
+            a_trait(":test(list > member > simpleType)"),
+        ),
+        (TRAIT_UNSTABLE, a_trait("*")),
+        (TRAIT_NOREPLACE, a_trait("resource:test(-[put]->)")),
+        (TRAIT_SPARSE, a_trait(":is(list, map)")),
+        (TRAIT_INPUT, a_trait("structure")),
+        (TRAIT_OUTPUT, a_trait("structure")),
+        (
+            TRAIT_EVENTHEADER,
+            a_trait_with(
+                "structure > :test(member > :test(boolean, byte, short, integer, long, blob, string, timestamp))",
+                None,
+                &[TRAIT_EVENTPAYLOAD],
+            ),
+        ),
+        (
+            TRAIT_EVENTPAYLOAD,
+            a_trait_with(
+                "structure > :test(member > :test(blob, string, structure, union))",
+                Some("member"),
+                &[TRAIT_EVENTHEADER],
+            ),
+        ),
+        (TRAIT_HTTPCHECKSUMREQUIRED, a_trait("operation")),
+        (TRAIT_OPTIONALAUTH, a_trait("operation")),
+        (TRAIT_UNITTYPE, a_trait("structure")),
+    ] {
+        annotation_trait(&mut builder, name, definition, &[]);
+    }
+    for name in &[
+        TRAIT_HTTPBASICAUTH,
+        TRAIT_HTTPDIGESTAUTH,
+        TRAIT_HTTPBEARERAUTH,
+    ] {
+        annotation_trait(
+            &mut builder,
+            name,
+            a_trait("service"),
+            &[TraitBuilder::annotation(TRAIT_AUTHDEFINITION)],
+        );
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // String, number, and document traits
+    // --------------------------------------------------------------------------------------------
+
+    for (name, definition, constraint) in vec![
+        (TRAIT_DOCUMENTATION, a_trait("*"), None),
+        (TRAIT_SINCE, a_trait("*"), None),
+        (
+            TRAIT_TITLE,
+            a_trait(":test(service, resource, operation)"),
+            None,
+        ),
+        (TRAIT_PATTERN, a_trait(":test(string, member > string)"), None),
+        (TRAIT_JSONNAME, a_trait("structure > member"), None),
+        (
+            TRAIT_XMLNAME,
+            a_trait(":test(structure, union, member)"),
+            Some(pattern("^[a-zA-Z_][a-zA-Z_0-9-]*(:[a-zA-Z_][a-zA-Z_0-9-]*)?$")),
+        ),
+        (TRAIT_MEDIATYPE, a_trait(":test(blob, string)"), None),
+        (
+            TRAIT_HTTPHEADER,
+            a_trait_with(
+                "structure > :test(member > :test(boolean, number, string, timestamp, collection > member > :test(boolean, number, string, timestamp)))",
+                None,
+                &except(http_bindings, TRAIT_HTTPHEADER),
+            ),
+            Some(length_min(1)),
+        ),
+        (
+            TRAIT_HTTPQUERY,
+            a_trait_with(
+                "structure > member :test(> :test(simpleType, collection > member > simpleType))",
+                None,
+                &except(http_bindings, TRAIT_HTTPQUERY),
+            ),
+            Some(length_min(1)),
+        ),
+        (
+            TRAIT_HTTPPREFIXHEADERS,
+            a_trait_with(
+                "structure > member :test(> map > member[id|member=value] > :test(string, collection > member > string))",
+                Some("member"),
+                &except(http_bindings, TRAIT_HTTPPREFIXHEADERS),
+            ),
+            None,
+        ),
+        (
+            TRAIT_RESOURCEIDENTIFIER,
+            a_trait("structure > member[trait|required] > string"),
+            Some(length(Some(1), None)),
+        ),
+        (
+            TRAIT_TIMESTAMPFORMAT,
+            a_trait(":test(timestamp, member > timestamp)"),
+            Some(string_enum(&["date-time", "epoch-seconds", "http-date"])),
+        ),
+        (
+            TRAIT_ERROR,
+            a_trait_with("structure", None, &[TRAIT_TRAIT]),
+            Some(string_enum(&["client", "server"])),
+        ),
+    ] {
+        let mut shape = SimpleShapeBuilder::string(name);
+        let _ = shape.apply_trait(definition);
+        if let Some(constraint) = constraint {
+            let _ = shape.apply_trait(constraint);
+        }
+        let _ = builder.simple_shape(shape);
+    }
+
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::integer(TRAIT_HTTPERROR)
+            .apply_trait(a_trait("structure[trait|error]"))
+            .apply_trait(range(Some(200), Some(599)))
+            .into(),
+    );
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::document(TRAIT_ENUMVALUE)
+            .apply_trait(a_trait(":is(enum, intEnum) > member"))
+            .into(),
+    );
+    let _ = builder.simple_shape(
+        SimpleShapeBuilder::document(TRAIT_DEFAULT)
+            .apply_trait(a_trait(
+                ":is(simpleType, collection, map, structure > member)",
+            ))
+            .into(),
+    );
+
+    // --------------------------------------------------------------------------------------------
+    // List and map traits
+    // --------------------------------------------------------------------------------------------
+
+    list(&mut builder, TRAIT_TAGS, SHAPE_STRING, Some(a_trait("*")));
+    list(
+        &mut builder,
+        TRAIT_SUPPRESS,
+        SHAPE_STRING,
+        Some(a_trait("*")),
+    );
+    list(
+        &mut builder,
+        TRAIT_AUTH,
+        TRAIT_AUTHTRAITREFERENCE,
+        Some(a_trait(":is(service, operation)")),
+    );
+    list(
+        &mut builder,
+        TRAIT_ENUM,
+        TRAIT_ENUMDEFINITION,
+        Some(a_trait("string")),
+    );
+    list(
+        &mut builder,
+        TRAIT_REFERENCES,
+        TRAIT_REFERENCE,
+        Some(a_trait(":is(structure, string)")),
+    );
+    list(
+        &mut builder,
+        TRAIT_EXAMPLES,
+        TRAIT_EXAMPLE,
+        Some(a_trait("operation")),
+    );
+    let _ = builder.map(
+        MapBuilder::new(
+            TRAIT_EXTERNALDOCUMENTATION,
+            TRAIT_NONEMPTYSTRING,
+            TRAIT_NONEMPTYSTRING,
+        )
+        .apply_trait(a_trait("*"))
+        .apply_trait(length_min(1))
+        .into(),
+    );
+
+    // --------------------------------------------------------------------------------------------
+    // Structure traits
+    // --------------------------------------------------------------------------------------------
+
+    structure(
+        &mut builder,
+        TRAIT_TRAIT,
+        &[
+            member("selector", TRAIT_NONEMPTYSTRING),
+            member("structurallyExclusive", TRAIT_STRUCTURALLYEXCLUSIVE),
+            member("conflicts", TRAIT_NONEMPTYSTRINGLIST),
+        ],
+        Some(a_trait("structure, simpleType, collection, map, union")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_LENGTH,
+        &[member("min", SHAPE_LONG), member("max", SHAPE_LONG)],
+        Some(a_trait(
+            ":test(collection, map, string, blob, member > :test(collection, map, string, blob))",
+        )),
+    );
+    structure(
+        &mut builder,
+        TRAIT_RANGE,
+        &[
+            member("min", SHAPE_BIGDECIMAL),
+            member("max", SHAPE_BIGDECIMAL),
+        ],
+        Some(a_trait(":test(number, member > number)")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_HTTP,
+        &[
+            required("method", TRAIT_NONEMPTYSTRING),
+            required("uri", TRAIT_NONEMPTYSTRING),
+            member("code", SHAPE_PRIMITIVEINTEGER),
+        ],
+        Some(a_trait("operation")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_PAGINATED,
+        &[
+            member("inputToken", TRAIT_NONEMPTYSTRING),
+            member("outputToken", TRAIT_NONEMPTYSTRING),
+            member("items", TRAIT_NONEMPTYSTRING),
+            member("pageSize", TRAIT_NONEMPTYSTRING),
+        ],
+        Some(a_trait(":is(operation, service)")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_DEPRECATED,
+        &[
+            member("message", SHAPE_STRING),
+            member("since", SHAPE_STRING),
+        ],
+        Some(a_trait("*")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_RETRYABLE,
+        &[member("throttling", SHAPE_BOOLEAN)],
+        Some(a_trait("structure[trait|error]")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_RECOMMENDED,
+        &[member("reason", SHAPE_STRING)],
+        Some(a_trait_with("structure > member", None, &[TRAIT_REQUIRED])),
+    );
+    structure(
+        &mut builder,
+        TRAIT_XMLNAMESPACE,
+        &[
+            required("uri", TRAIT_NONEMPTYSTRING),
+            member("prefix", TRAIT_NONEMPTYSTRING),
+        ],
+        Some(a_trait(":not(:is(service, resource, operation))")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_ENDPOINT,
+        &[required("hostPrefix", TRAIT_NONEMPTYSTRING)],
+        Some(a_trait("operation")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_CORS,
+        &[
+            member("origin", TRAIT_NONEMPTYSTRING),
+            member("maxAge", SHAPE_INTEGER),
+            member("additionalAllowedHeaders", TRAIT_NONEMPTYSTRINGLIST),
+            member("additionalExposedHeaders", TRAIT_NONEMPTYSTRINGLIST),
+        ],
+        Some(a_trait("service")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_HTTPAPIKEYAUTH,
+        &[
+            required("name", TRAIT_NONEMPTYSTRING),
+            required("in", TRAIT_HTTPAPIKEYLOCATIONS),
+            member("scheme", TRAIT_NONEMPTYSTRING),
+        ],
+        Some(a_trait("service")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_IDREF,
+        &[
+            member("selector", SHAPE_STRING),
+            member("failWhenMissing", SHAPE_BOOLEAN),
+            member("errorMessage", SHAPE_STRING),
+        ],
+        Some(a_trait(":test(string, member > string)")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_PROTOCOLDEFINITION,
+        &[
+            member("traits", TRAIT_TRAITSHAPEIDLIST),
+            member("noInlineDocumentSupport", SHAPE_BOOLEAN),
+        ],
+        Some(a_trait("[trait|trait]")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_AUTHDEFINITION,
+        &[member("traits", TRAIT_TRAITSHAPEIDLIST)],
+        Some(a_trait("[trait|trait]")),
+    );
+    structure(
+        &mut builder,
+        TRAIT_MIXIN,
+        &[member("localTraits", TRAIT_TRAITSHAPEIDLIST)],
+        Some(a_trait(":not(member)")),
+    );
+
+    builder.try_into().unwrap()
+}
+
+///
+/// The `@trait` trait for a prelude trait definition with the given selector.
+///
+fn a_trait(selector: &str) -> TraitBuilder {
+    a_trait_with(selector, None, &[])
+}
+
+///
+/// The `@trait` trait for a prelude trait definition with the given selector, structural
+/// exclusivity, and conflicting traits.
+///
+fn a_trait_with(
+    selector: &str,
+    structurally_exclusive: Option<&str>,
+    conflicts: &[&str],
+) -> TraitBuilder {
+    let mut value = ObjectBuilder::default();
+    let _ = value.string("selector", selector);
+    if let Some(structurally_exclusive) = structurally_exclusive {
+        let _ = value.string("structurallyExclusive", structurally_exclusive);
+    }
+    if !conflicts.is_empty() {
+        let mut names = ArrayBuilder::default();
+        for name in conflicts {
+            let _ = names.string(&format!("{}#{}", PRELUDE_NAMESPACE, name));
+        }
+        let _ = value.insert("conflicts", names.into());
+    }
+    TraitBuilder::with_value(TRAIT_TRAIT, value.into())
+}
+
+fn except<'a>(names: &[&'a str], name: &str) -> Vec<&'a str> {
+    names.iter().filter(|n| **n != name).cloned().collect()
+}
+
+fn string_enum(values: &[&str]) -> TraitBuilder {
+    let mut definitions = ArrayBuilder::default();
+    for value in values {
+        let _ = definitions.push(ObjectBuilder::default().string("value", value).into());
+    }
+    TraitBuilder::with_value(TRAIT_ENUM, definitions.into())
+}
+
+fn unit_type() -> TraitBuilder {
+    TraitBuilder::annotation(TRAIT_UNITTYPE)
+}
+
+fn annotation_trait(
+    builder: &mut ModelBuilder,
+    name: &str,
+    definition: TraitBuilder,
+    others: &[TraitBuilder],
+) {
+    let mut structure = StructureBuilder::new(name);
+    let _ = structure.apply_trait(definition);
+    for other in others {
+        let _ = structure.apply_trait(other.clone());
+    }
+    let _ = builder.structure(structure);
+}
+
+fn structure(
+    builder: &mut ModelBuilder,
+    name: &str,
+    members: &[MemberBuilder],
+    definition: Option<TraitBuilder>,
+) {
+    let mut structure = StructureBuilder::new(name);
+    for member in members {
+        let _ = structure.add_member(member.clone());
+    }
+    if let Some(definition) = definition {
+        let _ = structure.apply_trait(definition);
+    }
+    let _ = builder.structure(structure);
+}
+
+fn list(builder: &mut ModelBuilder, name: &str, member: &str, definition: Option<TraitBuilder>) {
+    let mut list = ListBuilder::new(name, member);
+    if let Some(definition) = definition {
+        let _ = list.apply_trait(definition);
+    }
+    let _ = builder.list(list);
+}
+
+fn member(name: &str, target: &str) -> MemberBuilder {
+    MemberBuilder::new(name, target)
+}
+
+fn required(name: &str, target: &str) -> MemberBuilder {
+    MemberBuilder::new(name, target).required().into()
+}
@@ -78,6 +78,12 @@ pub const SHAPE_TIMESTAMP: &str = "timestamp";
 /// String identifier for the shape `ShapeBody::Apply`.
 pub const SHAPE_APPLY: &str = "apply";
 
+/// String identifier for the shape `ShapeBody::Enum`.
+pub const SHAPE_ENUM: &str = "enum";
+
+/// String identifier for the shape `ShapeBody::IntEnum`.
+pub const SHAPE_INT_ENUM: &str = "intEnum";
+
 /// String identifier for the shape `ShapeBody::List`.
 pub const SHAPE_LIST: &str = "list";
 
@@ -127,6 +133,9 @@ pub const MEMBER_INPUT: &str = "input";
 /// The member named "key" on the shape Map.
 pub const MEMBER_KEY: &str = "key";
 
+/// The member named "localTraits" on the trait Mixin.
+pub const MEMBER_LOCAL_TRAITS: &str = "localTraits";
+
 /// The member named "list" on the shape Resource.
 pub const MEMBER_LIST: &str = "list";
 
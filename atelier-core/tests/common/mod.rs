@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use atelier_core::action::validate::run_validation_actions;
+use atelier_core::action::{IssueLevel, Validator};
+use atelier_core::model::{Model, ShapeID};
+use std::str::FromStr;
+
+///
+/// Parse `s` as a shape ID, panicking if it is not valid.
+///
+pub fn id(s: &str) -> ShapeID {
+    ShapeID::from_str(s).unwrap()
+}
+
+///
+/// Run `validator` over `model`, returning the level and message of each issue in the order
+/// they were reported.
+///
+pub fn validation_issues(
+    model: &Model,
+    validator: Box<dyn Validator>,
+) -> Vec<(IssueLevel, String)> {
+    run_validation_actions(&mut [validator], model, false)
+        .unwrap()
+        .iter()
+        .map(|issue| (issue.level().clone(), issue.message().clone()))
+        .collect()
+}
+
+///
+/// Run `validator` over `model`, returning the message of each issue in the order they were
+/// reported.
+///
+pub fn validation_messages(model: &Model, validator: Box<dyn Validator>) -> Vec<String> {
+    validation_issues(model, validator)
+        .into_iter()
+        .map(|(_, message)| message)
+        .collect()
+}
+
+///
+/// Return the string form of each of `items`, sorted, for comparison where the order in which
+/// they were produced is not significant.
+///
+pub fn sorted_strings<T: ToString>(items: &[T]) -> Vec<String> {
+    let mut strings: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+    strings.sort();
+    strings
+}
@@ -0,0 +1,216 @@
+use atelier_core::action::validate::{run_validation_actions, BackwardCompatibility};
+use atelier_core::action::IssueLevel;
+use atelier_core::builder::traits::{length, range_min, ErrorSource};
+use atelier_core::builder::values::ObjectBuilder;
+use atelier_core::builder::{
+    EnumBuilder, MemberBuilder, ModelBuilder, OperationBuilder, ServiceBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder, TraitBuilder,
+};
+use atelier_core::model::values::Number;
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+mod common;
+use common::validation_issues;
+
+fn http(method: &str, uri: &str) -> TraitBuilder {
+    TraitBuilder::with_value(
+        "http",
+        ObjectBuilder::default()
+            .string("method", method)
+            .string("uri", uri)
+            .integer("code", 200)
+            .into(),
+    )
+}
+
+fn old_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-01")
+                .operations(&["GetCity", "DeleteCity"])
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .apply_trait(http("GET", "/cities/{name}"))
+                .into(),
+        )
+        .operation(OperationBuilder::new("DeleteCity"))
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .member("name", "CityName")
+                .member("units", "Units")
+                .member("verbose", "Boolean")
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("CityName")
+                .apply_trait(length(Some(1), Some(100)))
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::integer("Population")
+                .apply_trait(range_min(10))
+                .into(),
+        )
+        .enum_shape(
+            EnumBuilder::new("Units")
+                .member("METRIC")
+                .member("IMPERIAL")
+                .into(),
+        )
+        .try_into()
+        .unwrap()
+}
+
+fn new_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-02")
+                .operations(&["GetCity"])
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .error("NoSuchCity")
+                .apply_trait(http("GET", "/city/{name}"))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .add_member(MemberBuilder::new("name", "CityName").required().into())
+                .member("units", "Units")
+                .member("verbose", "String")
+                .member("language", "String")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("NoSuchCity")
+                .error_source(ErrorSource::Client)
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("CityName")
+                .apply_trait(length(Some(1), Some(50)))
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::integer("Population")
+                .apply_trait(range_min(0))
+                .into(),
+        )
+        .enum_shape(EnumBuilder::new("Units").member("METRIC").into())
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_compatible_model() {
+    let model = old_model();
+    assert!(
+        validation_issues(&model, Box::new(BackwardCompatibility::new(old_model()))).is_empty()
+    );
+}
+
+#[test]
+fn test_breaking_changes() {
+    let actual = validation_issues(
+        &new_model(),
+        Box::new(BackwardCompatibility::new(old_model())),
+    );
+    assert_eq!(
+        actual,
+        vec![
+            (
+                IssueLevel::Error,
+                "The constraint trait @smithy.api#length on smithy.example#CityName was narrowed."
+                    .to_string()
+            ),
+            (
+                IssueLevel::Error,
+                "The operation smithy.example#DeleteCity was removed.".to_string()
+            ),
+            (
+                IssueLevel::Error,
+                "The HTTP binding of operation smithy.example#GetCity was changed.".to_string()
+            ),
+            (
+                IssueLevel::Warning,
+                "The error smithy.example#NoSuchCity was added to smithy.example#GetCity, clients may not expect it."
+                    .to_string()
+            ),
+            (
+                IssueLevel::Error,
+                "The member smithy.example#GetCityInput$name is now required.".to_string()
+            ),
+            (
+                IssueLevel::Error,
+                "The target of member smithy.example#GetCityInput$verbose was changed from smithy.api#Boolean to smithy.api#String."
+                    .to_string()
+            ),
+            (
+                IssueLevel::Error,
+                "The enum value smithy.example#Units$IMPERIAL was removed.".to_string()
+            ),
+            (
+                IssueLevel::Error,
+                "The operation smithy.example#DeleteCity was removed from smithy.example#Weather."
+                    .to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_fail_fast_on_breaking_changes() {
+    let issues = run_validation_actions(
+        &mut [Box::new(BackwardCompatibility::new(old_model()))],
+        &new_model(),
+        true,
+    )
+    .unwrap();
+    assert!(issues
+        .iter()
+        .any(|issue| issue.level() == &IssueLevel::Error));
+}
+
+#[test]
+fn test_big_range_narrowing() {
+    let model = |max: &str| -> Model {
+        ModelBuilder::new(Version::V10, "smithy.example")
+            .simple_shape(
+                SimpleShapeBuilder::big_integer("Count")
+                    .apply_trait(TraitBuilder::with_value(
+                        "range",
+                        ObjectBuilder::default()
+                            .number("max", Number::from_str(max).unwrap())
+                            .into(),
+                    ))
+                    .into(),
+            )
+            .try_into()
+            .unwrap()
+    };
+    assert!(validation_issues(
+        &model("9223372036854775808"),
+        Box::new(BackwardCompatibility::new(model("9223372036854775808"))),
+    )
+    .is_empty());
+    assert_eq!(
+        validation_issues(
+            &model("9223372036854775807"),
+            Box::new(BackwardCompatibility::new(model("9223372036854775808"))),
+        ),
+        vec![(
+            IssueLevel::Error,
+            "The constraint trait @smithy.api#range on smithy.example#Count was narrowed."
+                .to_string()
+        )]
+    );
+}
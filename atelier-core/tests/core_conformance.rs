@@ -0,0 +1,304 @@
+use atelier_core::builder::traits::{length, pattern, range_min, unique_items};
+use atelier_core::builder::values::{ArrayBuilder, ObjectBuilder};
+use atelier_core::builder::{
+    EnumBuilder, ListBuilder, MapBuilder, MemberBuilder, ModelBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder, TraitBuilder,
+};
+use atelier_core::model::conformance::{check_value, value_conforms};
+use atelier_core::model::values::{Number, Value};
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+mod common;
+use common::sorted_strings;
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .structure(
+            StructureBuilder::new("City")
+                .add_member(MemberBuilder::new("name", "CityName").required().into())
+                .add_member(
+                    MemberBuilder::new("population", "Integer")
+                        .apply_trait(range_min(0))
+                        .into(),
+                )
+                .member("location", "Location")
+                .member("units", "Units")
+                .member("tags", "TagList")
+                .member("neighborhoods", "Neighborhoods")
+                .member("forecast", "Forecast")
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("CityName")
+                .apply_trait(length(Some(1), Some(10)))
+                .apply_trait(pattern("^[A-Z][a-z]+$"))
+                .into(),
+        )
+        .union(
+            StructureBuilder::new("Location")
+                .member("address", "String")
+                .member("coordinates", "Coordinates")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("Coordinates")
+                .add_member(MemberBuilder::new("latitude", "Float").required().into())
+                .add_member(MemberBuilder::new("longitude", "Float").required().into())
+                .into(),
+        )
+        .enum_shape(
+            EnumBuilder::new("Units")
+                .member("METRIC")
+                .member("IMPERIAL")
+                .into(),
+        )
+        .list(
+            ListBuilder::new("TagList", "Tag")
+                .apply_trait(unique_items())
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("Tag")
+                .apply_trait(TraitBuilder::with_value(
+                    "enum",
+                    ArrayBuilder::default()
+                        .push(ObjectBuilder::default().string("value", "coastal").into())
+                        .push(ObjectBuilder::default().string("value", "capital").into())
+                        .into(),
+                ))
+                .into(),
+        )
+        .map(
+            MapBuilder::new("Neighborhoods", "String", "Integer")
+                .apply_trait(length(None, Some(2)))
+                .into(),
+        )
+        .set(ListBuilder::new("Forecast", "Boolean"))
+        .try_into()
+        .unwrap()
+}
+
+fn city() -> ShapeID {
+    ShapeID::from_str("smithy.example#City").unwrap()
+}
+
+#[test]
+fn test_conforming_value() {
+    let model = make_model();
+    let value: Value = ObjectBuilder::default()
+        .string("name", "Seattle")
+        .integer("population", 750000)
+        .insert(
+            "location",
+            ObjectBuilder::default()
+                .insert(
+                    "coordinates",
+                    ObjectBuilder::default()
+                        .float("latitude", 47.6)
+                        .float("longitude", -122.3)
+                        .into(),
+                )
+                .into(),
+        )
+        .string("units", "METRIC")
+        .insert(
+            "tags",
+            ArrayBuilder::default()
+                .string("coastal")
+                .string("capital")
+                .into(),
+        )
+        .insert(
+            "neighborhoods",
+            ObjectBuilder::default().integer("Ballard", 5).into(),
+        )
+        .insert(
+            "forecast",
+            ArrayBuilder::default().boolean(true).boolean(false).into(),
+        )
+        .into();
+    assert!(check_value(&model, &value, &city()).is_empty());
+    assert!(value_conforms(&model, &value, &city()));
+}
+
+#[test]
+fn test_type_violations() {
+    let model = make_model();
+    let value: Value = ObjectBuilder::default()
+        .integer("name", 1)
+        .string("population", "many")
+        .insert(
+            "location",
+            ObjectBuilder::default()
+                .string("address", "1 Main St")
+                .insert(
+                    "coordinates",
+                    ObjectBuilder::default().string("latitude", "north").into(),
+                )
+                .into(),
+        )
+        .string("units", "KELVIN")
+        .string("mayor", "Someone")
+        .insert(
+            "forecast",
+            ArrayBuilder::default().boolean(true).boolean(true).into(),
+        )
+        .into();
+    assert_eq!(
+        sorted_strings(&check_value(&model, &value, &city())),
+        vec![
+            "forecast: a set value must not contain duplicate items",
+            "location.coordinates.latitude: expected a number, found a string",
+            "location.coordinates: the required member longitude is missing",
+            "location: a union value must have exactly one member",
+            "mayor: smithy.example#City has no member named mayor",
+            "name: expected a string, found an integer",
+            "population: expected an integer, found a string",
+            "units: \"KELVIN\" is not a value of the enum smithy.example#Units",
+        ]
+    );
+}
+
+#[test]
+fn test_constraint_violations() {
+    let model = make_model();
+    let value: Value = ObjectBuilder::default()
+        .string("name", "seattle is lovely")
+        .integer("population", -1)
+        .insert(
+            "tags",
+            ArrayBuilder::default()
+                .string("coastal")
+                .string("rainy")
+                .string("coastal")
+                .into(),
+        )
+        .insert(
+            "neighborhoods",
+            ObjectBuilder::default()
+                .integer("Ballard", 1)
+                .integer("Fremont", 2)
+                .integer("Queen Anne", 3)
+                .into(),
+        )
+        .into();
+    assert_eq!(
+        sorted_strings(&check_value(&model, &value, &city())),
+        vec![
+            "name: \"seattle is lovely\" does not match the pattern \"^[A-Z][a-z]+$\"",
+            "name: the length 17 is greater than the maximum 10",
+            "neighborhoods: the length 3 is greater than the maximum 2",
+            "population: the value -1 is less than the minimum 0",
+            "tags: the array must not contain duplicate items",
+            "tags[1]: \"rainy\" is not one of the values of the enum trait",
+        ]
+    );
+}
+
+#[test]
+fn test_top_level_values() {
+    let model = make_model();
+    let city_name = ShapeID::from_str("smithy.example#CityName").unwrap();
+    assert_eq!(
+        sorted_strings(&check_value(
+            &model,
+            &Value::String(String::new()),
+            &city_name
+        )),
+        vec![
+            "\"\" does not match the pattern \"^[A-Z][a-z]+$\"",
+            "the length 0 is less than the minimum 1",
+        ]
+    );
+    assert!(value_conforms(
+        &model,
+        &Value::from("anything"),
+        &ShapeID::from_str("smithy.api#String").unwrap()
+    ));
+    assert_eq!(
+        sorted_strings(&check_value(
+            &model,
+            &Value::from(true),
+            &ShapeID::from_str("smithy.example#Missing").unwrap()
+        )),
+        vec!["the shape smithy.example#Missing is not defined in the model"]
+    );
+}
+
+#[test]
+fn test_integer_ranges() {
+    let model = make_model();
+    let prelude = |name: &str| ShapeID::from_str(&format!("smithy.api#{}", name)).unwrap();
+    let number = |s: &str| Value::Number(Number::from_str(s).unwrap());
+    for (name, min, max) in [
+        ("Byte", "-128", "127"),
+        ("Short", "-32768", "32767"),
+        ("Integer", "-2147483648", "2147483647"),
+        ("Long", "-9223372036854775808", "9223372036854775807"),
+    ] {
+        assert!(value_conforms(&model, &number(min), &prelude(name)));
+        assert!(value_conforms(&model, &number(max), &prelude(name)));
+    }
+    for (name, value, kind) in [
+        ("Byte", "300", "byte"),
+        ("Byte", "-129", "byte"),
+        ("Short", "32768", "short"),
+        ("Integer", "1099511627776", "integer"),
+        ("Long", "9223372036854775808", "long"),
+    ] {
+        assert_eq!(
+            sorted_strings(&check_value(&model, &number(value), &prelude(name))),
+            vec![format!(
+                "the value {} is outside the range of a {}",
+                value, kind
+            )]
+        );
+    }
+    assert!(value_conforms(
+        &model,
+        &number("9223372036854775808"),
+        &prelude("BigInteger")
+    ));
+}
+
+#[test]
+fn test_big_number_ranges() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .simple_shape(
+            SimpleShapeBuilder::big_integer("Count")
+                .apply_trait(TraitBuilder::with_value(
+                    "range",
+                    ObjectBuilder::default()
+                        .number("min", Number::from_str("-9223372036854775809").unwrap())
+                        .number("max", Number::from_str("9007199254740992").unwrap())
+                        .into(),
+                ))
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    let count = ShapeID::from_str("smithy.example#Count").unwrap();
+    let number = |s: &str| Value::Number(Number::from_str(s).unwrap());
+    assert!(value_conforms(&model, &number("9007199254740992"), &count));
+    assert!(value_conforms(
+        &model,
+        &number("-9223372036854775809"),
+        &count
+    ));
+    assert_eq!(
+        sorted_strings(&check_value(&model, &number("9007199254740993"), &count)),
+        vec!["the value 9007199254740993 is greater than the maximum 9007199254740992"]
+    );
+    assert_eq!(
+        sorted_strings(&check_value(
+            &model,
+            &number("-9223372036854775810"),
+            &count
+        )),
+        vec!["the value -9223372036854775810 is less than the minimum -9223372036854775809"]
+    );
+}
@@ -0,0 +1,89 @@
+use atelier_core::builder::{EnumBuilder, MemberBuilder, ModelBuilder};
+use atelier_core::model::shapes::{EnumValue, Enumeration, MemberShape, ShapeKind};
+use atelier_core::model::{Identifier, Model, ShapeID};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V20, "smithy.example")
+        .enum_shape(
+            EnumBuilder::new("Suit")
+                .member("CLUB")
+                .add_member(MemberBuilder::unit("SPADE"))
+                .into(),
+        )
+        .int_enum(
+            EnumBuilder::new("FaceCard")
+                .int_member("JACK", 11)
+                .add_member(MemberBuilder::unit("QUEEN"))
+                .into(),
+        )
+        .try_into()
+        .unwrap()
+}
+
+fn id(s: &str) -> Identifier {
+    Identifier::from_str(s).unwrap()
+}
+
+#[test]
+fn test_enum_member_values() {
+    let model = make_model();
+    let shape = model
+        .shape(&ShapeID::from_str("smithy.example#Suit").unwrap())
+        .unwrap();
+    if let ShapeKind::Enum(body) = shape.body() {
+        assert_eq!(
+            body.member_value(&id("CLUB"), false),
+            Some(EnumValue::String("CLUB".to_string()))
+        );
+        assert_eq!(
+            body.member_value(&id("SPADE"), false),
+            Some(EnumValue::String("SPADE".to_string()))
+        );
+    } else {
+        panic!("expected an enum shape");
+    }
+}
+
+#[test]
+fn test_int_enum_member_values() {
+    let model = make_model();
+    let shape = model
+        .shape(&ShapeID::from_str("smithy.example#FaceCard").unwrap())
+        .unwrap();
+    if let ShapeKind::IntEnum(body) = shape.body() {
+        assert_eq!(
+            body.member_value(&id("JACK"), true),
+            Some(EnumValue::Integer(11))
+        );
+        assert_eq!(body.member_value(&id("QUEEN"), true), None);
+        let values: Vec<(&Identifier, EnumValue)> = body.values(true).collect();
+        assert_eq!(values, vec![(&id("JACK"), EnumValue::Integer(11))]);
+    } else {
+        panic!("expected an intEnum shape");
+    }
+
+    let body =
+        Enumeration::with_members(&[MemberShape::new(id("ACE"), Enumeration::member_target())]);
+    assert_eq!(body.member_value(&id("ACE"), true), None);
+    assert_eq!(
+        body.member_value(&id("ACE"), false),
+        Some(EnumValue::String("ACE".to_string()))
+    );
+}
+
+#[test]
+fn test_int_enum_rejects_string_values() {
+    let result: Result<Model, _> = ModelBuilder::new(Version::V20, "smithy.example")
+        .int_enum(
+            EnumBuilder::new("FaceCard")
+                .int_member("JACK", 11)
+                .member("QUEEN")
+                .into(),
+        )
+        .try_into();
+    assert!(result.is_err());
+}
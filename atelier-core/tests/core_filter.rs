@@ -0,0 +1,139 @@
+use atelier_core::action::transform::FilterShapes;
+use atelier_core::action::{Action, Transformer};
+use atelier_core::builder::traits::{documentation, internal, private, tagged};
+use atelier_core::builder::{
+    ListBuilder, MemberBuilder, ModelBuilder, OperationBuilder, ServiceBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder, TraitBuilder,
+};
+use atelier_core::io::lines::make_line_oriented_form;
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+
+mod common;
+use common::id;
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-01")
+                .operation("GetCity")
+                .operation("Debug")
+                .apply_trait(tagged(&["public"]))
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .error("Secret")
+                .apply_trait(tagged(&["public"]))
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("Debug")
+                .apply_trait(internal())
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .member("name", "String")
+                .add_member(
+                    MemberBuilder::new("trace", "String")
+                        .apply_trait(tagged(&["beta"]))
+                        .into(),
+                )
+                .member("secret", "Secret")
+                .member("secrets", "Secrets")
+                .apply_trait(tagged(&["public"]))
+                .apply_trait(documentation("The input."))
+                .apply_trait(TraitBuilder::annotation("audited"))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("Secret")
+                .apply_trait(private())
+                .into(),
+        )
+        .list(ListBuilder::new("Secrets", "Secret"))
+        .structure(
+            StructureBuilder::new("audited")
+                .apply_trait(TraitBuilder::annotation("trait"))
+                .into(),
+        )
+        .simple_shape(SimpleShapeBuilder::string("Unrelated"))
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_filter_external() {
+    let model = make_model();
+    let mut transformer = FilterShapes::external();
+    transformer.add_exclude_tag("beta".to_string());
+    transformer.add_strip_trait(id("smithy.example#audited"));
+    transformer.add_strip_trait(id("smithy.api#documentation"));
+    let filtered = transformer.transform(&model).unwrap();
+
+    assert_eq!(
+        make_line_oriented_form(&filtered),
+        vec![
+            "operation::smithy.example#GetCity",
+            "operation::smithy.example#GetCity::input=>smithy.example#GetCityInput",
+            "operation::smithy.example#GetCity::trait::smithy.api#tags<=[0]=\"public\"",
+            "service::smithy.example#Weather",
+            "service::smithy.example#Weather::operation=>smithy.example#GetCity",
+            "service::smithy.example#Weather::trait::smithy.api#tags<=[0]=\"public\"",
+            "service::smithy.example#Weather::version<=\"2006-03-01\"",
+            "string::smithy.example#Unrelated",
+            "structure::smithy.example#GetCityInput",
+            "structure::smithy.example#GetCityInput::name=>smithy.api#String",
+            "structure::smithy.example#GetCityInput::trait::smithy.api#tags<=[0]=\"public\"",
+        ]
+    );
+
+    let messages: Vec<String> = transformer
+        .issues()
+        .iter()
+        .map(|issue| issue.message().clone())
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "The shape smithy.example#Debug was removed by the filter.",
+            "The shape smithy.example#Secret was removed by the filter.",
+            "The shape smithy.example#Secrets was removed by the filter.",
+            "The shape smithy.example#audited was removed by the filter.",
+            "The member smithy.example#GetCityInput$secret was removed by the filter.",
+            "The member smithy.example#GetCityInput$secrets was removed by the filter.",
+            "The member smithy.example#GetCityInput$trace was removed by the filter.",
+        ]
+    );
+}
+
+#[test]
+fn test_filter_include_tags() {
+    let model = make_model();
+    let mut transformer = FilterShapes::default();
+    transformer.add_include_tag("public".to_string());
+    let filtered = transformer.transform(&model).unwrap();
+
+    let mut names: Vec<String> = filtered
+        .shape_names()
+        .map(|shape_id| shape_id.to_string())
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        vec![
+            "smithy.example#GetCity",
+            "smithy.example#GetCityInput",
+            "smithy.example#Weather",
+            "smithy.example#audited",
+        ]
+    );
+    let input = filtered.shape(&id("smithy.example#GetCityInput")).unwrap();
+    assert!(input.has_member(&"name".parse().unwrap()));
+    assert!(input.has_member(&"trace".parse().unwrap()));
+    assert!(!input.has_member(&"secret".parse().unwrap()));
+}
@@ -0,0 +1,200 @@
+use atelier_core::action::validate::{CorrectHttpBindings, NoHttpBindingConflicts};
+use atelier_core::builder::traits::required;
+use atelier_core::builder::{
+    MemberBuilder, ModelBuilder, OperationBuilder, ResourceBuilder, ServiceBuilder, ShapeTraits,
+    StructureBuilder, TraitBuilder,
+};
+use atelier_core::model::traits::HttpTrait;
+use atelier_core::model::values::{Value, ValueMap};
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+
+mod common;
+use common::validation_messages;
+
+fn http(method: &str, uri: &str) -> TraitBuilder {
+    TraitBuilder::with_value("smithy.api#http", HttpTrait::new(method, uri).into())
+}
+
+fn http_operation(name: &str, method: &str, uri: &str) -> OperationBuilder {
+    OperationBuilder::new(name)
+        .apply_trait(http(method, uri))
+        .into()
+}
+
+fn label_member(name: &str, target: &str) -> MemberBuilder {
+    MemberBuilder::new(name, target)
+        .apply_trait(TraitBuilder::annotation("smithy.api#httpLabel"))
+        .apply_trait(required())
+        .into()
+}
+
+#[test]
+fn test_http_bindings() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .operation(
+            http_operation("GetForecast", "GET", "/cities/{cityId}/forecast/{day}")
+                .input("GetForecastInput")
+                .output("GetForecastOutput")
+                .into(),
+        )
+        .operation(http_operation("GetCities", "GET", "cities/{cityId}"))
+        .operation(
+            http_operation("GetFile", "GET", "/files/{path+}")
+                .input("GetFileInput")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetForecastInput")
+                .add_member(
+                    MemberBuilder::new("cityId", "smithy.api#String")
+                        .apply_trait(TraitBuilder::annotation("smithy.api#httpLabel"))
+                        .into(),
+                )
+                .add_member(label_member("region", "smithy.api#String"))
+                .add_member(
+                    MemberBuilder::new("units", "smithy.api#String")
+                        .apply_trait(TraitBuilder::with_value(
+                            "smithy.api#httpQuery",
+                            Value::from("units"),
+                        ))
+                        .into(),
+                )
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetForecastOutput")
+                .add_member(
+                    MemberBuilder::new("chart", "smithy.api#Blob")
+                        .apply_trait(TraitBuilder::annotation("smithy.api#httpPayload"))
+                        .into(),
+                )
+                .add_member(
+                    MemberBuilder::new("summary", "smithy.api#Blob")
+                        .apply_trait(TraitBuilder::annotation("smithy.api#httpPayload"))
+                        .into(),
+                )
+                .add_member(
+                    MemberBuilder::new("tag", "smithy.api#Blob")
+                        .apply_trait(TraitBuilder::with_value(
+                            "smithy.api#httpHeader",
+                            Value::from("X-Tag"),
+                        ))
+                        .into(),
+                )
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetFileInput")
+                .add_member(label_member("path", "smithy.api#Integer"))
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(CorrectHttpBindings::default())),
+        vec![
+            "The URI pattern cities/{cityId} of operation smithy.example#GetCities is not valid, it must start with '/'.".to_string(),
+            "The @httpLabel member smithy.example#GetFileInput$path of operation smithy.example#GetFile is bound to a greedy label and must target a string shape.".to_string(),
+            "The @httpLabel member smithy.example#GetForecastInput$cityId of operation smithy.example#GetForecast must have the @required trait applied.".to_string(),
+            "The label day in the URI pattern of operation smithy.example#GetForecast has no corresponding @httpLabel member in the input structure.".to_string(),
+            "The @httpLabel member smithy.example#GetForecastInput$region of operation smithy.example#GetForecast does not correspond to a label in the URI pattern.".to_string(),
+            "The output structure smithy.example#GetForecastOutput of operation smithy.example#GetForecast has more than one @httpPayload member: smithy.example#GetForecastOutput$chart, smithy.example#GetForecastOutput$summary.".to_string(),
+            "The @httpHeader member smithy.example#GetForecastOutput$tag of operation smithy.example#GetForecast must target a boolean, number, string, or timestamp shape, or a list or set of these.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_http_binding_conflicts() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-01")
+                .operation("GetCityByName")
+                .operation("GetCurrentCity")
+                .operation("ListCities")
+                .operation("SearchCities")
+                .operation("GetFile")
+                .operation("GetFileById")
+                .operation("GetThing")
+                .operation("ListThings")
+                .resource("City")
+                .into(),
+        )
+        .resource(ResourceBuilder::new("City").read("GetCity").into())
+        .operation(http_operation("GetCity", "GET", "/cities/{cityId}"))
+        .operation(http_operation("GetCityByName", "get", "/cities/{name}"))
+        .operation(http_operation("GetCurrentCity", "GET", "/cities/current"))
+        .operation(http_operation("ListCities", "GET", "/cities"))
+        .operation(http_operation("SearchCities", "GET", "/cities?search"))
+        .operation(http_operation("GetFile", "GET", "/files/{key+}"))
+        .operation(http_operation("GetFileById", "GET", "/files/{id}"))
+        .operation(http_operation("GetThing", "GET", "/things/{id}"))
+        .operation(http_operation("ListThings", "GET", "/things/list"))
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(NoHttpBindingConflicts::default())),
+        vec![
+            "The operations smithy.example#GetCity (GET /cities/{cityId}) and smithy.example#GetCityByName (GET /cities/{name}) have conflicting HTTP bindings in the service smithy.example#Weather.".to_string(),
+            "The operations smithy.example#GetCity (GET /cities/{cityId}) and smithy.example#GetCurrentCity (GET /cities/current) have conflicting HTTP bindings in the service smithy.example#Weather.".to_string(),
+            "The operations smithy.example#GetCityByName (GET /cities/{name}) and smithy.example#GetCurrentCity (GET /cities/current) have conflicting HTTP bindings in the service smithy.example#Weather.".to_string(),
+            "The operations smithy.example#GetFile (GET /files/{key+}) and smithy.example#GetFileById (GET /files/{id}) have conflicting HTTP bindings in the service smithy.example#Weather.".to_string(),
+            "The operations smithy.example#GetThing (GET /things/{id}) and smithy.example#ListThings (GET /things/list) have conflicting HTTP bindings in the service smithy.example#Weather.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_http_binding_values() {
+    let mut invalid_http = ValueMap::default();
+    let _ = invalid_http.insert("method".to_string(), Value::from("GET"));
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .operation(
+            OperationBuilder::new("GetCity")
+                .apply_trait(TraitBuilder::with_value(
+                    "smithy.api#http",
+                    Value::Object(invalid_http),
+                ))
+                .into(),
+        )
+        .operation(
+            http_operation("GetForecast", "GET", "/forecast/{day}")
+                .input("GetForecastInput")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetForecastInput")
+                .add_member(
+                    MemberBuilder::new("day", "String")
+                        .apply_trait(TraitBuilder::with_value(
+                            "smithy.api#httpLabel",
+                            Value::from("day"),
+                        ))
+                        .apply_trait(required())
+                        .into(),
+                )
+                .add_member(
+                    MemberBuilder::new("body", "String")
+                        .apply_trait(TraitBuilder::with_value(
+                            "smithy.api#httpPayload",
+                            Value::from(true),
+                        ))
+                        .into(),
+                )
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(CorrectHttpBindings::default())),
+        vec![
+            "The value of the trait @http applied to smithy.example#GetCity is not valid.".to_string(),
+            "The value of the trait @httpLabel applied to smithy.example#GetForecastInput$day is not valid.".to_string(),
+            "The label day in the URI pattern of operation smithy.example#GetForecast has no corresponding @httpLabel member in the input structure.".to_string(),
+            "The value of the trait @httpPayload applied to smithy.example#GetForecastInput$body is not valid.".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,171 @@
+use atelier_core::action::validate::{CorrectOperationShapes, CorrectResourceBindings};
+use atelier_core::builder::traits::{error_source, idempotent, readonly, required, ErrorSource};
+use atelier_core::builder::{
+    MemberBuilder, ModelBuilder, OperationBuilder, ResourceBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder, TraitBuilder,
+};
+use atelier_core::model::values::Value;
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+
+mod common;
+use common::validation_messages;
+
+#[test]
+fn test_operation_shapes() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .output("CityName")
+                .error("NoSuchResource")
+                .error("NotAnError")
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("Ping")
+                .input("smithy.api#Unit")
+                .output("smithy.api#Unit")
+                .into(),
+        )
+        .structure(StructureBuilder::new("GetCityInput"))
+        .structure(
+            StructureBuilder::new("NoSuchResource")
+                .apply_trait(error_source(ErrorSource::Client))
+                .into(),
+        )
+        .structure(StructureBuilder::new("NotAnError"))
+        .simple_shape(SimpleShapeBuilder::string("CityName"))
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(CorrectOperationShapes::default())),
+        vec![
+            "The output smithy.example#CityName of operation smithy.example#GetCity must be a structure.".to_string(),
+            "The error smithy.example#NotAnError of operation smithy.example#GetCity must be a structure with the @error trait applied.".to_string(),
+        ]
+    );
+}
+
+fn make_resource_model() -> Model {
+    ModelBuilder::new(Version::V20, "smithy.example")
+        .resource(
+            ResourceBuilder::new("City")
+                .identifier("cityId", "CityId")
+                .put("PutCity")
+                .read("GetCity")
+                .delete("DeleteCity")
+                .list("ListCities")
+                .operation("RenameCity")
+                .resource("Forecast")
+                .into(),
+        )
+        .resource(ResourceBuilder::new("Country").resource("Forecast").into())
+        .resource(ResourceBuilder::new("Forecast"))
+        .operation(
+            OperationBuilder::new("PutCity")
+                .input("PutCityInput")
+                .apply_trait(idempotent())
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .apply_trait(readonly())
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("DeleteCity")
+                .input("DeleteCityInput")
+                .into(),
+        )
+        .operation(OperationBuilder::new("ListCities"))
+        .operation(OperationBuilder::new("RenameCity"))
+        .structure(
+            StructureBuilder::new("PutCityInput")
+                .add_member(
+                    MemberBuilder::new("cityId", "CityId")
+                        .apply_trait(required())
+                        .into(),
+                )
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .add_member(
+                    MemberBuilder::new("id", "CityId")
+                        .apply_trait(required())
+                        .apply_trait(TraitBuilder::with_value(
+                            "smithy.api#resourceIdentifier",
+                            Value::from("cityId"),
+                        ))
+                        .into(),
+                )
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("DeleteCityInput")
+                .member("cityId", "String")
+                .into(),
+        )
+        .simple_shape(SimpleShapeBuilder::string("CityId"))
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_resource_bindings() {
+    let model = make_resource_model();
+    assert_eq!(
+        validation_messages(&model, Box::new(CorrectResourceBindings::default())),
+        vec![
+            "The delete operation smithy.example#DeleteCity of resource smithy.example#City must have the @idempotent trait applied.".to_string(),
+            "The list operation smithy.example#ListCities of resource smithy.example#City must have the @readonly trait applied.".to_string(),
+            "The input member cityId of instance operation smithy.example#DeleteCity must target smithy.example#CityId, the target of identifier cityId of resource smithy.example#City.".to_string(),
+            "The input member cityId of instance operation smithy.example#DeleteCity binds the identifier cityId of resource smithy.example#City and must have the @required trait applied.".to_string(),
+            "The instance operation smithy.example#RenameCity of resource smithy.example#City must have an input structure to bind the resource identifiers.".to_string(),
+            "The resource smithy.example#Forecast is bound to more than one parent resource: smithy.example#City, smithy.example#Country.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_resource_identifier_value() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .resource(
+            ResourceBuilder::new("City")
+                .identifier("cityId", "CityId")
+                .read("GetCity")
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .apply_trait(readonly())
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .add_member(
+                    MemberBuilder::new("cityId", "CityId")
+                        .apply_trait(required())
+                        .apply_trait(TraitBuilder::with_value(
+                            "smithy.api#resourceIdentifier",
+                            Value::from(1),
+                        ))
+                        .into(),
+                )
+                .into(),
+        )
+        .simple_shape(SimpleShapeBuilder::string("CityId"))
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(CorrectResourceBindings::default())),
+        vec![
+            "The value of the trait @resourceIdentifier applied to smithy.example#GetCityInput$cityId is not valid.".to_string(),
+        ]
+    );
+}
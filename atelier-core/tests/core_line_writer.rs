@@ -82,3 +82,27 @@ fn service_with_renames() {
     println!("{:#?}", lines);
     assert_eq!(lines, test_case.expected_lines);
 }
+
+#[test]
+fn enum_shape() {
+    let test_case = parts::enum_shape();
+    let lines = make_line_oriented_form(&test_case.model);
+    println!("{:#?}", lines);
+    assert_eq!(lines, test_case.expected_lines);
+}
+
+#[test]
+fn int_enum_shape() {
+    let test_case = parts::int_enum_shape();
+    let lines = make_line_oriented_form(&test_case.model);
+    println!("{:#?}", lines);
+    assert_eq!(lines, test_case.expected_lines);
+}
+
+#[test]
+fn structure_with_mixins() {
+    let test_case = parts::structure_with_mixins();
+    let lines = make_line_oriented_form(&test_case.model);
+    println!("{:#?}", lines);
+    assert_eq!(lines, test_case.expected_lines);
+}
@@ -0,0 +1,128 @@
+use atelier_core::builder::traits::documentation;
+use atelier_core::builder::values::ArrayBuilder;
+use atelier_core::builder::{ModelBuilder, ShapeTraits, SimpleShapeBuilder, StructureBuilder};
+use atelier_core::error::ErrorKind;
+use atelier_core::model::merge::{MergeConflict, MergePolicies, MergePolicy, MergeResolution};
+use atelier_core::model::shapes::HasTraits;
+use atelier_core::model::values::Value;
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::sync::Arc;
+
+mod common;
+use common::id;
+
+fn make_model(owner: &str, doc: &str, member: &str) -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .meta_data("owner".to_string(), Value::String(owner.to_string()))
+        .meta_data(
+            "authors".to_string(),
+            ArrayBuilder::default().string(owner).into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("Name")
+                .apply_trait(documentation(doc))
+                .into(),
+        )
+        .structure(StructureBuilder::new("Thing").member(member, "Name").into())
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_merge_fails_by_default() {
+    let mut model = make_model("first", "A name.", "name");
+    let result = model.merge(make_model("first", "A name.", "label"));
+    assert!(matches!(
+        result.err().map(|e| e.0),
+        Some(ErrorKind::MergeShapeConflict(_))
+    ));
+
+    let mut model = make_model("first", "A name.", "name");
+    let result = model.merge(make_model("first", "Another name.", "name"));
+    assert!(matches!(
+        result.err().map(|e| e.0),
+        Some(ErrorKind::MergeTraitConflict(_))
+    ));
+
+    let mut model = make_model("first", "A name.", "name");
+    let report = model
+        .merge_with(
+            make_model("first", "A name.", "name"),
+            &MergePolicies::default(),
+        )
+        .unwrap();
+    assert!(report.is_empty());
+}
+
+#[test]
+fn test_merge_keep_last() {
+    let mut model = make_model("first", "A name.", "name");
+    let report = model
+        .merge_with(
+            make_model("second", "Another name.", "label"),
+            &MergePolicies::keep_last(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        report.to_string(),
+        "metadata conflict: owner (kept last)\n\
+         trait conflict: smithy.api#documentation on smithy.example#Name (kept last)\n\
+         shape conflict: smithy.example#Thing (kept last)\n"
+    );
+    assert_eq!(
+        model.metadata_value("owner"),
+        Some(&Value::String("second".to_string()))
+    );
+    assert_eq!(
+        model
+            .metadata_value("authors")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+    assert!(model
+        .shape(&id("smithy.example#Thing"))
+        .unwrap()
+        .has_member(&"label".parse().unwrap()));
+}
+
+#[test]
+fn test_merge_with_resolver() {
+    let mut policies = MergePolicies::default();
+    policies.set_trait_policy(MergePolicy::Resolve(Arc::new(|_, first, last| {
+        match (first, last) {
+            (Some(Value::String(first)), Some(Value::String(last))) => {
+                Ok(Some(Value::String(format!("{} {}", first, last))))
+            }
+            _ => Err(ErrorKind::InvalidTraitValue("documentation".to_string()).into()),
+        }
+    })));
+
+    let mut model = make_model("first", "A name.", "name");
+    let report = model
+        .merge_with(make_model("first", "Another name.", "name"), &policies)
+        .unwrap();
+    assert_eq!(
+        report.conflicts().collect::<Vec<_>>(),
+        vec![&(
+            MergeConflict::Trait {
+                shape_id: id("smithy.example#Name"),
+                trait_id: id("smithy.api#documentation"),
+            },
+            MergeResolution::Resolved
+        )]
+    );
+    assert_eq!(
+        model
+            .shape(&id("smithy.example#Name"))
+            .unwrap()
+            .trait_named(&id("smithy.api#documentation")),
+        Some(&Some(Value::String("A name. Another name.".to_string())))
+    );
+}
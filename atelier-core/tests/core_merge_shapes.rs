@@ -1,7 +1,7 @@
 use atelier_core::model::shapes::{HasTraits, ShapeKind, StructureOrUnion, TopLevelShape};
 use atelier_core::model::shapes::{ListOrSet, Map, Simple};
 use atelier_core::model::values::Value;
-use atelier_core::model::{Identifier, Model, ShapeID};
+use atelier_core::model::{HasSourceLocation, Identifier, Model, ShapeID, SourceLocation};
 use atelier_core::prelude::{prelude_shape_named, TRAIT_DOCUMENTATION};
 use atelier_core::Version;
 
@@ -145,6 +145,35 @@ fn merge_same_simple_shape_with_traits_from_right() {
     assert_eq!(shape.traits().len(), 1);
 }
 
+#[test]
+fn merge_same_simple_shape_keeps_locations() {
+    let mut model = Model::new(Version::V10);
+    let trait_id = prelude_shape_named(TRAIT_DOCUMENTATION).unwrap();
+
+    let mut left = make_simple_shape("simpleString", Simple::String, None);
+    left.set_location(SourceLocation::in_file("left.smithy", 3, 1));
+    assert!(model.add_shape(left).is_ok());
+
+    let mut right = make_simple_shape("simpleString", Simple::String, Some("a simple string"));
+    right.set_location(SourceLocation::in_file("right.smithy", 5, 1));
+    right.set_trait_location(
+        trait_id.clone(),
+        SourceLocation::in_file("right.smithy", 4, 1),
+    );
+    assert!(model.add_shape(right).is_ok());
+
+    let shape_id = ShapeID::new_unchecked("example.smithy", "simpleString", None);
+    let shape = model.shape(&shape_id).unwrap();
+    assert_eq!(
+        shape.location(),
+        &Some(SourceLocation::in_file("left.smithy", 3, 1))
+    );
+    assert_eq!(
+        shape.trait_location(&trait_id),
+        Some(&SourceLocation::in_file("right.smithy", 4, 1))
+    );
+}
+
 #[test]
 fn merge_same_list_shape() {
     let mut model = Model::new(Version::V10);
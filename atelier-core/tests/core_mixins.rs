@@ -0,0 +1,202 @@
+use atelier_core::action::transform::FlattenMixins;
+use atelier_core::action::Transformer;
+use atelier_core::builder::traits::documentation;
+use atelier_core::builder::{MemberBuilder, ModelBuilder, ShapeTraits, StructureBuilder};
+use atelier_core::error::ErrorKind;
+use atelier_core::model::shapes::HasTraits;
+use atelier_core::model::values::Value;
+use atelier_core::model::{HasIdentity, Identifier, Model, ShapeID};
+use atelier_core::prelude::{
+    prelude_shape_named, TRAIT_DOCUMENTATION, TRAIT_MIXIN, TRAIT_SENSITIVE,
+};
+use atelier_core::syntax::MEMBER_LOCAL_TRAITS;
+use atelier_core::Version;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V20, "smithy.example")
+        .structure(
+            StructureBuilder::new("PaginatedInput")
+                .mixin()
+                .documentation("Pagination members.")
+                .string("nextToken")
+                .integer("pageSize")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("AuditedInput")
+                .mixin()
+                .sensitive()
+                .add_member(MemberBuilder::string("requestedBy").required().into())
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("ListThingsInput")
+                .with_mixin("PaginatedInput")
+                .with_mixin("AuditedInput")
+                .add_member(
+                    MemberBuilder::string("nextToken")
+                        .apply_trait(documentation("Where to start."))
+                        .into(),
+                )
+                .string("filter")
+                .into(),
+        )
+        .try_into()
+        .unwrap()
+}
+
+fn shape_id(shape_name: &str) -> ShapeID {
+    ShapeID::new_unchecked("smithy.example", shape_name, None)
+}
+
+fn member_names(model: &Model, shape_name: &str) -> Vec<String> {
+    let mut names: Vec<String> = model
+        .effective_members(&shape_id(shape_name))
+        .unwrap()
+        .iter()
+        .map(|member| member.id().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_mixin_references() {
+    let model = make_model();
+    let shape = model.shape(&shape_id("ListThingsInput")).unwrap();
+    assert!(shape.has_mixins());
+    assert_eq!(
+        shape.mixins().collect::<Vec<&ShapeID>>(),
+        vec![&shape_id("PaginatedInput"), &shape_id("AuditedInput")]
+    );
+    assert!(model.shape(&shape_id("PaginatedInput")).unwrap().is_mixin());
+}
+
+#[test]
+fn test_effective_members() {
+    let model = make_model();
+    assert_eq!(
+        member_names(&model, "ListThingsInput"),
+        vec!["filter", "nextToken", "pageSize", "requestedBy"]
+    );
+
+    let members = model
+        .effective_members(&shape_id("ListThingsInput"))
+        .unwrap();
+    let next_token = members
+        .iter()
+        .find(|member| member.id() == &Identifier::from_str("nextToken").unwrap())
+        .unwrap();
+    assert!(next_token.has_documentation());
+    let requested_by = members
+        .iter()
+        .find(|member| member.id() == &Identifier::from_str("requestedBy").unwrap())
+        .unwrap();
+    assert!(requested_by.is_required());
+}
+
+#[test]
+fn test_effective_traits() {
+    let model = make_model();
+    let traits = model
+        .effective_traits(&shape_id("ListThingsInput"))
+        .unwrap();
+    assert!(traits.contains_key(&prelude_shape_named(TRAIT_DOCUMENTATION).unwrap()));
+    assert!(!traits.contains_key(&prelude_shape_named(TRAIT_MIXIN).unwrap()));
+    assert_eq!(traits.len(), 2);
+}
+
+#[test]
+fn test_effective_of_unknown_shape() {
+    let model = make_model();
+    let result = model.effective_members(&shape_id("Unknown"));
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::UnknownShape(_)
+    ));
+}
+
+#[test]
+fn test_invalid_mixin() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .structure(StructureBuilder::new("NotAMixin").string("name").into())
+        .structure(
+            StructureBuilder::new("UsesNotAMixin")
+                .with_mixin("NotAMixin")
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    let result = model.effective_members(&shape_id("UsesNotAMixin"));
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::InvalidMixin(_, _)
+    ));
+}
+
+#[test]
+fn test_mixin_cycle() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .structure(
+            StructureBuilder::new("Left")
+                .mixin()
+                .with_mixin("Right")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("Right")
+                .mixin()
+                .with_mixin("Left")
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    let result = model.effective_traits(&shape_id("Left"));
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::MixinCycle(_)
+    ));
+}
+
+#[test]
+fn test_flatten_mixins() {
+    let model = make_model();
+    let flattened = FlattenMixins::default().transform(&model).unwrap();
+
+    assert!(flattened.shape(&shape_id("PaginatedInput")).is_none());
+    assert!(flattened.shape(&shape_id("AuditedInput")).is_none());
+
+    let shape = flattened.shape(&shape_id("ListThingsInput")).unwrap();
+    assert!(!shape.has_mixins());
+    assert!(shape.has_documentation());
+    assert!(shape.is_sensitive());
+    assert!(!shape.is_mixin());
+    assert_eq!(
+        member_names(&flattened, "ListThingsInput"),
+        vec!["filter", "nextToken", "pageSize", "requestedBy"]
+    );
+}
+
+#[test]
+fn test_mixin_local_traits() {
+    let mut model = make_model();
+    let mixin = model.shape_mut(&shape_id("AuditedInput")).unwrap();
+    let _ = mixin.traits_mut().insert(
+        prelude_shape_named(TRAIT_MIXIN).unwrap(),
+        Some(Value::Object(
+            vec![(
+                MEMBER_LOCAL_TRAITS.to_string(),
+                Value::Array(vec![Value::String("smithy.api#sensitive".to_string())]),
+            )]
+            .into_iter()
+            .collect(),
+        )),
+    );
+    let traits = model
+        .effective_traits(&shape_id("ListThingsInput"))
+        .unwrap();
+    assert!(!traits.contains_key(&prelude_shape_named(TRAIT_SENSITIVE).unwrap()));
+    assert_eq!(traits.len(), 1);
+}
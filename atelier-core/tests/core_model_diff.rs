@@ -0,0 +1,135 @@
+use atelier_core::builder::values::ObjectBuilder;
+use atelier_core::builder::{
+    MemberBuilder, ModelBuilder, OperationBuilder, ResourceBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder,
+};
+use atelier_core::model::diff::{Change, ModelDiff};
+use atelier_core::model::values::Value;
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+fn old_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .meta_data("owner".to_string(), Value::String("team-a".to_string()))
+        .resource(
+            ResourceBuilder::new("City")
+                .identifier("cityId", "CityId")
+                .read("GetCity")
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .add_member(MemberBuilder::new("cityId", "CityId").required().into())
+                .member("verbose", "Boolean")
+                .into(),
+        )
+        .simple_shape(SimpleShapeBuilder::string("CityId"))
+        .simple_shape(SimpleShapeBuilder::string("Legacy"))
+        .try_into()
+        .unwrap()
+}
+
+fn new_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .meta_data("owner".to_string(), Value::String("team-b".to_string()))
+        .meta_data(
+            "suppressions".to_string(),
+            ObjectBuilder::default().string("id", "X").into(),
+        )
+        .resource(
+            ResourceBuilder::new("City")
+                .identifier("cityId", "CityId")
+                .read("GetCity")
+                .list("ListCities")
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .error("NoSuchCity")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .add_member(MemberBuilder::new("cityId", "String"))
+                .member("language", "String")
+                .documentation("The input.")
+                .into(),
+        )
+        .structure(StructureBuilder::new("NoSuchCity"))
+        .simple_shape(SimpleShapeBuilder::string("CityId"))
+        .simple_shape(SimpleShapeBuilder::integer("Legacy"))
+        .try_into()
+        .unwrap()
+}
+
+fn shape_id(s: &str) -> ShapeID {
+    ShapeID::from_str(s).unwrap()
+}
+
+#[test]
+fn test_no_changes() {
+    let diff = ModelDiff::new(&old_model(), &old_model());
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn test_all_changes() {
+    let diff = ModelDiff::new(&old_model(), &new_model());
+    let actual: Vec<String> = diff.changes().map(|change| change.to_string()).collect();
+    assert_eq!(
+        actual,
+        vec![
+            "metadata changed: owner (\"team-a\" -> \"team-b\")",
+            "metadata added: suppressions = { id: \"X\" }",
+            "reference added: smithy.example#City -[list]-> smithy.example#ListCities",
+            "reference added: smithy.example#GetCity -[error]-> smithy.example#NoSuchCity",
+            "member retargeted: smithy.example#GetCityInput$cityId (smithy.example#CityId -> smithy.api#String)",
+            "trait removed: smithy.example#GetCityInput$cityId @smithy.api#required = {  }",
+            "member added: smithy.example#GetCityInput$language -> smithy.api#String",
+            "member removed: smithy.example#GetCityInput$verbose -> smithy.api#Boolean",
+            "trait added: smithy.example#GetCityInput @smithy.api#documentation = \"The input.\"",
+            "shape type changed: smithy.example#Legacy (string -> integer)",
+            "shape added: smithy.example#NoSuchCity",
+        ]
+    );
+}
+
+#[test]
+fn test_change_summaries() {
+    let diff = ModelDiff::new(&old_model(), &new_model());
+    assert_eq!(
+        diff.added_shapes().collect::<Vec<&ShapeID>>(),
+        vec![&shape_id("smithy.example#NoSuchCity")]
+    );
+    assert_eq!(diff.removed_shapes().count(), 0);
+    assert_eq!(
+        diff.modified_shapes(),
+        vec![
+            shape_id("smithy.example#City"),
+            shape_id("smithy.example#GetCity"),
+            shape_id("smithy.example#GetCityInput"),
+            shape_id("smithy.example#Legacy"),
+        ]
+    );
+
+    let reverse = ModelDiff::new(&new_model(), &old_model());
+    assert_eq!(
+        reverse.removed_shapes().collect::<Vec<&ShapeID>>(),
+        vec![&shape_id("smithy.example#NoSuchCity")]
+    );
+    assert!(reverse.changes().any(|change| change
+        == &Change::MemberAdded {
+            member: shape_id("smithy.example#GetCityInput$verbose"),
+            target: shape_id("smithy.api#Boolean"),
+        }));
+}
@@ -0,0 +1,162 @@
+use atelier_core::action::transform::{NamespaceRule, RelocateNamespaces};
+use atelier_core::action::{Action, Transformer};
+use atelier_core::builder::traits::{documentation, references};
+use atelier_core::builder::values::{ArrayBuilder, ObjectBuilder};
+use atelier_core::builder::{
+    ModelBuilder, OperationBuilder, ServiceBuilder, ShapeTraits, SimpleShapeBuilder,
+    StructureBuilder,
+};
+use atelier_core::error::ErrorKind;
+use atelier_core::io::lines::make_line_oriented_form;
+use atelier_core::model::{Model, NamespaceID};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+fn ns(s: &str) -> NamespaceID {
+    NamespaceID::from_str(s).unwrap()
+}
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V10, "com.acme.core")
+        .service(
+            ServiceBuilder::new("Store", "2020-01-01")
+                .operation("com.acme.legacy#GetItem")
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("com.acme.legacy#GetItem")
+                .input("com.acme.legacy#GetItemInput")
+                .error("com.acme.legacy.auth#AccessDenied")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("com.acme.legacy#GetItemInput")
+                .member("id", "com.acme.legacy#ItemId")
+                .member("token", "com.acme.legacy.auth#Token")
+                .into(),
+        )
+        .simple_shape(SimpleShapeBuilder::string("com.acme.legacy#ItemId"))
+        .simple_shape(SimpleShapeBuilder::string("com.acme.legacy.auth#Token"))
+        .structure(StructureBuilder::new("com.acme.legacy.auth#AccessDenied"))
+        .simple_shape(SimpleShapeBuilder::string("Token"))
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_relocate_by_prefix() {
+    let model = make_model();
+    let mut transformer = RelocateNamespaces::new(&[
+        NamespaceRule::exact(ns("com.acme.legacy.auth"), ns("com.acme.auth")),
+        NamespaceRule::prefix(ns("com.acme.legacy"), ns("com.acme.core")),
+    ]);
+    let relocated = transformer.transform(&model).unwrap();
+
+    assert_eq!(
+        make_line_oriented_form(&relocated),
+        vec![
+            "operation::com.acme.core#GetItem",
+            "operation::com.acme.core#GetItem::error=>com.acme.auth#AccessDenied",
+            "operation::com.acme.core#GetItem::input=>com.acme.core#GetItemInput",
+            "service::com.acme.core#Store",
+            "service::com.acme.core#Store::operation=>com.acme.core#GetItem",
+            "service::com.acme.core#Store::version<=\"2020-01-01\"",
+            "string::com.acme.auth#Token",
+            "string::com.acme.core#ItemId",
+            "string::com.acme.core#Token",
+            "structure::com.acme.auth#AccessDenied",
+            "structure::com.acme.core#GetItemInput",
+            "structure::com.acme.core#GetItemInput::id=>com.acme.core#ItemId",
+            "structure::com.acme.core#GetItemInput::token=>com.acme.auth#Token",
+        ]
+    );
+    assert_eq!(transformer.issues().len(), 5);
+    assert_eq!(
+        transformer.issues()[0].message(),
+        "The shape com.acme.legacy#GetItem was relocated to com.acme.core#GetItem."
+    );
+}
+
+#[test]
+fn test_relocate_nested_prefix() {
+    let rule = NamespaceRule::prefix(ns("com.acme.legacy"), ns("com.acme.core"));
+    assert_eq!(
+        rule.relocate(&ns("com.acme.legacy.auth")),
+        Some(ns("com.acme.core.auth"))
+    );
+    assert_eq!(rule.relocate(&ns("com.acme.legacyx")), None);
+    assert_eq!(
+        NamespaceRule::exact(ns("com.acme.legacy"), ns("com.acme.core"))
+            .relocate(&ns("com.acme.legacy.auth")),
+        None
+    );
+}
+
+#[test]
+fn test_relocate_collisions() {
+    let model = make_model();
+    let mut transformer = RelocateNamespaces::new(&[NamespaceRule::exact(
+        ns("com.acme.legacy.auth"),
+        ns("com.acme.core"),
+    )]);
+    let result = transformer.transform(&model);
+    match result.err().map(|e| e.0) {
+        Some(ErrorKind::ActionIssue(issues)) => {
+            let messages: Vec<&String> = issues.iter().map(|issue| issue.message()).collect();
+            assert_eq!(
+                messages,
+                vec!["The shape com.acme.legacy.auth#Token cannot be relocated to com.acme.core#Token, a shape with that identifier already exists."]
+            );
+        }
+        _ => panic!("expected a collision error"),
+    }
+
+    let mut transformer = RelocateNamespaces::new(&[
+        NamespaceRule::exact(ns("com.acme.core"), ns("com.acme.merged")),
+        NamespaceRule::exact(ns("com.acme.legacy.auth"), ns("com.acme.merged")),
+    ]);
+    assert!(transformer.transform(&model).is_err());
+    assert_eq!(
+        transformer.issues()[0].message(),
+        "The shapes com.acme.core#Token, com.acme.legacy.auth#Token would all be relocated to com.acme.merged#Token."
+    );
+}
+
+#[test]
+fn test_relocate_leaves_documentation() {
+    let model: Model = ModelBuilder::new(Version::V10, "com.acme.core")
+        .structure(
+            StructureBuilder::new("Item")
+                .apply_trait(documentation("com.acme.legacy#ItemId"))
+                .apply_trait(references(
+                    ArrayBuilder::default()
+                        .push(
+                            ObjectBuilder::default()
+                                .string("resource", "com.acme.legacy#ItemId")
+                                .into(),
+                        )
+                        .into(),
+                ))
+                .into(),
+        )
+        .simple_shape(SimpleShapeBuilder::string("com.acme.legacy#ItemId"))
+        .try_into()
+        .unwrap();
+    let mut transformer = RelocateNamespaces::new(&[NamespaceRule::exact(
+        ns("com.acme.legacy"),
+        ns("com.acme.core"),
+    )]);
+    let relocated = transformer.transform(&model).unwrap();
+
+    assert_eq!(
+        make_line_oriented_form(&relocated),
+        vec![
+            "string::com.acme.core#ItemId",
+            "structure::com.acme.core#Item",
+            "structure::com.acme.core#Item::trait::smithy.api#documentation<=\"com.acme.legacy#ItemId\"",
+            "structure::com.acme.core#Item::trait::smithy.api#references<=[0]={resource}=\"com.acme.core#ItemId\"",
+        ]
+    );
+}
@@ -0,0 +1,146 @@
+use atelier_core::builder::traits::{pattern, ErrorSource};
+use atelier_core::builder::{
+    MemberBuilder, ModelBuilder, OperationBuilder, ResourceBuilder, ServiceBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder,
+};
+use atelier_core::model::neighbor::{NeighborIndex, Relationship, RelationshipKind};
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-01")
+                .resource("City")
+                .into(),
+        )
+        .resource(
+            ResourceBuilder::new("City")
+                .identifier("cityId", "CityId")
+                .read("GetCity")
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .error("NoSuchResource")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .add_member(MemberBuilder::new("cityId", "CityId").required().into())
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("NoSuchResource")
+                .member("resourceType", "String")
+                .error_source(ErrorSource::Client)
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("CityId")
+                .apply_trait(pattern("^[A-Za-z0-9 ]+$"))
+                .into(),
+        )
+        .try_into()
+        .unwrap()
+}
+
+fn shape_id(s: &str) -> ShapeID {
+    ShapeID::from_str(s).unwrap()
+}
+
+#[test]
+fn test_outgoing_relationships() {
+    let model = make_model();
+    let index = NeighborIndex::from(&model);
+
+    let mut actual: Vec<String> = index
+        .relationships_from(&shape_id("smithy.example#City"))
+        .map(|rel| rel.to_string())
+        .collect();
+    actual.sort();
+    assert_eq!(
+        actual,
+        vec![
+            "smithy.example#City -[identifier]-> smithy.example#CityId".to_string(),
+            "smithy.example#City -[read]-> smithy.example#GetCity".to_string(),
+        ]
+    );
+
+    let mut actual: Vec<&ShapeID> = index
+        .neighbors(&shape_id("smithy.example#GetCity"))
+        .collect();
+    actual.sort_by_key(|id| id.to_string());
+    assert_eq!(
+        actual,
+        vec![
+            &shape_id("smithy.example#GetCityInput"),
+            &shape_id("smithy.example#NoSuchResource"),
+        ]
+    );
+}
+
+#[test]
+fn test_incoming_relationships() {
+    let model = make_model();
+    let index = NeighborIndex::from(&model);
+
+    let city_id = shape_id("smithy.example#CityId");
+    let mut actual: Vec<&Relationship> = index.relationships_to(&city_id).collect();
+    actual.sort_by_key(|rel| rel.to_string());
+    assert_eq!(
+        actual,
+        vec![
+            &Relationship::new(
+                shape_id("smithy.example#City"),
+                RelationshipKind::Identifier,
+                city_id.clone()
+            ),
+            &Relationship::new(
+                shape_id("smithy.example#GetCityInput$cityId"),
+                RelationshipKind::MemberTarget,
+                city_id.clone()
+            ),
+        ]
+    );
+
+    assert!(index.is_referenced(&shape_id("smithy.example#City")));
+    assert!(!index.is_referenced(&shape_id("smithy.example#Weather")));
+}
+
+#[test]
+fn test_member_and_trait_relationships() {
+    let model = make_model();
+    let index = NeighborIndex::from(&model);
+
+    let input = shape_id("smithy.example#GetCityInput");
+    let member = shape_id("smithy.example#GetCityInput$cityId");
+    assert_eq!(
+        index
+            .relationships_from_of_kind(&input, RelationshipKind::Member)
+            .map(|rel| rel.target())
+            .collect::<Vec<&ShapeID>>(),
+        vec![&member]
+    );
+
+    let required = shape_id("smithy.api#required");
+    assert_eq!(
+        index
+            .relationships_to_of_kind(&required, RelationshipKind::Trait)
+            .map(|rel| rel.source())
+            .collect::<Vec<&ShapeID>>(),
+        vec![&member]
+    );
+
+    let mut error_trait_users: Vec<&ShapeID> =
+        index.referenced_by(&shape_id("smithy.api#error")).collect();
+    error_trait_users.dedup();
+    assert_eq!(
+        error_trait_users,
+        vec![&shape_id("smithy.example#NoSuchResource")]
+    );
+}
@@ -0,0 +1,233 @@
+use atelier_core::action::validate::CorrectPaginatedTraits;
+use atelier_core::builder::traits::{paginated, required};
+use atelier_core::builder::{
+    ListBuilder, MemberBuilder, ModelBuilder, OperationBuilder, ServiceBuilder, ShapeTraits,
+    StructureBuilder, TraitBuilder,
+};
+use atelier_core::model::paginated::PaginatedIndex;
+use atelier_core::model::values::Value;
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+
+mod common;
+use common::{id, validation_messages};
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V20, "smithy.example")
+        .service(
+            ServiceBuilder::new("Example", "2019-06-27")
+                .operation("ListThings")
+                .operation("ListBadThings")
+                .apply_trait(paginated(
+                    Some("nextToken"),
+                    Some("nextToken"),
+                    None,
+                    Some("maxResults"),
+                ))
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("ListThings")
+                .input("ListThingsInput")
+                .output("ListThingsOutput")
+                .apply_trait(paginated(None, None, Some("result.things"), None))
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("ListBadThings")
+                .input("ListBadThingsInput")
+                .output("ListBadThingsOutput")
+                .apply_trait(paginated(Some("token"), None, Some("things"), None))
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("ListOrphans")
+                .apply_trait(paginated(None, None, Some("orphans"), None))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("ListThingsInput")
+                .member("nextToken", "smithy.api#String")
+                .member("maxResults", "smithy.api#Integer")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("ListThingsOutput")
+                .member("nextToken", "smithy.api#String")
+                .member("result", "ThingResult")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("ThingResult")
+                .member("things", "Things")
+                .into(),
+        )
+        .list(ListBuilder::new("Things", "smithy.api#String"))
+        .structure(
+            StructureBuilder::new("ListBadThingsInput")
+                .member("token", "smithy.api#Integer")
+                .member("maxResults", "smithy.api#String")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("ListBadThingsOutput")
+                .member("things", "smithy.api#String")
+                .into(),
+        )
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_paginated_index() {
+    let model = make_model();
+    let index = PaginatedIndex::from(&model);
+    assert_eq!(index.len(), 5);
+    assert!(index.is_paginated(&id("smithy.example#ListOrphans")));
+    assert!(!index.is_paginated(&id("smithy.example#ThingResult")));
+
+    let operation = index
+        .pagination_info(&id("smithy.example#ListThings"))
+        .unwrap();
+    assert_eq!(operation.paginated().input_token(), &None);
+    assert_eq!(operation.input_token_member(), &None);
+
+    let info = index
+        .service_pagination_info(
+            &id("smithy.example#Example"),
+            &id("smithy.example#ListThings"),
+        )
+        .unwrap();
+    assert_eq!(info.service(), &Some(id("smithy.example#Example")));
+    assert_eq!(
+        info.input_token_member(),
+        &Some(id("smithy.example#ListThingsInput$nextToken"))
+    );
+    assert_eq!(
+        info.page_size_member(),
+        &Some(id("smithy.example#ListThingsInput$maxResults"))
+    );
+    assert_eq!(
+        info.output_token_member(),
+        Some(&id("smithy.example#ListThingsOutput$nextToken"))
+    );
+    assert_eq!(
+        info.items_path(),
+        Some(
+            vec![
+                id("smithy.example#ListThingsOutput$result"),
+                id("smithy.example#ThingResult$things"),
+            ]
+            .as_slice()
+        )
+    );
+
+    assert!(index
+        .service_pagination_info(
+            &id("smithy.example#Example"),
+            &id("smithy.example#ListOrphans"),
+        )
+        .is_none());
+}
+
+#[test]
+fn test_paginated_validator() {
+    let model = make_model();
+    assert_eq!(
+        validation_messages(&model, Box::new(CorrectPaginatedTraits::default())),
+        vec![
+            "The paginated operation smithy.example#ListOrphans must specify inputToken.".to_string(),
+            "The paginated operation smithy.example#ListOrphans must specify outputToken.".to_string(),
+            "The items value orphans of paginated operation smithy.example#ListOrphans does not resolve to a member of the output structure.".to_string(),
+            "The inputToken member smithy.example#ListBadThingsInput$token of paginated operation smithy.example#ListBadThings in the service smithy.example#Example must target a string shape.".to_string(),
+            "The pageSize member smithy.example#ListBadThingsInput$maxResults of paginated operation smithy.example#ListBadThings in the service smithy.example#Example must target an integer shape.".to_string(),
+            "The outputToken value nextToken of paginated operation smithy.example#ListBadThings in the service smithy.example#Example does not resolve to a member of the output structure.".to_string(),
+            "The items member smithy.example#ListBadThingsOutput$things of paginated operation smithy.example#ListBadThings in the service smithy.example#Example must target a list, set, or map shape.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_paginated_required_members() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .operation(
+            OperationBuilder::new("ListThings")
+                .input("ListThingsInput")
+                .output("ListThingsOutput")
+                .apply_trait(paginated(
+                    Some("nextToken"),
+                    Some("nextToken"),
+                    None,
+                    Some("maxResults"),
+                ))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("ListThingsInput")
+                .add_member(
+                    MemberBuilder::new("nextToken", "smithy.api#String")
+                        .apply_trait(required())
+                        .into(),
+                )
+                .add_member(
+                    MemberBuilder::new("maxResults", "smithy.api#Integer")
+                        .apply_trait(required())
+                        .into(),
+                )
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("ListThingsOutput")
+                .add_member(
+                    MemberBuilder::new("nextToken", "smithy.api#String")
+                        .apply_trait(required())
+                        .into(),
+                )
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(CorrectPaginatedTraits::default())),
+        vec![
+            "The inputToken member smithy.example#ListThingsInput$nextToken of paginated operation smithy.example#ListThings must not have the @required trait applied.".to_string(),
+            "The pageSize member smithy.example#ListThingsInput$maxResults of paginated operation smithy.example#ListThings must not have the @required trait applied.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_paginated_invalid_values() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .service(
+            ServiceBuilder::new("Example", "2019-06-27")
+                .operation("ListThings")
+                .apply_trait(TraitBuilder::with_value(
+                    "smithy.api#paginated",
+                    Value::from("nextToken"),
+                ))
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("ListThings")
+                .apply_trait(TraitBuilder::with_value(
+                    "smithy.api#paginated",
+                    Value::from(1),
+                ))
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    assert!(PaginatedIndex::from(&model).is_empty());
+    assert_eq!(
+        validation_messages(&model, Box::new(CorrectPaginatedTraits::default())),
+        vec![
+            "The value of the trait @paginated applied to smithy.example#Example is not valid."
+                .to_string(),
+            "The value of the trait @paginated applied to smithy.example#ListThings is not valid."
+                .to_string(),
+        ]
+    );
+}
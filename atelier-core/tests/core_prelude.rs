@@ -0,0 +1,134 @@
+use atelier_core::action::validate::{
+    run_validation_actions, CorrectTraitValues, NoUnresolvedReferences,
+};
+use atelier_core::builder::{MemberBuilder, ModelBuilder, StructureBuilder};
+use atelier_core::model::shapes::HasTraits;
+use atelier_core::model::traits::{TraitTrait, TypedTrait};
+use atelier_core::model::{HasIdentity, Model, ShapeID};
+use atelier_core::prelude::{
+    defined_prelude_shapes, defined_prelude_traits, prelude_model, prelude_model_for_version,
+    prelude_shape_named, PRELUDE_NAMESPACE,
+};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+fn prelude_id(name: &str) -> ShapeID {
+    ShapeID::from_str(&format!("{}#{}", PRELUDE_NAMESPACE, name)).unwrap()
+}
+
+#[test]
+fn test_all_prelude_names_defined() {
+    let prelude = prelude_model();
+    assert_eq!(prelude.smithy_version(), &Version::latest());
+    for name in defined_prelude_shapes() {
+        let shape = prelude.shape(&prelude_shape_named(name).unwrap());
+        assert!(shape.is_some(), "prelude shape {} is not defined", name);
+        assert!(!shape.unwrap().is_unresolved());
+    }
+    for name in defined_prelude_traits() {
+        let shape = prelude.shape(&prelude_id(name));
+        assert!(shape.is_some(), "prelude trait {} is not defined", name);
+        assert!(!shape.unwrap().is_unresolved());
+    }
+    for shape in prelude
+        .shapes()
+        .filter(|shape| shape.has_trait(&TraitTrait::trait_id()))
+    {
+        let name = shape.id().shape_name().to_string();
+        assert!(
+            defined_prelude_traits().contains(name.as_str()),
+            "prelude trait {} is not named",
+            name
+        );
+    }
+    assert!(prelude
+        .shapes()
+        .all(|shape| shape.id().namespace().to_string() == PRELUDE_NAMESPACE));
+}
+
+#[test]
+fn test_trait_definitions() {
+    let prelude = prelude_model();
+    for name in defined_prelude_traits()
+        .iter()
+        .filter(|name| name.starts_with(char::is_lowercase))
+    {
+        let shape = prelude.shape(&prelude_id(name)).unwrap();
+        assert!(
+            shape.typed_trait::<TraitTrait>().unwrap().is_some(),
+            "prelude trait {} has no @trait",
+            name
+        );
+    }
+
+    let http = prelude.shape(&prelude_id("http")).unwrap();
+    let definition = http.typed_trait::<TraitTrait>().unwrap().unwrap();
+    assert_eq!(definition.selector(), &Some("operation".to_string()));
+
+    let readonly = prelude.shape(&prelude_id("readonly")).unwrap();
+    let definition = readonly.typed_trait::<TraitTrait>().unwrap().unwrap();
+    assert!(definition
+        .conflicts()
+        .any(|id| id == &prelude_id("idempotent")));
+
+    let payload = prelude.shape(&prelude_id("httpPayload")).unwrap();
+    let definition = payload.typed_trait::<TraitTrait>().unwrap().unwrap();
+    assert_eq!(
+        definition.structurally_exclusive(),
+        &Some("member".to_string())
+    );
+    assert!(definition
+        .conflicts()
+        .any(|id| id == &prelude_id("httpHeader")));
+    assert!(!definition
+        .conflicts()
+        .any(|id| id == &prelude_id("httpPayload")));
+
+    let non_empty = prelude.shape(&prelude_id("NonEmptyString")).unwrap();
+    assert!(non_empty.has_trait(&prelude_id("length")));
+    assert!(!non_empty.has_trait(&TraitTrait::trait_id()));
+}
+
+#[test]
+fn test_merge_prelude() {
+    let mut model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+        .structure(
+            StructureBuilder::new("City")
+                .add_member(MemberBuilder::string("name").required().into())
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    assert!(model.merge(prelude_model().clone()).is_err());
+
+    model
+        .merge(prelude_model_for_version(Version::V10))
+        .unwrap();
+    assert_eq!(model.smithy_version(), &Version::V10);
+    assert!(!model.shape(&prelude_id("String")).unwrap().is_unresolved());
+    assert!(model.shape(&prelude_id("required")).unwrap().is_trait());
+    assert!(model
+        .shape(&ShapeID::from_str("smithy.example#City").unwrap())
+        .is_some());
+
+    let issues = run_validation_actions(
+        &mut [Box::new(NoUnresolvedReferences::default())],
+        &model,
+        false,
+    )
+    .unwrap();
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_prelude_trait_values() {
+    let issues = run_validation_actions(
+        &mut [Box::new(CorrectTraitValues::default())],
+        prelude_model(),
+        false,
+    )
+    .unwrap();
+    assert!(issues.is_empty(), "{:#?}", issues);
+}
@@ -0,0 +1,187 @@
+use atelier_core::action::transform::PruneUnreachableShapes;
+use atelier_core::action::{Action, Transformer};
+use atelier_core::builder::traits::{documentation, error_source, references, ErrorSource};
+use atelier_core::builder::values::{ArrayBuilder, ObjectBuilder};
+use atelier_core::builder::{
+    ModelBuilder, OperationBuilder, ResourceBuilder, ServiceBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder, TraitBuilder,
+};
+use atelier_core::model::{HasIdentity, Model};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+
+mod common;
+use common::id;
+
+fn shape_names(model: &Model) -> Vec<String> {
+    let mut names: Vec<String> = model
+        .shapes()
+        .map(|shape| shape.id().shape_name().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-01")
+                .resource("City")
+                .into(),
+        )
+        .service(
+            ServiceBuilder::new("Admin", "2006-03-01")
+                .operation("Reset")
+                .into(),
+        )
+        .resource(
+            ResourceBuilder::new("City")
+                .identifier("cityId", "CityId")
+                .read("GetCity")
+                .into(),
+        )
+        .resource(ResourceBuilder::new("Country"))
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .error("NoSuchResource")
+                .into(),
+        )
+        .operation(OperationBuilder::new("Reset"))
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .member("cityId", "CityId")
+                .apply_trait(references(
+                    ArrayBuilder::default()
+                        .push(
+                            ObjectBuilder::default()
+                                .string("resource", "smithy.example#Country")
+                                .into(),
+                        )
+                        .into(),
+                ))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("NoSuchResource")
+                .apply_trait(error_source(ErrorSource::Client))
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("CityId")
+                .apply_trait(TraitBuilder::annotation("internal"))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("internal")
+                .apply_trait(TraitBuilder::annotation("trait"))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("unused")
+                .apply_trait(TraitBuilder::annotation("trait"))
+                .member("level", "Level")
+                .into(),
+        )
+        .simple_shape(SimpleShapeBuilder::integer("Level"))
+        .structure(
+            StructureBuilder::new("Orphan")
+                .member("name", "String")
+                .into(),
+        )
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_prune_from_service() {
+    let model = make_model();
+    let mut transformer = PruneUnreachableShapes::new(&[id("smithy.example#Weather")]);
+    let pruned = transformer.transform(&model).unwrap();
+    assert_eq!(
+        shape_names(&pruned),
+        vec![
+            "City",
+            "CityId",
+            "Country",
+            "GetCity",
+            "GetCityInput",
+            "NoSuchResource",
+            "Weather",
+            "internal"
+        ]
+    );
+
+    let messages: Vec<String> = transformer
+        .issues()
+        .iter()
+        .map(|issue| issue.message().clone())
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "The shape smithy.example#Admin was removed, it is not reachable from a service.",
+            "The shape smithy.example#Level was removed, it is not reachable from a service.",
+            "The shape smithy.example#Orphan was removed, it is not reachable from a service.",
+            "The shape smithy.example#Reset was removed, it is not reachable from a service.",
+            "The shape smithy.example#unused was removed, it is not reachable from a service.",
+        ]
+    );
+}
+
+#[test]
+fn test_prune_all_services_keeping_traits() {
+    let model = make_model();
+    let mut transformer = PruneUnreachableShapes::default();
+    transformer.set_keep_traits(true);
+    let pruned = transformer.transform(&model).unwrap();
+    assert_eq!(
+        shape_names(&pruned),
+        vec![
+            "Admin",
+            "City",
+            "CityId",
+            "Country",
+            "GetCity",
+            "GetCityInput",
+            "Level",
+            "NoSuchResource",
+            "Reset",
+            "Weather",
+            "internal",
+            "unused"
+        ]
+    );
+}
+
+#[test]
+fn test_prune_requires_services() {
+    let model = make_model();
+    let mut transformer = PruneUnreachableShapes::new(&[id("smithy.example#Missing")]);
+    assert!(transformer.transform(&model).is_err());
+
+    let mut transformer = PruneUnreachableShapes::new(&[id("smithy.example#City")]);
+    assert!(transformer.transform(&model).is_err());
+}
+
+#[test]
+fn test_prune_ignores_identifiers_in_text() {
+    let model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-01")
+                .apply_trait(documentation("smithy.example#Orphan"))
+                .apply_trait(TraitBuilder::with_value(
+                    "externalDocumentation",
+                    ObjectBuilder::default()
+                        .string("Orphan", "smithy.example#Orphan")
+                        .into(),
+                ))
+                .into(),
+        )
+        .structure(StructureBuilder::new("Orphan"))
+        .try_into()
+        .unwrap();
+    let pruned = PruneUnreachableShapes::default().transform(&model).unwrap();
+    assert_eq!(shape_names(&pruned), vec!["Weather"]);
+}
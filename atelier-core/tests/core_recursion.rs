@@ -0,0 +1,83 @@
+use atelier_core::action::validate::NoIllegalRecursion;
+use atelier_core::builder::traits::required;
+use atelier_core::builder::{
+    ListBuilder, MemberBuilder, ModelBuilder, ShapeTraits, StructureBuilder,
+};
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+
+mod common;
+use common::validation_messages;
+
+fn required_member(name: &str, target: &str) -> MemberBuilder {
+    MemberBuilder::new(name, target)
+        .apply_trait(required())
+        .into()
+}
+
+#[test]
+fn test_required_cycle_is_illegal() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .structure(
+            StructureBuilder::new("A")
+                .add_member(required_member("b", "B"))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("B")
+                .add_member(required_member("a", "A"))
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(NoIllegalRecursion::default())),
+        vec![
+            "The shape smithy.example#A is recursive through required members only, and so cannot be instantiated: smithy.example#A$b -> smithy.example#B$a -> smithy.example#A.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_cycle_broken_by_list() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .structure(
+            StructureBuilder::new("Node")
+                .add_member(required_member("children", "Nodes"))
+                .into(),
+        )
+        .list(ListBuilder::new("Nodes", "Node"))
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(NoIllegalRecursion::default())),
+        vec![
+            "The shape smithy.example#Node is recursive, the cycle is broken by the list smithy.example#Nodes: smithy.example#Node$children -> smithy.example#Nodes$member -> smithy.example#Node.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_cycle_broken_by_optional_member() {
+    let model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .structure(
+            StructureBuilder::new("Person")
+                .add_member(required_member("partner", "Partner"))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("Partner")
+                .member("person", "Person")
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        validation_messages(&model, Box::new(NoIllegalRecursion::default())),
+        vec![
+            "The shape smithy.example#Partner is recursive, the cycle is broken by the optional member smithy.example#Partner$person: smithy.example#Partner$person -> smithy.example#Person$partner -> smithy.example#Partner.".to_string(),
+        ]
+    );
+}
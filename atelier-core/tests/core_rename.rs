@@ -0,0 +1,230 @@
+use atelier_core::action::transform::RenameShapes;
+use atelier_core::action::{Action, Transformer};
+use atelier_core::builder::traits::{a_trait, documentation, references};
+use atelier_core::builder::values::{ArrayBuilder, ObjectBuilder};
+use atelier_core::builder::{
+    MemberBuilder, ModelBuilder, OperationBuilder, ResourceBuilder, ServiceBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder, TraitBuilder,
+};
+use atelier_core::io::lines::make_line_oriented_form;
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+mod common;
+use common::id;
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-01")
+                .resource("City")
+                .operation("GetForecast")
+                .rename("smithy.example#CityCode", "Code")
+                .into(),
+        )
+        .resource(
+            ResourceBuilder::new("City")
+                .identifier("cityId", "CityCode")
+                .read("GetCity")
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .output("GetCityOutput")
+                .into(),
+        )
+        .operation(OperationBuilder::new("GetForecast"))
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .add_member(MemberBuilder::new("cityId", "CityCode").required().into())
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityOutput")
+                .member("name", "String")
+                .apply_trait(references(
+                    ArrayBuilder::default()
+                        .push(
+                            ObjectBuilder::default()
+                                .string("resource", "smithy.example#City")
+                                .into(),
+                        )
+                        .into(),
+                ))
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("CityCode")
+                .apply_trait(TraitBuilder::annotation("internal"))
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("internal")
+                .apply_trait(TraitBuilder::annotation("trait"))
+                .into(),
+        )
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_rename_shapes() {
+    let model = make_model();
+    let mut renames: HashMap<ShapeID, ShapeID> = Default::default();
+    let _ = renames.insert(id("smithy.example#City"), id("smithy.cities#City"));
+    let _ = renames.insert(id("smithy.example#CityCode"), id("smithy.example#CityId"));
+    let _ = renames.insert(id("smithy.example#internal"), id("smithy.example#private"));
+    let mut transformer = RenameShapes::new(renames);
+    let renamed = transformer.transform(&model).unwrap();
+
+    assert_eq!(
+        make_line_oriented_form(&renamed),
+        vec![
+            "operation::smithy.example#GetCity",
+            "operation::smithy.example#GetCity::input=>smithy.example#GetCityInput",
+            "operation::smithy.example#GetCity::output=>smithy.example#GetCityOutput",
+            "operation::smithy.example#GetForecast",
+            "resource::smithy.cities#City",
+            "resource::smithy.cities#City::identifier::cityId=>smithy.example#CityId",
+            "resource::smithy.cities#City::read=>smithy.example#GetCity",
+            "service::smithy.example#Weather",
+            "service::smithy.example#Weather::operation=>smithy.example#GetForecast",
+            "service::smithy.example#Weather::rename::smithy.example#CityId<=Code",
+            "service::smithy.example#Weather::resource=>smithy.cities#City",
+            "service::smithy.example#Weather::version<=\"2006-03-01\"",
+            "string::smithy.example#CityId",
+            "string::smithy.example#CityId::trait::smithy.example#private<={}",
+            "structure::smithy.example#GetCityInput",
+            "structure::smithy.example#GetCityInput::cityId::trait::smithy.api#required<={}",
+            "structure::smithy.example#GetCityInput::cityId=>smithy.example#CityId",
+            "structure::smithy.example#GetCityOutput",
+            "structure::smithy.example#GetCityOutput::name=>smithy.api#String",
+            "structure::smithy.example#GetCityOutput::trait::smithy.api#references<=[0]={resource}=\"smithy.cities#City\"",
+            "structure::smithy.example#private",
+            "structure::smithy.example#private::trait::smithy.api#trait<={}",
+        ]
+    );
+
+    let messages: Vec<String> = transformer
+        .issues()
+        .iter()
+        .map(|issue| issue.message().clone())
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "The shape smithy.example#City was renamed to smithy.cities#City.",
+            "The shape smithy.example#CityCode was renamed to smithy.example#CityId.",
+            "The shape smithy.example#internal was renamed to smithy.example#private.",
+            "The references from smithy.cities#City to smithy.example#CityCode were rewritten.",
+            "The references from smithy.example#CityId to smithy.example#internal were rewritten.",
+            "The references from smithy.example#GetCityInput to smithy.example#CityCode were rewritten.",
+            "The references from smithy.example#GetCityOutput to smithy.example#City were rewritten.",
+            "The references from smithy.example#Weather to smithy.example#City, smithy.example#CityCode were rewritten.",
+        ]
+    );
+}
+
+#[test]
+fn test_rename_conflicts() {
+    let model = make_model();
+
+    let mut transformer = RenameShapes::default();
+    let _ = transformer.insert_rename(id("smithy.example#City"), id("smithy.example#GetCity"));
+    assert!(transformer.transform(&model).is_err());
+
+    let mut transformer = RenameShapes::default();
+    let _ = transformer.insert_rename(id("smithy.example#City"), id("smithy.example#Town"));
+    let _ = transformer.insert_rename(id("smithy.example#CityCode"), id("smithy.example#Town"));
+    assert!(transformer.transform(&model).is_err());
+
+    let mut transformer = RenameShapes::default();
+    let _ = transformer.insert_rename(
+        id("smithy.example#GetCity"),
+        id("smithy.example#GetForecast"),
+    );
+    let _ = transformer.insert_rename(
+        id("smithy.example#GetForecast"),
+        id("smithy.example#GetCity"),
+    );
+    let swapped = transformer.transform(&model).unwrap();
+    assert!(swapped.has_shape(&id("smithy.example#GetCity")));
+    assert!(swapped.has_shape(&id("smithy.example#GetForecast")));
+
+    let mut transformer = RenameShapes::default();
+    let _ = transformer.insert_rename(id("smithy.example#Missing"), id("smithy.example#Found"));
+    let unchanged = transformer.transform(&model).unwrap();
+    assert_eq!(unchanged, model);
+    assert_eq!(
+        transformer.issues()[0].message(),
+        "The shape smithy.example#Missing cannot be renamed, it is not in the model."
+    );
+}
+
+#[test]
+fn test_rename_only_rewrites_id_ref_values() {
+    let model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+        .structure(StructureBuilder::new("City"))
+        .structure(
+            StructureBuilder::new("GetCityOutput")
+                .apply_trait(documentation("smithy.example#City"))
+                .apply_trait(TraitBuilder::with_value(
+                    "examples",
+                    ArrayBuilder::default()
+                        .push(
+                            ObjectBuilder::default()
+                                .string("title", "smithy.example#City")
+                                .into(),
+                        )
+                        .into(),
+                ))
+                .apply_trait(references(
+                    ArrayBuilder::default()
+                        .push(
+                            ObjectBuilder::default()
+                                .string("resource", "smithy.example#City")
+                                .into(),
+                        )
+                        .into(),
+                ))
+                .apply_trait(TraitBuilder::with_value(
+                    "cityRef",
+                    "smithy.example#City".into(),
+                ))
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("cityRef")
+                .apply_trait(a_trait())
+                .apply_trait(TraitBuilder::with_value(
+                    "idRef",
+                    ObjectBuilder::default()
+                        .string("selector", "structure")
+                        .into(),
+                ))
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    let mut renames: HashMap<ShapeID, ShapeID> = Default::default();
+    let _ = renames.insert(id("smithy.example#City"), id("smithy.cities#City"));
+    let _ = renames.insert(id("smithy.example#cityRef"), id("smithy.example#townRef"));
+    let renamed = RenameShapes::new(renames).transform(&model).unwrap();
+
+    assert_eq!(
+        make_line_oriented_form(&renamed)
+            .into_iter()
+            .filter(|line| line.starts_with("structure::smithy.example#GetCityOutput::trait"))
+            .collect::<Vec<String>>(),
+        vec![
+            "structure::smithy.example#GetCityOutput::trait::smithy.api#documentation<=\"smithy.example#City\"",
+            "structure::smithy.example#GetCityOutput::trait::smithy.api#examples<=[0]={title}=\"smithy.example#City\"",
+            "structure::smithy.example#GetCityOutput::trait::smithy.api#references<=[0]={resource}=\"smithy.cities#City\"",
+            "structure::smithy.example#GetCityOutput::trait::smithy.example#townRef<=\"smithy.cities#City\"",
+        ]
+    );
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "serde")]
+
+use atelier_core::builder::traits::{documentation, length};
+use atelier_core::builder::values::{ArrayBuilder, ObjectBuilder};
+use atelier_core::builder::{
+    EnumBuilder, ListBuilder, MapBuilder, MemberBuilder, ModelBuilder, OperationBuilder,
+    ResourceBuilder, ServiceBuilder, ShapeTraits, SimpleShapeBuilder, StructureBuilder,
+    TraitBuilder,
+};
+use atelier_core::model::shapes::{ShapeKind, TopLevelShape};
+use atelier_core::model::values::Value;
+use atelier_core::model::{HasSourceLocation, Model, ShapeID, SourceLocation};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::collections::HashSet;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+fn make_model() -> Model {
+    ModelBuilder::new(Version::V20, "smithy.example")
+        .meta_data(
+            "authors".to_string(),
+            ArrayBuilder::default().string("Someone").into(),
+        )
+        .service(
+            ServiceBuilder::new("Weather", "2006-03-01")
+                .resource("City")
+                .rename("smithy.other#Country", "OtherCountry")
+                .into(),
+        )
+        .resource(
+            ResourceBuilder::new("City")
+                .identifier("cityId", "CityId")
+                .read("GetCity")
+                .into(),
+        )
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .output("GetCityOutput")
+                .error("NoSuchResource")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .add_member(MemberBuilder::new("cityId", "CityId").required().into())
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityOutput")
+                .member("name", "String")
+                .member("location", "Location")
+                .member("forecast", "Forecast")
+                .member("neighborhoods", "Neighborhoods")
+                .member("units", "Units")
+                .member("rank", "Rank")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("NoSuchResource")
+                .apply_trait(TraitBuilder::with_value("error", Value::from("client")))
+                .member("resourceType", "String")
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("CityId")
+                .apply_trait(length(Some(1), None))
+                .apply_trait(documentation("The identifier of a city."))
+                .into(),
+        )
+        .union(
+            StructureBuilder::new("Location")
+                .member("address", "String")
+                .float("latitude")
+                .into(),
+        )
+        .list(ListBuilder::new("Forecast", "Float"))
+        .set(ListBuilder::new("Tags", "String"))
+        .map(MapBuilder::new("Neighborhoods", "String", "Integer"))
+        .enum_shape(
+            EnumBuilder::new("Units")
+                .member("METRIC")
+                .string_member("IMPERIAL", "imperial")
+                .into(),
+        )
+        .int_enum(
+            EnumBuilder::new("Rank")
+                .int_member("FIRST", 1)
+                .int_member("SECOND", 2)
+                .into(),
+        )
+        .apply(
+            "Weather",
+            TraitBuilder::with_value(
+                "smithy.example#config",
+                ObjectBuilder::default()
+                    .boolean("enabled", true)
+                    .float("ratio", 0.5)
+                    .integer("retries", -3)
+                    .insert("nothing", Value::None)
+                    .into(),
+            ),
+        )
+        .uses("smithy.other#Country")
+        .try_into()
+        .unwrap()
+}
+
+fn kind_name(shape: &TopLevelShape) -> &'static str {
+    match shape.body() {
+        ShapeKind::Simple(_) => "simple",
+        ShapeKind::List(_) => "list",
+        ShapeKind::Set(_) => "set",
+        ShapeKind::Map(_) => "map",
+        ShapeKind::Structure(_) => "structure",
+        ShapeKind::Union(_) => "union",
+        ShapeKind::Enum(_) => "enum",
+        ShapeKind::IntEnum(_) => "intEnum",
+        ShapeKind::Service(_) => "service",
+        ShapeKind::Operation(_) => "operation",
+        ShapeKind::Resource(_) => "resource",
+        ShapeKind::Unresolved => "unresolved",
+    }
+}
+
+#[test]
+fn test_model_round_trip() {
+    let model = make_model();
+    let kinds: HashSet<&str> = model.shapes().map(kind_name).collect();
+    assert_eq!(kinds.len(), 12);
+
+    let json = serde_json::to_string(&model).unwrap();
+    let read: Model = serde_json::from_str(&json).unwrap();
+    assert_eq!(read, model);
+    assert_eq!(read.smithy_version(), &Version::V20);
+}
+
+#[test]
+fn test_identifiers_as_strings() {
+    let shape_id = ShapeID::from_str("smithy.example#City$name").unwrap();
+    assert_eq!(
+        serde_json::to_string(&shape_id).unwrap(),
+        "\"smithy.example#City$name\""
+    );
+    assert_eq!(
+        serde_json::from_str::<ShapeID>("\"smithy.example#City$name\"").unwrap(),
+        shape_id
+    );
+    assert!(serde_json::from_str::<ShapeID>("\"not a shape id\"").is_err());
+    assert_eq!(serde_json::to_string(&Version::V20).unwrap(), "\"2.0\"");
+
+    let json = serde_json::to_value(make_model()).unwrap();
+    assert!(json["shapes"]["smithy.example#City"].is_object());
+}
+
+#[test]
+fn test_source_locations_preserved() {
+    let mut shape = make_model()
+        .shape(&ShapeID::from_str("smithy.example#CityId").unwrap())
+        .unwrap()
+        .clone();
+    shape.set_location(SourceLocation::in_file("weather.smithy", 12, 1));
+
+    let json = serde_json::to_string(&shape).unwrap();
+    let read: TopLevelShape = serde_json::from_str(&json).unwrap();
+    assert_eq!(read, shape);
+    assert_eq!(read.location(), shape.location());
+}
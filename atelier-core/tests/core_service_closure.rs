@@ -0,0 +1,89 @@
+use atelier_core::action::validate::NoServiceNameConflicts;
+use atelier_core::builder::{
+    ModelBuilder, OperationBuilder, ServiceBuilder, SimpleShapeBuilder, StructureBuilder,
+};
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+
+mod common;
+use common::validation_messages;
+
+fn make_model(service: &mut ServiceBuilder) -> Model {
+    let mut model: Model = ModelBuilder::new(Version::V20, "smithy.example")
+        .service(service.operation("GetCity").into())
+        .operation(
+            OperationBuilder::new("GetCity")
+                .input("GetCityInput")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("GetCityInput")
+                .member("city", "City")
+                .member("other", "smithy.other#City")
+                .member("summary", "citySummary")
+                .member("otherSummary", "smithy.other#CitySummary")
+                .member("name", "String")
+                .into(),
+        )
+        .structure(
+            StructureBuilder::new("City")
+                .member("name", "String")
+                .into(),
+        )
+        .simple_shape(SimpleShapeBuilder::string("citySummary"))
+        .simple_shape(SimpleShapeBuilder::string("Unused"))
+        .try_into()
+        .unwrap();
+    let other: Model = ModelBuilder::new(Version::V20, "smithy.other")
+        .structure(StructureBuilder::new("City"))
+        .simple_shape(SimpleShapeBuilder::string("CitySummary"))
+        .try_into()
+        .unwrap();
+    model.merge(other).unwrap();
+    model
+}
+
+#[test]
+fn test_conflicting_names() {
+    let model = make_model(&mut ServiceBuilder::new("Weather", "2006-03-01"));
+    assert_eq!(
+        validation_messages(&model, Box::new(NoServiceNameConflicts::default())),
+        vec![
+            "The shapes smithy.example#City, smithy.other#City have conflicting names in the closure of the service smithy.example#Weather, consider adding a rename to the service.".to_string(),
+            "The shapes smithy.example#citySummary, smithy.other#CitySummary have conflicting names in the closure of the service smithy.example#Weather, consider adding a rename to the service.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_renamed_names() {
+    let model = make_model(
+        ServiceBuilder::new("Weather", "2006-03-01")
+            .rename("smithy.other#City", "OtherCity")
+            .rename("smithy.other#CitySummary", "OtherCitySummary")
+            .rename("smithy.example#Unused", "NotUsed"),
+    );
+    assert_eq!(
+        validation_messages(&model, Box::new(NoServiceNameConflicts::default())),
+        vec![
+            "The service smithy.example#Weather renames the shape smithy.example#Unused to NotUsed, but that shape is not in the closure of the service.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_renamed_into_conflict() {
+    let model = make_model(
+        ServiceBuilder::new("Weather", "2006-03-01")
+            .rename("smithy.other#City", "getCityInput")
+            .rename("smithy.other#CitySummary", "OtherCitySummary"),
+    );
+    assert_eq!(
+        validation_messages(&model, Box::new(NoServiceNameConflicts::default())),
+        vec![
+            "The shapes smithy.example#GetCityInput, smithy.other#City have conflicting names in the closure of the service smithy.example#Weather, consider adding a rename to the service.".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,37 @@
+use atelier_core::{
+    builder::{ListBuilder, ModelBuilder},
+    model::{shapes::ShapeKind, Model, ShapeID},
+    Version,
+};
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+#[test]
+/// verifies that `ModelBuilder::set` builds a set, not a list
+fn test_shape_set() {
+    let model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+        .set(ListBuilder::new("MySet", "String"))
+        .list(ListBuilder::new("MyList", "String"))
+        .try_into()
+        .unwrap();
+
+    let set = model
+        .shape(&ShapeID::from_str("smithy.example#MySet").unwrap())
+        .unwrap();
+    assert!(set.is_set());
+    if let ShapeKind::Set(set) = set.body() {
+        assert_eq!(
+            set.member().target(),
+            &ShapeID::from_str("smithy.api#String").unwrap()
+        );
+    } else {
+        panic!("unexpected shape kind - expected set")
+    }
+
+    let list = model
+        .shape(&ShapeID::from_str("smithy.example#MyList").unwrap())
+        .unwrap();
+    assert!(list.is_list());
+    assert!(!list.is_set());
+}
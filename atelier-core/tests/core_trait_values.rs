@@ -0,0 +1,125 @@
+use atelier_core::action::validate::CorrectTraitValues;
+use atelier_core::builder::traits::{documentation, length};
+use atelier_core::builder::values::{ArrayBuilder, ObjectBuilder};
+use atelier_core::builder::{
+    ListBuilder, MemberBuilder, ModelBuilder, ShapeTraits, SimpleShapeBuilder, StructureBuilder,
+    TraitBuilder,
+};
+use atelier_core::model::values::Value;
+use atelier_core::model::Model;
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::TryInto;
+
+mod common;
+use common::{sorted_strings, validation_messages};
+
+fn make_model(config_value: Value, length_trait: TraitBuilder) -> Model {
+    ModelBuilder::new(Version::V10, "smithy.example")
+        .structure(
+            StructureBuilder::new("config")
+                .add_member(MemberBuilder::string("name").required().into())
+                .add_member(MemberBuilder::new("retries", "Integer"))
+                .add_member(MemberBuilder::new("tags", "TagList"))
+                .apply_trait(TraitBuilder::annotation("trait"))
+                .into(),
+        )
+        .list(ListBuilder::new("TagList", "String"))
+        .simple_shape(
+            SimpleShapeBuilder::string("MyName")
+                .apply_trait(documentation("A name."))
+                .into(),
+        )
+        .simple_shape(
+            SimpleShapeBuilder::string("MyString")
+                .apply_trait(length_trait)
+                .apply_trait(TraitBuilder::with_value("config", config_value))
+                .into(),
+        )
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_valid_trait_values() {
+    let model = make_model(
+        ObjectBuilder::default()
+            .string("name", "example")
+            .integer("retries", 3)
+            .insert(
+                "tags",
+                ArrayBuilder::default().string("a").string("b").into(),
+            )
+            .into(),
+        length(Some(1), Some(10)),
+    );
+    assert!(validation_messages(&model, Box::new(CorrectTraitValues::default())).is_empty());
+}
+
+#[test]
+fn test_invalid_prelude_trait_value() {
+    let model = make_model(
+        ObjectBuilder::default().string("name", "example").into(),
+        TraitBuilder::with_value(
+            "length",
+            ObjectBuilder::default()
+                .string("min", "x")
+                .integer("maximum", 10)
+                .into(),
+        ),
+    );
+    assert_eq!(
+        sorted_strings(&validation_messages(
+            &model,
+            Box::new(CorrectTraitValues::default())
+        )),
+        vec![
+            "The value of trait @smithy.api#length applied to smithy.example#MyString is invalid at `maximum`: smithy.api#length has no member named maximum.",
+            "The value of trait @smithy.api#length applied to smithy.example#MyString is invalid at `min`: expected an integer, found a string.",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_user_trait_value() {
+    let model = make_model(
+        ObjectBuilder::default()
+            .integer("retries", 3)
+            .insert(
+                "tags",
+                ArrayBuilder::default().string("a").integer(2).into(),
+            )
+            .into(),
+        length(Some(1), Some(10)),
+    );
+    assert_eq!(
+        sorted_strings(&validation_messages(
+            &model,
+            Box::new(CorrectTraitValues::default())
+        )),
+        vec![
+            "The value of trait @smithy.example#config applied to smithy.example#MyString is invalid at `tags[1]`: expected a string, found an integer.",
+            "The value of trait @smithy.example#config applied to smithy.example#MyString is invalid: the required member name is missing.",
+        ]
+    );
+}
+
+#[test]
+fn test_applied_shape_is_not_a_trait() {
+    let model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+        .simple_shape(SimpleShapeBuilder::string("NotATrait"))
+        .simple_shape(
+            SimpleShapeBuilder::string("Other")
+                .apply_trait(TraitBuilder::annotation("NotATrait"))
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        sorted_strings(&validation_messages(
+            &model,
+            Box::new(CorrectTraitValues::default())
+        )),
+        vec!["The shape smithy.example#NotATrait applied to smithy.example#Other is not a trait, it has no @trait trait."]
+    );
+}
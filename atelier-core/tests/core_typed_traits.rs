@@ -0,0 +1,167 @@
+use atelier_core::builder::traits::{length, ErrorSource};
+use atelier_core::builder::values::{ArrayBuilder, ObjectBuilder};
+use atelier_core::builder::{ModelBuilder, ShapeTraits, SimpleShapeBuilder, StructureBuilder};
+use atelier_core::model::shapes::{HasTraits, TopLevelShape};
+use atelier_core::model::traits::{
+    DeprecatedTrait, DocumentationTrait, EnumTrait, ErrorTrait, HttpTrait, LengthTrait,
+    PaginatedTrait, RangeTrait, ReferencesTrait, RequiredTrait, TypedTrait,
+};
+use atelier_core::model::values::{Number, Value};
+use atelier_core::model::{Model, ShapeID};
+use atelier_core::Version;
+use pretty_assertions::assert_eq;
+use std::convert::{TryFrom, TryInto};
+use std::str::FromStr;
+
+fn round_trip<T: TypedTrait + Clone + PartialEq + std::fmt::Debug>(value: Value) -> T {
+    let typed = T::try_from(&value).unwrap();
+    let back: Value = typed.clone().into();
+    assert_eq!(back, value);
+    typed
+}
+
+#[test]
+fn test_trait_ids() {
+    assert_eq!(
+        HttpTrait::trait_id(),
+        ShapeID::from_str("smithy.api#http").unwrap()
+    );
+    assert_eq!(
+        RequiredTrait::trait_id(),
+        ShapeID::from_str("smithy.api#required").unwrap()
+    );
+}
+
+#[test]
+fn test_value_round_trips() {
+    let http: HttpTrait = round_trip(
+        ObjectBuilder::default()
+            .string("method", "PUT")
+            .string("uri", "/cities/{name}")
+            .integer("code", 201)
+            .into(),
+    );
+    assert_eq!(http.method(), "PUT");
+    assert_eq!(http.code(), &Some(201));
+
+    let paginated: PaginatedTrait = round_trip(
+        ObjectBuilder::default()
+            .string("inputToken", "nextToken")
+            .string("items", "cities")
+            .into(),
+    );
+    assert_eq!(paginated.input_token(), &Some("nextToken".to_string()));
+    assert_eq!(paginated.page_size(), &None);
+
+    let range: RangeTrait = round_trip(ObjectBuilder::default().float("max", 1.5).into());
+    assert_eq!(range.min(), &None);
+    assert_eq!(range.max(), &Some(Number::Float(1.5)));
+
+    let deprecated: DeprecatedTrait = round_trip(ObjectBuilder::default().into());
+    assert!(!deprecated.has_message());
+
+    let error: ErrorTrait = round_trip(Value::from("client"));
+    assert_eq!(error.source(), &ErrorSource::Client);
+
+    let enums: EnumTrait = round_trip(
+        ArrayBuilder::default()
+            .push(
+                ObjectBuilder::default()
+                    .string("value", "t2.nano")
+                    .string("name", "T2_NANO")
+                    .boolean("deprecated", true)
+                    .into(),
+            )
+            .into(),
+    );
+    let definition = enums.values().next().unwrap();
+    assert_eq!(definition.value(), "t2.nano");
+    assert!(definition.is_deprecated());
+
+    let references: ReferencesTrait = round_trip(
+        ArrayBuilder::default()
+            .push(
+                ObjectBuilder::default()
+                    .string("resource", "smithy.example#City")
+                    .insert(
+                        "ids",
+                        ObjectBuilder::default().string("cityId", "id").into(),
+                    )
+                    .into(),
+            )
+            .into(),
+    );
+    let reference = references.values().next().unwrap();
+    assert_eq!(
+        reference.resource(),
+        &ShapeID::from_str("smithy.example#City").unwrap()
+    );
+    assert_eq!(
+        reference.contains_id(&"cityId".to_string()),
+        Some(&"id".to_string())
+    );
+
+    let _: RequiredTrait = round_trip(ObjectBuilder::default().into());
+}
+
+#[test]
+fn test_invalid_values() {
+    assert!(HttpTrait::try_from(&ObjectBuilder::default().string("method", "GET").into()).is_err());
+    assert!(HttpTrait::try_from(
+        &ObjectBuilder::default()
+            .string("method", "GET")
+            .string("uri", "/")
+            .string("verb", "GET")
+            .into()
+    )
+    .is_err());
+    assert!(LengthTrait::try_from(&ObjectBuilder::default().integer("min", -1).into()).is_err());
+    assert!(DocumentationTrait::try_from(&Value::from(1)).is_err());
+    assert!(ErrorTrait::try_from(&Value::from("neither")).is_err());
+    assert!(RequiredTrait::try_from(&Value::from(true)).is_err());
+}
+
+#[test]
+fn test_typed_trait_helpers() {
+    let model: Model = ModelBuilder::new(Version::V10, "smithy.example")
+        .simple_shape(
+            SimpleShapeBuilder::string("CityName")
+                .apply_trait(length(Some(1), Some(20)))
+                .into(),
+        )
+        .structure(StructureBuilder::new("City"))
+        .try_into()
+        .unwrap();
+    let city_name = model
+        .shape(&ShapeID::from_str("smithy.example#CityName").unwrap())
+        .unwrap();
+
+    let length = city_name.typed_trait::<LengthTrait>().unwrap().unwrap();
+    assert_eq!(length.min(), &Some(1));
+    assert_eq!(length.max(), &Some(20));
+    assert_eq!(city_name.typed_trait::<HttpTrait>().unwrap(), None);
+
+    let mut city: TopLevelShape = model
+        .shape(&ShapeID::from_str("smithy.example#City").unwrap())
+        .unwrap()
+        .clone();
+    city.apply_typed_trait(DocumentationTrait::new("A city."))
+        .unwrap();
+    city.apply_typed_trait(DeprecatedTrait::default()).unwrap();
+    assert!(city.has_documentation());
+    assert!(city.is_deprecated());
+    assert_eq!(
+        city.typed_trait::<DocumentationTrait>().unwrap(),
+        Some(DocumentationTrait::new("A city."))
+    );
+    assert_eq!(
+        city.typed_trait::<DeprecatedTrait>().unwrap(),
+        Some(DeprecatedTrait::default())
+    );
+
+    city.apply(RequiredTrait::trait_id()).unwrap();
+    assert_eq!(
+        city.typed_trait::<RequiredTrait>().unwrap(),
+        Some(RequiredTrait)
+    );
+}
@@ -0,0 +1,88 @@
+use atelier_core::builder::values::ObjectBuilder;
+use atelier_core::model::values::{Number, Value};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_number_from_str() {
+    assert_eq!(Number::from_str("42").unwrap(), Number::Integer(42));
+    assert_eq!(Number::from_str("-42").unwrap(), Number::Integer(-42));
+    assert_eq!(
+        Number::from_str("9223372036854775808").unwrap(),
+        Number::BigInteger("9223372036854775808".to_string())
+    );
+    assert_eq!(Number::from_str("1.5").unwrap(), Number::Float(1.5));
+    assert_eq!(Number::from_str("1e3").unwrap(), Number::Float(1000.0));
+    assert_eq!(Number::from_str("0.000").unwrap(), Number::Float(0.0));
+    assert_eq!(
+        Number::from_str("3.14159265358979323846").unwrap(),
+        Number::BigDecimal("3.14159265358979323846".to_string())
+    );
+    assert_eq!(
+        Number::from_str("1e400").unwrap(),
+        Number::BigDecimal("1e400".to_string())
+    );
+    assert!(Number::from_str("").is_err());
+    assert!(Number::from_str("1.").is_err());
+    assert!(Number::from_str("1e").is_err());
+    assert!(Number::from_str("one").is_err());
+}
+
+#[test]
+fn test_number_display_is_lossless() {
+    for literal in &[
+        "-170141183460469231731687303715884105728",
+        "0.1000000000000000000000000000001",
+    ] {
+        let number = Number::from_str(literal).unwrap();
+        assert!(!matches!(number, Number::Integer(_) | Number::Float(_)));
+        assert_eq!(&number.to_string(), literal);
+    }
+}
+
+#[test]
+fn test_number_numeric_cmp_is_lossless() {
+    let number = |s: &str| Number::from_str(s).unwrap();
+    assert_eq!(
+        Number::Integer(i64::MAX).numeric_cmp(&number("9223372036854775808")),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        number("-9223372036854775809").numeric_cmp(&Number::Integer(i64::MIN)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        number("0.1000000000000000000000000000001").numeric_cmp(&Number::Float(0.1)),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        Number::Integer(1000).numeric_cmp(&Number::Float(1e3)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        Number::Float(-0.0).numeric_cmp(&Number::Integer(0)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        Number::Float(-2.5).numeric_cmp(&Number::Integer(-3)),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        Number::Float(f64::NAN).numeric_cmp(&Number::Integer(0)),
+        None
+    );
+}
+
+#[test]
+fn test_object_keeps_insertion_order() {
+    let value: Value = ObjectBuilder::default()
+        .string("zulu", "z")
+        .string("alpha", "a")
+        .string("mike", "m")
+        .into();
+    assert_eq!(
+        value.as_object().unwrap().keys().collect::<Vec<&String>>(),
+        vec!["zulu", "alpha", "mike"]
+    );
+    assert_eq!(value.to_string(), r#"{ zulu: "z", alpha: "a", mike: "m" }"#);
+}
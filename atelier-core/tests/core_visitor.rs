@@ -1,10 +1,20 @@
+use atelier_core::builder::{ModelBuilder, StructureBuilder};
+use atelier_core::error::{Error, ErrorKind};
+use atelier_core::io::lines::make_line_oriented_form;
 use atelier_core::model::shapes::{
-    AppliedTraits, Operation, Resource, Service, Simple, StructureOrUnion,
+    AppliedTraits, HasTraits, MemberShape, Operation, Resource, Service, ShapeKind, Simple,
+    StructureOrUnion, TopLevelShape,
 };
-use atelier_core::model::visitor::{walk_model, ModelVisitor};
-use atelier_core::model::ShapeID;
+use atelier_core::model::values::Value;
+use atelier_core::model::visitor::{
+    walk_model, walk_model_mut, ModelVisitor, ModelVisitorMut, VisitAction,
+};
+use atelier_core::model::{HasIdentity, Identifier, Model, ShapeID};
+use atelier_core::Version;
 use std::cell::RefCell;
 use std::collections::HashSet;
+use std::convert::TryInto;
+use std::str::FromStr;
 
 use atelier_test::examples::motd::make_message_of_the_day_model;
 
@@ -117,3 +127,224 @@ fn test_model_visitor() {
     let remaining = visitor.expected.borrow();
     assert!(remaining.is_empty());
 }
+
+#[derive(Default)]
+struct RewritingVisitor {
+    visited: Vec<String>,
+}
+
+impl ModelVisitorMut for RewritingVisitor {
+    type Error = Error;
+
+    fn simple_shape(
+        &mut self,
+        id: &ShapeID,
+        traits: &mut AppliedTraits,
+        _: &mut Simple,
+    ) -> std::result::Result<VisitAction<TopLevelShape>, Self::Error> {
+        self.visited.push(id.to_string());
+        let _ = traits.insert(
+            ShapeID::from_str("smithy.api#documentation").unwrap(),
+            Some(Value::from("A date.")),
+        );
+        Ok(VisitAction::Keep)
+    }
+
+    fn structure(
+        &mut self,
+        id: &ShapeID,
+        _: &mut AppliedTraits,
+        _: &mut StructureOrUnion,
+    ) -> std::result::Result<VisitAction<TopLevelShape>, Self::Error> {
+        self.visited.push(id.to_string());
+        if id.shape_name().to_string() == "BadDateValue" {
+            Ok(VisitAction::Remove)
+        } else {
+            Ok(VisitAction::Keep)
+        }
+    }
+
+    fn operation(
+        &mut self,
+        id: &ShapeID,
+        traits: &mut AppliedTraits,
+        operation: &mut Operation,
+    ) -> std::result::Result<VisitAction<TopLevelShape>, Self::Error> {
+        self.visited.push(id.to_string());
+        operation.set_errors(&[]);
+        Ok(VisitAction::Replace(TopLevelShape::with_traits(
+            ShapeID::from_str("example.motd#FetchMessage").unwrap(),
+            ShapeKind::Operation(operation.clone()),
+            traits.clone(),
+        )))
+    }
+
+    fn member(
+        &mut self,
+        parent_shape_id: &ShapeID,
+        member: &mut MemberShape,
+    ) -> std::result::Result<VisitAction<MemberShape>, Self::Error> {
+        self.visited
+            .push(parent_shape_id.make_member(member.id().clone()).to_string());
+        if member.id().to_string() == "date" {
+            member.set_target(ShapeID::from_str("smithy.api#Timestamp").unwrap());
+        }
+        Ok(VisitAction::Keep)
+    }
+
+    fn applied_trait(
+        &mut self,
+        _: &ShapeID,
+        trait_id: &ShapeID,
+        value: &mut Option<Value>,
+    ) -> std::result::Result<VisitAction<Option<Value>>, Self::Error> {
+        match trait_id.shape_name().to_string().as_str() {
+            "required" => Ok(VisitAction::Remove),
+            "documentation" => match value {
+                Some(Value::String(s)) => {
+                    Ok(VisitAction::Replace(Some(Value::from(s.to_uppercase()))))
+                }
+                _ => Err(trait_id.to_string().into()),
+            },
+            _ => Ok(VisitAction::Keep),
+        }
+    }
+
+    fn added_shapes(&mut self) -> std::result::Result<Vec<TopLevelShape>, Self::Error> {
+        Ok(vec![TopLevelShape::new(
+            ShapeID::from_str("example.motd#Language").unwrap(),
+            ShapeKind::Simple(Simple::String),
+        )])
+    }
+}
+
+#[test]
+fn test_model_visitor_mut() {
+    let mut model = make_message_of_the_day_model().model;
+    let mut visitor = RewritingVisitor::default();
+    walk_model_mut(&mut model, &mut visitor).unwrap();
+
+    assert_eq!(
+        visitor.visited,
+        vec![
+            "example.motd#BadDateValue",
+            "example.motd#Date",
+            "example.motd#GetMessage",
+            "example.motd#GetMessageInput",
+            "example.motd#GetMessageInput$date",
+            "example.motd#GetMessageOutput",
+            "example.motd#GetMessageOutput$message",
+        ]
+    );
+    assert_eq!(
+        make_line_oriented_form(&model),
+        vec![
+            "operation::example.motd#FetchMessage",
+            "operation::example.motd#FetchMessage::input=>example.motd#GetMessageInput",
+            "operation::example.motd#FetchMessage::output=>example.motd#GetMessageOutput",
+            "operation::example.motd#FetchMessage::trait::smithy.api#readonly<={}",
+            "resource::example.motd#Message",
+            "resource::example.motd#Message::identifier::date=>example.motd#Date",
+            "resource::example.motd#Message::read=>example.motd#GetMessage",
+            "service::example.motd#MessageOfTheDay",
+            "service::example.motd#MessageOfTheDay::resource=>example.motd#Message",
+            "service::example.motd#MessageOfTheDay::trait::smithy.api#documentation<=\"PROVIDES A MESSAGE OF THE DAY.\"",
+            "service::example.motd#MessageOfTheDay::version<=\"2020-06-21\"",
+            "string::example.motd#Date",
+            "string::example.motd#Date::trait::smithy.api#documentation<=\"A DATE.\"",
+            "string::example.motd#Date::trait::smithy.api#pattern<=\"^\\d\\d\\d\\d\\-\\d\\d-\\d\\d$\"",
+            "string::example.motd#Language",
+            "structure::example.motd#GetMessageInput",
+            "structure::example.motd#GetMessageInput::date=>smithy.api#Timestamp",
+            "structure::example.motd#GetMessageOutput",
+            "structure::example.motd#GetMessageOutput::message=>smithy.api#String",
+        ]
+    );
+    assert!(model
+        .shape(&ShapeID::from_str("example.motd#Date").unwrap())
+        .unwrap()
+        .has_trait(&ShapeID::from_str("smithy.api#documentation").unwrap()));
+}
+
+struct ConflictingVisitor {
+    replace_members: bool,
+}
+
+impl ModelVisitorMut for ConflictingVisitor {
+    type Error = Error;
+
+    fn structure(
+        &mut self,
+        id: &ShapeID,
+        traits: &mut AppliedTraits,
+        structure: &mut StructureOrUnion,
+    ) -> std::result::Result<VisitAction<TopLevelShape>, Self::Error> {
+        if !self.replace_members && id.shape_name().to_string() == "GetMessageInput" {
+            Ok(VisitAction::Replace(TopLevelShape::with_traits(
+                ShapeID::from_str("example.motd#GetMessageOutput").unwrap(),
+                ShapeKind::Structure(structure.clone()),
+                traits.clone(),
+            )))
+        } else {
+            Ok(VisitAction::Keep)
+        }
+    }
+
+    fn member(
+        &mut self,
+        _: &ShapeID,
+        member: &mut MemberShape,
+    ) -> std::result::Result<VisitAction<MemberShape>, Self::Error> {
+        if self.replace_members && member.id().to_string() == "first" {
+            Ok(VisitAction::Replace(MemberShape::with_traits(
+                Identifier::from_str("second").unwrap(),
+                member.target().clone(),
+                member.traits().clone(),
+            )))
+        } else {
+            Ok(VisitAction::Keep)
+        }
+    }
+}
+
+#[test]
+fn test_model_visitor_mut_replace_conflict() {
+    let original = make_message_of_the_day_model().model;
+
+    let mut model = original.clone();
+    let mut visitor = ConflictingVisitor {
+        replace_members: false,
+    };
+    let result = walk_model_mut(&mut model, &mut visitor);
+    assert!(matches!(
+        result.unwrap_err().kind(),
+        ErrorKind::ReplacedShapeConflict(id) if id.to_string() == "example.motd#GetMessageOutput"
+    ));
+    assert_eq!(
+        make_line_oriented_form(&model),
+        make_line_oriented_form(&original)
+    );
+
+    let original: Model = ModelBuilder::new(Version::V10, "smithy.example")
+        .structure(
+            StructureBuilder::new("Pair")
+                .member("first", "String")
+                .member("second", "Integer")
+                .into(),
+        )
+        .try_into()
+        .unwrap();
+    let mut model = original.clone();
+    let mut visitor = ConflictingVisitor {
+        replace_members: true,
+    };
+    let result = walk_model_mut(&mut model, &mut visitor);
+    assert!(matches!(
+        result.unwrap_err().kind(),
+        ErrorKind::ReplacedShapeConflict(id) if id.to_string() == "smithy.example#Pair$second"
+    ));
+    assert_eq!(
+        make_line_oriented_form(&model),
+        make_line_oriented_form(&original)
+    );
+}
@@ -51,17 +51,18 @@ writer.write(&mut stdout(), &model).expect("Error writing model documentation");
 use atelier_core::error::Result as ModelResult;
 use atelier_core::io::ModelWriter;
 use atelier_core::model::shapes::{
-    HasTraits, ListOrSet, Map, Operation, Resource, Service, ShapeKind, StructureOrUnion,
-    TopLevelShape,
+    Enumeration, HasTraits, ListOrSet, Map, Operation, Resource, Service, ShapeKind,
+    StructureOrUnion, TopLevelShape,
 };
+use atelier_core::model::traits::{DocumentationTrait, ExternalDocumentationTrait};
 use atelier_core::model::values::Value;
 use atelier_core::model::{HasIdentity, Model, NamespaceID, ShapeID};
 use atelier_core::prelude::{
     prelude_namespace_id, PRELUDE_NAMESPACE, TRAIT_DOCUMENTATION, TRAIT_EXTERNALDOCUMENTATION,
 };
 use atelier_core::syntax::{
-    SHAPE_ID_MEMBER_SEPARATOR, SHAPE_LIST, SHAPE_MAP, SHAPE_OPERATION, SHAPE_RESOURCE,
-    SHAPE_SERVICE, SHAPE_SET, SHAPE_STRUCTURE, SHAPE_UNION,
+    SHAPE_ENUM, SHAPE_ID_MEMBER_SEPARATOR, SHAPE_INT_ENUM, SHAPE_LIST, SHAPE_MAP, SHAPE_OPERATION,
+    SHAPE_RESOURCE, SHAPE_SERVICE, SHAPE_SET, SHAPE_STRUCTURE, SHAPE_UNION,
 };
 use somedoc::model::block::{
     Caption, Cell, Column, HasBlockContent, HasLabel, Heading, Item, Label, List, Paragraph, Quote,
@@ -165,6 +166,8 @@ fn describe_shape(shape: &TopLevelShape, doc: &mut Document) {
         ShapeKind::Map(_) => SHAPE_MAP.to_string(),
         ShapeKind::Structure(_) => SHAPE_STRUCTURE.to_string(),
         ShapeKind::Union(_) => SHAPE_UNION.to_string(),
+        ShapeKind::Enum(_) => SHAPE_ENUM.to_string(),
+        ShapeKind::IntEnum(_) => SHAPE_INT_ENUM.to_string(),
         ShapeKind::Service(_) => SHAPE_SERVICE.to_string(),
         ShapeKind::Operation(_) => SHAPE_OPERATION.to_string(),
         ShapeKind::Resource(_) => SHAPE_RESOURCE.to_string(),
@@ -184,6 +187,8 @@ fn describe_shape(shape: &TopLevelShape, doc: &mut Document) {
         ShapeKind::Map(v) => describe_map(shape.id(), v, doc),
         ShapeKind::Structure(v) => describe_structure_or_union(shape.id(), v, doc),
         ShapeKind::Union(v) => describe_structure_or_union(shape.id(), v, doc),
+        ShapeKind::Enum(v) => describe_enumeration(v, false, doc),
+        ShapeKind::IntEnum(v) => describe_enumeration(v, true, doc),
         ShapeKind::Service(v) => describe_service(shape.id(), v, doc),
         ShapeKind::Operation(v) => describe_operation(shape.id(), v, doc),
         ShapeKind::Resource(v) => describe_resource(shape.id(), v, doc),
@@ -277,18 +282,16 @@ fn describe_traits(shape: &impl HasTraits, doc: &mut Document) {
 }
 
 fn describe_documentation(shape: &impl HasTraits, doc: &mut Document) {
-    let trait_id = ShapeID::new_unchecked(PRELUDE_NAMESPACE, TRAIT_DOCUMENTATION, None);
-    if let Some(Some(doc_value)) = shape.trait_named(&trait_id) {
-        let _ = doc.add_paragraph(Paragraph::plain_str(doc_value.as_string().unwrap()));
+    if let Ok(Some(documentation)) = shape.typed_trait::<DocumentationTrait>() {
+        let _ = doc.add_paragraph(Paragraph::plain_str(documentation.value()));
     }
 
-    let trait_id = ShapeID::new_unchecked(PRELUDE_NAMESPACE, TRAIT_EXTERNALDOCUMENTATION, None);
-    if let Some(Some(Value::Object(value_map))) = shape.trait_named(&trait_id) {
-        let mut links: Vec<InlineContent> = value_map
-            .iter()
+    if let Ok(Some(external)) = shape.typed_trait::<ExternalDocumentationTrait>() {
+        let mut links: Vec<InlineContent> = external
+            .links()
             .map(|(k, v)| {
                 InlineContent::HyperLink(HyperLink::external_with_caption(
-                    &v.to_string(),
+                    v,
                     Caption::from(k.to_string()),
                 ))
             })
@@ -368,6 +371,24 @@ fn describe_structure_or_union(shape_id: &ShapeID, shape: &StructureOrUnion, doc
     }
 }
 
+fn describe_enumeration(shape: &Enumeration, int_enum: bool, doc: &mut Document) {
+    if shape.has_members() {
+        let _ = doc.add_heading(Heading::sub_sub_section("Values"));
+        let mut indent = Quote::default();
+        for member in shape.members() {
+            let mut content: Vec<InlineContent> =
+                vec![Span::code_str(&member.id().to_string()).into()];
+            if let Some(value) = shape.member_value(member.id(), int_enum) {
+                content.push(Span::plain_str(" = ").into());
+                content.push(Span::code_str(&value.to_string()).into());
+            }
+            let _ = indent.add_paragraph(Paragraph::from(content));
+            describe_documentation(member, doc);
+        }
+        let _ = doc.add_block_quote(indent);
+    }
+}
+
 fn describe_service(shape_id: &ShapeID, shape: &Service, doc: &mut Document) {
     let _ = doc.add_paragraph(Paragraph::from(vec![
         Span::bold_str("Service version").into(),
@@ -25,6 +25,7 @@ The mapping is reasonably simple, but also effective.
    of precedence:  `«error»` if it has the error trait applied, `«union»` if it is a union shape
    or none,
    1. members of the shape are modeled as UML fields on the class.
+1. Each enum and intEnum shape is modeled as a UML enum, with each member shown with its value.
 1. Each simple shape is modeled as a `«dataType»` class,
    1. `member` for list and set as well as the `key` and `value` for a map are modeled as
       association relationships to the relevant types,
@@ -108,7 +109,7 @@ Which would produce an image like the following.
 
 use atelier_core::io::ModelWriter;
 use atelier_core::model::shapes::HasTraits;
-use atelier_core::model::shapes::{ShapeKind, TopLevelShape};
+use atelier_core::model::shapes::{EnumValue, ShapeKind, TopLevelShape};
 use atelier_core::model::values::Value;
 use atelier_core::model::HasIdentity;
 use atelier_core::model::{Model, NamespaceID, ShapeID};
@@ -175,6 +176,9 @@ impl ModelWriter for PlantUmlWriter {
                     ShapeKind::Structure(_) | ShapeKind::Union(_) => {
                         self.write_class(w, element, model)?
                     }
+                    ShapeKind::Enum(_) | ShapeKind::IntEnum(_) => {
+                        self.write_enum(w, element, model)?
+                    }
                     _ => {}
                 }
             }
@@ -331,6 +335,31 @@ impl PlantUmlWriter {
         Ok(())
     }
 
+    fn write_enum(
+        &self,
+        w: &mut impl Write,
+        enumeration: &TopLevelShape,
+        model: &Model,
+    ) -> atelier_core::error::Result<()> {
+        let body = match enumeration.body() {
+            ShapeKind::Enum(e) | ShapeKind::IntEnum(e) => e,
+            _ => unreachable!(),
+        };
+        writeln!(w, "    enum {} {{", enumeration.id())?;
+        let notes = self.write_class_traits(w, enumeration, model)?;
+        for member in body.members() {
+            match body.member_value(member.id(), enumeration.is_int_enum()) {
+                Some(EnumValue::String(v)) => writeln!(w, "        {} = \"{}\"", member.id(), v)?,
+                Some(EnumValue::Integer(v)) => writeln!(w, "        {} = {}", member.id(), v)?,
+                None => writeln!(w, "        {}", member.id())?,
+            }
+        }
+        writeln!(w, "    }}")?;
+        self.write_class_notes(w, enumeration.id(), notes)?;
+        writeln!(w)?;
+        Ok(())
+    }
+
     fn write_data_type(
         &self,
         w: &mut impl Write,
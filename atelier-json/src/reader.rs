@@ -4,20 +4,23 @@ use crate::REPRESENTATION_NAME;
 use atelier_core::error::{ErrorKind, Result as ModelResult, ResultExt};
 use atelier_core::io::ModelReader;
 use atelier_core::model::shapes::{
-    AppliedTraits, HasTraits, ListOrSet, Map as MapShape, MemberShape, Operation, Resource,
-    Service, ShapeKind, Simple, StructureOrUnion, TopLevelShape,
+    AppliedTraits, Enumeration, HasTraits, ListOrSet, Map as MapShape, MemberShape, Operation,
+    Resource, Service, ShapeKind, Simple, StructureOrUnion, TopLevelShape,
+};
+use atelier_core::model::values::{Number, Value as NodeValue, ValueMap};
+use atelier_core::model::{
+    HasIdentity, HasSourceLocation, Identifier, Model, NamespaceID, ShapeID, SourceLocation,
 };
-use atelier_core::model::values::{Value as NodeValue, ValueMap};
-use atelier_core::model::{Identifier, Model, NamespaceID, ShapeID};
 use atelier_core::syntax::{
     MEMBER_COLLECTION_OPERATIONS, MEMBER_CREATE, MEMBER_DELETE, MEMBER_ERRORS, MEMBER_IDENTIFIERS,
     MEMBER_INPUT, MEMBER_KEY, MEMBER_LIST, MEMBER_MEMBER, MEMBER_OPERATIONS, MEMBER_OUTPUT,
     MEMBER_PUT, MEMBER_READ, MEMBER_RENAME, MEMBER_RESOURCES, MEMBER_UPDATE, MEMBER_VALUE,
-    MEMBER_VERSION, MODEL_METADATA, MODEL_SHAPES, SHAPE_APPLY, SHAPE_LIST, SHAPE_MAP,
-    SHAPE_OPERATION, SHAPE_RESOURCE, SHAPE_SERVICE, SHAPE_SET, SHAPE_STRUCTURE, SHAPE_UNION,
+    MEMBER_VERSION, MODEL_METADATA, MODEL_SHAPES, SHAPE_APPLY, SHAPE_ENUM, SHAPE_INT_ENUM,
+    SHAPE_LIST, SHAPE_MAP, SHAPE_OPERATION, SHAPE_RESOURCE, SHAPE_SERVICE, SHAPE_SET,
+    SHAPE_STRUCTURE, SHAPE_UNION,
 };
 use atelier_core::Version;
-use serde_json::{from_reader, Map, Value};
+use serde_json::{from_str, Map, Value};
 use std::collections::HashMap;
 use std::io::Read;
 use std::str::FromStr;
@@ -41,6 +44,12 @@ use std::str::FromStr;
 #[allow(missing_debug_implementations)]
 pub struct JsonReader;
 
+///
+/// The location of each object key in the JSON source, keyed by the path of object keys from the
+/// root of the document; array elements are denoted by the path element `"[]"`.
+///
+type KeyLocations = HashMap<Vec<String>, SourceLocation>;
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -53,7 +62,9 @@ impl<'a> Default for JsonReader {
 
 impl ModelReader for JsonReader {
     fn read(&mut self, r: &mut impl Read) -> ModelResult<Model> {
-        let json: Value = from_reader(r).chain_err(|| {
+        let mut content = String::new();
+        let _ = r.read_to_string(&mut content)?;
+        let json: Value = from_str(&content).chain_err(|| {
             ErrorKind::Deserialization(
                 REPRESENTATION_NAME.to_string(),
                 "ModelReader::read".to_string(),
@@ -61,12 +72,12 @@ impl ModelReader for JsonReader {
             )
             .to_string()
         })?;
-        self.model(json)
+        self.model(json, &key_locations(&content))
     }
 }
 
 impl JsonReader {
-    fn model(&self, json: Value) -> ModelResult<Model> {
+    fn model(&self, json: Value, locations: &KeyLocations) -> ModelResult<Model> {
         if let Value::Object(vs) = json {
             let version = self.version(vs.get(ADD_MODEL_SMITHY_VERSION))?;
             let mut model = Model::new(version);
@@ -77,7 +88,8 @@ impl JsonReader {
             }
 
             let shapes = self.shapes(vs.get(MODEL_SHAPES))?;
-            for shape in shapes.into_iter().map(|(_, s)| s) {
+            for mut shape in shapes.into_iter().map(|(_, s)| s) {
+                locate_shape(&mut shape, locations);
                 model.add_shape(shape)?;
             }
 
@@ -126,6 +138,10 @@ impl JsonReader {
                     shape.append_traits(&self.traits(vs)?)?;
                 };
 
+                for mixin in self.target_list(v.get(ADD_SHAPE_KEY_MIXINS))? {
+                    shape.add_mixin(mixin);
+                }
+
                 shapes.push((id.namespace().clone(), shape))
             }
         }
@@ -170,6 +186,18 @@ impl JsonReader {
                 Ok(ShapeKind::Union(StructureOrUnion::with_members(
                     members.as_slice(),
                 )))
+            } else if s == SHAPE_ENUM || s == SHAPE_INT_ENUM {
+                let members = if let Some(Value::Object(vs)) = outer.get(ADD_SHAPE_KEY_MEMBERS) {
+                    self.members(vs)?
+                } else {
+                    Default::default()
+                };
+                let body = Enumeration::with_members(members.as_slice());
+                Ok(if s == SHAPE_ENUM {
+                    ShapeKind::Enum(body)
+                } else {
+                    ShapeKind::IntEnum(body)
+                })
             } else if s == SHAPE_SERVICE {
                 let version = if let Some(Value::String(value)) = outer.get(MEMBER_VERSION) {
                     value.clone()
@@ -407,22 +435,7 @@ impl JsonReader {
         match json {
             Value::Null => Ok(NodeValue::None),
             Value::Bool(v) => Ok(NodeValue::from(*v)),
-            Value::Number(v) => {
-                if v.is_f64() {
-                    Ok(NodeValue::from(v.as_f64().unwrap()))
-                } else if v.is_i64() {
-                    Ok(NodeValue::from(v.as_i64().unwrap()))
-                } else if v.is_u64() {
-                    Ok(NodeValue::from(v.as_u64().unwrap() as i64))
-                } else {
-                    Err(ErrorKind::Deserialization(
-                        REPRESENTATION_NAME.to_string(),
-                        "JsonReader::value".to_string(),
-                        Some(format!("{:#?}", json)),
-                    )
-                    .into())
-                }
-            }
+            Value::Number(v) => Ok(NodeValue::Number(Number::from_str(&v.to_string())?)),
             Value::String(v) => Ok(NodeValue::from(v.to_string())),
             Value::Array(vs) => {
                 let result: ModelResult<Vec<NodeValue>> =
@@ -443,6 +456,144 @@ impl JsonReader {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn locate_shape(shape: &mut TopLevelShape, locations: &KeyLocations) {
+    let path = vec![MODEL_SHAPES.to_string(), shape.id().to_string()];
+    locate_element(shape, &path, locations);
+    let member_path = |name: &str| {
+        let mut path = path.clone();
+        path.push(name.to_string());
+        path
+    };
+    match shape.body_mut() {
+        ShapeKind::List(body) | ShapeKind::Set(body) => {
+            let mut member = body.member().clone();
+            locate_element(&mut member, &member_path(MEMBER_MEMBER), locations);
+            body.set_member(member);
+        }
+        ShapeKind::Map(body) => {
+            let mut key = body.key().clone();
+            locate_element(&mut key, &member_path(MEMBER_KEY), locations);
+            body.set_key(key);
+            let mut value = body.value().clone();
+            locate_element(&mut value, &member_path(MEMBER_VALUE), locations);
+            body.set_value(value);
+        }
+        ShapeKind::Structure(body) | ShapeKind::Union(body) => {
+            let members: Vec<MemberShape> = body.members().cloned().collect();
+            for mut member in members {
+                let mut path = member_path(ADD_SHAPE_KEY_MEMBERS);
+                path.push(member.id().to_string());
+                locate_element(&mut member, &path, locations);
+                let _ = body.add_a_member(member);
+            }
+        }
+        ShapeKind::Enum(body) | ShapeKind::IntEnum(body) => {
+            let members: Vec<MemberShape> = body.members().cloned().collect();
+            for mut member in members {
+                let mut path = member_path(ADD_SHAPE_KEY_MEMBERS);
+                path.push(member.id().to_string());
+                locate_element(&mut member, &path, locations);
+                let _ = body.add_a_member(member);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn locate_element(
+    element: &mut (impl HasTraits + HasSourceLocation),
+    path: &[String],
+    locations: &KeyLocations,
+) {
+    if let Some(location) = locations.get(path) {
+        element.set_location(location.clone());
+    }
+    let trait_ids: Vec<ShapeID> = element.traits().keys().cloned().collect();
+    for id in trait_ids {
+        let mut path = path.to_vec();
+        path.push(ADD_SHAPE_KEY_TRAITS.to_string());
+        path.push(id.to_string());
+        if let Some(location) = locations.get(&path) {
+            element.set_trait_location(id, location.clone());
+        }
+    }
+}
+
+///
+/// As `serde_json` does not retain the position of values this performs a simple scan of the JSON
+/// source, which is assumed to be well-formed, to record the line and column of every object key.
+///
+fn key_locations(json: &str) -> KeyLocations {
+    // Each entry is `Some(key)` for an object, where `key` is the most recent key seen in that
+    // object, or `None` for an array.
+    let mut containers: Vec<Option<Option<String>>> = Default::default();
+    let mut expecting_key = false;
+    let mut locations: KeyLocations = Default::default();
+    let (mut line, mut column) = (1, 0);
+    let mut chars = json.chars();
+    while let Some(c) = chars.next() {
+        column += 1;
+        match c {
+            '\n' => {
+                line += 1;
+                column = 0;
+            }
+            '{' => {
+                containers.push(Some(None));
+                expecting_key = true;
+            }
+            '[' => {
+                containers.push(None);
+                expecting_key = false;
+            }
+            '}' | ']' => {
+                let _ = containers.pop();
+                expecting_key = false;
+            }
+            ',' => {
+                expecting_key = matches!(containers.last(), Some(Some(_)));
+            }
+            '"' => {
+                let location = SourceLocation::new(line, column);
+                let mut string = String::new();
+                while let Some(c) = chars.next() {
+                    column += 1;
+                    match c {
+                        '"' => break,
+                        '\\' => {
+                            if let Some(c) = chars.next() {
+                                column += 1;
+                                string.push(c);
+                            }
+                        }
+                        _ => string.push(c),
+                    }
+                }
+                if expecting_key {
+                    if let Some(Some(key)) = containers.last_mut() {
+                        *key = Some(string);
+                    }
+                    let path: Vec<String> = containers
+                        .iter()
+                        .map(|container| match container {
+                            Some(Some(key)) => key.clone(),
+                            _ => "[]".to_string(),
+                        })
+                        .collect();
+                    let _ = locations.insert(path, location);
+                    expecting_key = false;
+                }
+            }
+            _ => {}
+        }
+    }
+    locations
+}
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
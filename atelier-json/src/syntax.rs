@@ -19,3 +19,5 @@ pub(crate) const ADD_SHAPE_KEY_MEMBERS: &str = "members";
 pub(crate) const ADD_SHAPE_KEY_TARGET: &str = "target";
 
 pub(crate) const ADD_SHAPE_KEY_TRAITS: &str = "traits";
+
+pub(crate) const ADD_SHAPE_KEY_MIXINS: &str = "mixins";
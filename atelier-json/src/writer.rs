@@ -3,7 +3,7 @@ use crate::FILE_EXTENSION;
 use atelier_core::error::{ErrorKind, Result as ModelResult, ResultExt};
 use atelier_core::io::ModelWriter;
 use atelier_core::model::shapes::{
-    AppliedTraits, HasTraits, MemberShape, ShapeKind, TopLevelShape,
+    AppliedTraits, HasTraits, MemberShape, ShapeKind, TopLevelShape, TraitValue,
 };
 use atelier_core::model::values::{Number, Value as NodeValue};
 use atelier_core::model::{HasIdentity, Model, ShapeID};
@@ -11,11 +11,13 @@ use atelier_core::syntax::{
     MEMBER_COLLECTION_OPERATIONS, MEMBER_CREATE, MEMBER_DELETE, MEMBER_ERRORS, MEMBER_IDENTIFIERS,
     MEMBER_INPUT, MEMBER_KEY, MEMBER_LIST, MEMBER_MEMBER, MEMBER_OPERATIONS, MEMBER_OUTPUT,
     MEMBER_PUT, MEMBER_READ, MEMBER_RENAME, MEMBER_RESOURCES, MEMBER_UPDATE, MEMBER_VALUE,
-    MEMBER_VERSION, MODEL_METADATA, MODEL_SHAPES, SHAPE_APPLY, SHAPE_LIST, SHAPE_MAP,
-    SHAPE_OPERATION, SHAPE_RESOURCE, SHAPE_SERVICE, SHAPE_SET, SHAPE_STRUCTURE, SHAPE_UNION,
+    MEMBER_VERSION, MODEL_METADATA, MODEL_SHAPES, SHAPE_APPLY, SHAPE_ENUM, SHAPE_INT_ENUM,
+    SHAPE_LIST, SHAPE_MAP, SHAPE_OPERATION, SHAPE_RESOURCE, SHAPE_SERVICE, SHAPE_SET,
+    SHAPE_STRUCTURE, SHAPE_UNION,
 };
 use serde_json::{to_writer, to_writer_pretty, Map, Number as JsonNumber, Value};
 use std::io::Write;
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -109,8 +111,10 @@ impl<'a> JsonWriter {
 // ------------------------------------------------------------------------------------------------
 
 fn from_shapes(model: &Model) -> Value {
+    let mut shapes: Vec<&TopLevelShape> = model.shapes().collect();
+    shapes.sort_by_key(|shape| shape.id());
     let mut shape_map: Map<String, Value> = Default::default();
-    for shape in model.shapes() {
+    for shape in shapes {
         let _ = shape_map.insert(shape.id().to_string(), from_shape(shape));
     }
     Value::Object(shape_map)
@@ -118,12 +122,6 @@ fn from_shapes(model: &Model) -> Value {
 
 fn from_shape(shape: &TopLevelShape) -> Value {
     let mut shape_map: Map<String, Value> = Default::default();
-    if shape.has_traits() {
-        let _ = shape_map.insert(
-            ADD_SHAPE_KEY_TRAITS.to_string(),
-            from_traits(shape.traits()),
-        );
-    }
     match shape.body() {
         ShapeKind::Simple(v) => {
             let _ = shape_map.insert(ADD_SHAPE_KEY_TYPE.to_string(), Value::String(v.to_string()));
@@ -170,6 +168,26 @@ fn from_shape(shape: &TopLevelShape) -> Value {
                     shape_map.insert(ADD_SHAPE_KEY_MEMBERS.to_string(), from_members(v.members()));
             }
         }
+        ShapeKind::Enum(v) => {
+            let _ = shape_map.insert(
+                ADD_SHAPE_KEY_TYPE.to_string(),
+                Value::String(SHAPE_ENUM.to_string()),
+            );
+            if v.has_members() {
+                let _ =
+                    shape_map.insert(ADD_SHAPE_KEY_MEMBERS.to_string(), from_members(v.members()));
+            }
+        }
+        ShapeKind::IntEnum(v) => {
+            let _ = shape_map.insert(
+                ADD_SHAPE_KEY_TYPE.to_string(),
+                Value::String(SHAPE_INT_ENUM.to_string()),
+            );
+            if v.has_members() {
+                let _ =
+                    shape_map.insert(ADD_SHAPE_KEY_MEMBERS.to_string(), from_members(v.members()));
+            }
+        }
         ShapeKind::Service(v) => {
             let _ = shape_map.insert(
                 ADD_SHAPE_KEY_TYPE.to_string(),
@@ -193,7 +211,9 @@ fn from_shape(shape: &TopLevelShape) -> Value {
             }
             if v.has_renames() {
                 let mut rename_map: Map<String, Value> = Default::default();
-                for (k, v) in v.renames() {
+                let mut renames: Vec<_> = v.renames().collect();
+                renames.sort();
+                for (k, v) in renames {
                     let _ = rename_map.insert(k.to_string(), Value::String(v.to_string()));
                 }
                 let _ = shape_map.insert(MEMBER_RENAME.to_string(), Value::Object(rename_map));
@@ -224,7 +244,9 @@ fn from_shape(shape: &TopLevelShape) -> Value {
             );
             if v.has_identifiers() {
                 let mut id_map: Map<String, Value> = Default::default();
-                for (id, ref_id) in v.identifiers() {
+                let mut identifiers: Vec<_> = v.identifiers().collect();
+                identifiers.sort();
+                for (id, ref_id) in identifiers {
                     let _ = id_map.insert(id.to_string(), from_reference(ref_id));
                 }
                 let _ = shape_map.insert(MEMBER_IDENTIFIERS.to_string(), Value::Object(id_map));
@@ -277,10 +299,24 @@ fn from_shape(shape: &TopLevelShape) -> Value {
             );
         }
     }
+    if shape.has_mixins() {
+        let _ = shape_map.insert(
+            ADD_SHAPE_KEY_MIXINS.to_string(),
+            Value::Array(shape.mixins().map(from_reference).collect()),
+        );
+    }
+    if shape.has_traits() {
+        let _ = shape_map.insert(
+            ADD_SHAPE_KEY_TRAITS.to_string(),
+            from_traits(shape.traits()),
+        );
+    }
     Value::Object(shape_map)
 }
 
 fn from_traits(traits: &AppliedTraits) -> Value {
+    let mut traits: Vec<(&ShapeID, &TraitValue)> = traits.iter().collect();
+    traits.sort_by_key(|(id, _)| *id);
     let mut trait_map: Map<String, Value> = Default::default();
     for (id, value) in traits {
         let _ = trait_map.insert(
@@ -295,6 +331,8 @@ fn from_traits(traits: &AppliedTraits) -> Value {
 }
 
 fn from_members<'a>(members: impl Iterator<Item = &'a MemberShape>) -> Value {
+    let mut members: Vec<&MemberShape> = members.collect();
+    members.sort_by_key(|member| member.id());
     let mut members_map: Map<String, Value> = Default::default();
     for member in members {
         let _ = members_map.insert(member.id().to_string(), from_member(member));
@@ -331,6 +369,9 @@ fn from_value(value: &NodeValue) -> Value {
         NodeValue::Number(v) => match v {
             Number::Integer(v) => Value::Number((*v).into()),
             Number::Float(v) => Value::Number(JsonNumber::from_f64(*v).unwrap()),
+            Number::BigInteger(v) | Number::BigDecimal(v) => {
+                Value::Number(JsonNumber::from_str(v).unwrap())
+            }
         },
         NodeValue::Boolean(v) => Value::Bool(*v),
         NodeValue::String(v) => Value::String(v.clone()),
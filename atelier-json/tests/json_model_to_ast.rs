@@ -15,6 +15,20 @@ fn model_to_json_ex_17_2_1() {
     )
 }
 
+#[test]
+fn model_to_json_version_2() {
+    parse_and_write_json(
+        r#"{
+    "smithy": "2.0",
+    "shapes": {
+        "smithy.example#MyString": {
+            "type": "string"
+        }
+    }
+}"#,
+    )
+}
+
 #[test]
 fn model_to_json_ex_17_2_3() {
     parse_and_write_json(
@@ -377,3 +391,38 @@ fn model_to_json_ex_17_12() {
 }"#,
     )
 }
+
+#[test]
+fn model_to_json_mixins() {
+    parse_and_write_json(
+        r#"{
+    "smithy": "2.0",
+    "shapes": {
+        "smithy.example#PaginatedInput": {
+            "type": "structure",
+            "members": {
+                "nextToken": {
+                    "target": "smithy.api#String"
+                }
+            },
+            "traits": {
+                "smithy.api#mixin": {}
+            }
+        },
+        "smithy.example#ListThingsInput": {
+            "type": "structure",
+            "mixins": [
+                {
+                    "target": "smithy.example#PaginatedInput"
+                }
+            ],
+            "members": {
+                "filter": {
+                    "target": "smithy.api#String"
+                }
+            }
+        }
+    }
+}"#,
+    )
+}
@@ -1,4 +1,5 @@
 use atelier_core::model::Model;
+use atelier_core::Version;
 use atelier_json::JsonReader;
 use atelier_test::{parse_and_compare_model, parts as model_part};
 
@@ -11,6 +12,12 @@ fn empty_file() {
     model_test(r##"{"smithy": "1"}"##, Model::default())
 }
 
+#[test]
+fn version_2_file() {
+    model_test(r##"{"smithy": "2"}"##, Model::new(Version::V20));
+    model_test(r##"{"smithy": "2.0"}"##, Model::new(Version::V20))
+}
+
 #[test]
 fn namespace_only() {
     model_test(r##"{"smithy": "1"}"##, model_part::namespace_only().model)
@@ -159,3 +166,110 @@ fn service_with_renames() {
         model_part::service_with_renames().model,
     )
 }
+
+#[test]
+fn enum_shape() {
+    model_test(
+        r##"{
+    "smithy": "2",
+    "shapes": {
+        "smithy.example#Suit": {
+            "type": "enum",
+            "members": {
+                "CLUB": {
+                    "target": "smithy.api#Unit",
+                    "traits": {
+                        "smithy.api#enumValue": "club"
+                    }
+                },
+                "DIAMOND": {
+                    "target": "smithy.api#Unit",
+                    "traits": {
+                        "smithy.api#documentation": "Red.",
+                        "smithy.api#enumValue": "diamond"
+                    }
+                },
+                "HEART": {
+                    "target": "smithy.api#Unit",
+                    "traits": {
+                        "smithy.api#enumValue": "HEART"
+                    }
+                }
+            }
+        }
+    }
+}"##,
+        model_part::enum_shape().model,
+    )
+}
+
+#[test]
+fn int_enum_shape() {
+    model_test(
+        r##"{
+    "smithy": "2",
+    "shapes": {
+        "smithy.example#FaceCard": {
+            "type": "intEnum",
+            "members": {
+                "JACK": {
+                    "target": "smithy.api#Unit",
+                    "traits": {
+                        "smithy.api#enumValue": 11
+                    }
+                },
+                "QUEEN": {
+                    "target": "smithy.api#Unit",
+                    "traits": {
+                        "smithy.api#enumValue": 12
+                    }
+                },
+                "KING": {
+                    "target": "smithy.api#Unit",
+                    "traits": {
+                        "smithy.api#enumValue": 13
+                    }
+                }
+            }
+        }
+    }
+}"##,
+        model_part::int_enum_shape().model,
+    )
+}
+
+#[test]
+fn structure_with_mixins() {
+    model_test(
+        r##"{
+    "smithy": "2",
+    "shapes": {
+        "smithy.example#PaginatedInput": {
+            "type": "structure",
+            "members": {
+                "nextToken": {
+                    "target": "smithy.api#String"
+                }
+            },
+            "traits": {
+                "smithy.api#mixin": {}
+            }
+        },
+        "smithy.example#ListThingsInput": {
+            "type": "structure",
+            "mixins": [
+                {
+                    "target": "smithy.example#PaginatedInput"
+                }
+            ],
+            "members": {
+                "filter": {
+                    "target": "smithy.api#String"
+                }
+            }
+        }
+    }
+}"##,
+        model_part::structure_with_mixins().model,
+    )
+}
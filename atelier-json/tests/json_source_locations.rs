@@ -0,0 +1,74 @@
+use atelier_core::io::read_model_from_string;
+use atelier_core::model::{HasSourceLocation, Identifier, ShapeID, SourceLocation};
+use atelier_core::prelude::{prelude_shape_named, TRAIT_DOCUMENTATION, TRAIT_REQUIRED};
+use atelier_json::JsonReader;
+use std::str::FromStr;
+
+const MODEL: &str = r##"{
+    "smithy": "1.0",
+    "shapes": {
+        "smithy.example#MyString": {
+            "type": "string",
+            "traits": {
+                "smithy.api#documentation": "My \"documentation\" string"
+            }
+        },
+        "smithy.example#MyList": {
+            "type": "list",
+            "member": {
+                "target": "smithy.example#MyString"
+            }
+        },
+        "smithy.example#MyStructure": {
+            "type": "structure",
+            "members": {
+                "name": {
+                    "target": "smithy.example#MyString",
+                    "traits": { "smithy.api#required": {} }
+                }
+            }
+        }
+    }
+}"##;
+
+fn shape_id(shape_name: &str) -> ShapeID {
+    ShapeID::new_unchecked("smithy.example", shape_name, None)
+}
+
+#[test]
+fn shape_and_trait_locations() {
+    let model = read_model_from_string(&mut JsonReader, MODEL).unwrap();
+
+    let shape = model.shape(&shape_id("MyString")).unwrap();
+    assert_eq!(shape.location(), &Some(SourceLocation::new(4, 9)));
+    assert_eq!(
+        shape.trait_location(&prelude_shape_named(TRAIT_DOCUMENTATION).unwrap()),
+        Some(&SourceLocation::new(7, 17))
+    );
+
+    assert_eq!(
+        model.location_of(&shape_id("MyStructure")),
+        Some(&SourceLocation::new(16, 9))
+    );
+}
+
+#[test]
+fn member_locations() {
+    let model = read_model_from_string(&mut JsonReader, MODEL).unwrap();
+
+    let shape = model.shape(&shape_id("MyList")).unwrap();
+    let member = shape
+        .member(&Identifier::from_str("member").unwrap())
+        .unwrap();
+    assert_eq!(member.location(), &Some(SourceLocation::new(12, 13)));
+
+    let shape = model.shape(&shape_id("MyStructure")).unwrap();
+    let member = shape
+        .member(&Identifier::from_str("name").unwrap())
+        .unwrap();
+    assert_eq!(member.location(), &Some(SourceLocation::new(19, 17)));
+    assert_eq!(
+        member.trait_location(&prelude_shape_named(TRAIT_REQUIRED).unwrap()),
+        Some(&SourceLocation::new(21, 33))
+    );
+}
@@ -0,0 +1,64 @@
+use atelier_core::io::{read_model_from_string, write_model_to_string};
+use atelier_json::{JsonReader, JsonWriter};
+
+const MODEL: &str = r##"{
+    "smithy": "2.0",
+    "metadata": {
+        "zulu": 1,
+        "alpha": 2
+    },
+    "shapes": {
+        "example.values#Huge": {
+            "type": "bigInteger",
+            "traits": {
+                "smithy.api#range": {
+                    "min": -170141183460469231731687303715884105728,
+                    "max": 170141183460469231731687303715884105727
+                }
+            }
+        },
+        "example.values#Precise": {
+            "type": "bigDecimal",
+            "traits": {
+                "smithy.api#range": {
+                    "min": 0.1000000000000000000000000000001,
+                    "max": 1.5
+                }
+            }
+        },
+        "example.values#Ordered": {
+            "type": "string",
+            "traits": {
+                "example.values#custom": {
+                    "zulu": "z",
+                    "alpha": "a",
+                    "mike": "m"
+                }
+            }
+        }
+    }
+}"##;
+
+fn round_trip() -> String {
+    let model = read_model_from_string(&mut JsonReader, MODEL).unwrap();
+    write_model_to_string(&mut JsonWriter::default(), &model).unwrap()
+}
+
+#[test]
+fn test_numbers_are_lossless() {
+    let output = round_trip();
+    assert!(output.contains(r#""min":-170141183460469231731687303715884105728"#));
+    assert!(output.contains(r#""max":170141183460469231731687303715884105727"#));
+    assert!(output.contains(r#""min":0.1000000000000000000000000000001"#));
+    assert!(output.contains(r#""max":1.5"#));
+}
+
+#[test]
+fn test_output_is_reproducible() {
+    let output = round_trip();
+    assert!(output.contains(r#""metadata":{"zulu":1,"alpha":2}"#));
+    assert!(output.contains(r#"{"zulu":"z","alpha":"a","mike":"m"}"#));
+    for _ in 0..10 {
+        assert_eq!(round_trip(), output);
+    }
+}
@@ -21,6 +21,7 @@ default feature, although the core will be included regardless of any feature se
 | "openapi"    | No      | [atelier_openapi](https://docs.rs/atelier_openapi)   | `::format::openapi`    | Reading and Writing OpenAPI representations.          |
 | "rdf"        | No      | [atelier_rdf](https://docs.rs/atelier_rdf)           | `::format::rdf`        | Reading and Writing RDF representations.              |
 | "smithy"     | **Yes** | [atelier_smithy](https://docs.rs/atelier_smithy)     | `::format::smithy`     | Reading and Writing the Smithy native representation. |
+| "serde"      | No      | [atelier_core](https://docs.rs/atelier_core)         | `::core`               | Serde support for the semantic model types.           |
 
 This crate also provides some pre-defined [action](actions/index.html) functions for linting and
 validating models.
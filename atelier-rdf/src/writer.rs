@@ -63,13 +63,14 @@ use atelier_core::model::values::{Number, Value};
 use atelier_core::model::visitor::{walk_model, ModelVisitor};
 use atelier_core::model::{HasIdentity, Model, ShapeID};
 use rdftk_core::model::graph::{Graph, GraphFactoryRef, GraphRef};
+use rdftk_core::model::literal::DataType;
 use rdftk_core::model::statement::SubjectNodeRef;
 use rdftk_core::simple::graph_factory;
 use rdftk_io::turtle::writer::TurtleWriter;
 use rdftk_io::turtle::NAME;
 use rdftk_io::GraphWriter;
 use rdftk_iri::{IRIRef, IRI};
-use rdftk_names::rdf;
+use rdftk_names::{rdf, xsd};
 use std::cell::RefMut;
 use std::io::Write;
 use std::str::FromStr;
@@ -804,6 +805,34 @@ fn from_value(
                         .unwrap(),
                 );
             }
+            Number::BigInteger(v) => {
+                graph.insert(
+                    statement_factory
+                        .statement(
+                            subject,
+                            predicate,
+                            statement_factory.literal_object(
+                                literal_factory
+                                    .with_data_type(v, DataType::Other(xsd::integer().clone())),
+                            ),
+                        )
+                        .unwrap(),
+                );
+            }
+            Number::BigDecimal(v) => {
+                graph.insert(
+                    statement_factory
+                        .statement(
+                            subject,
+                            predicate,
+                            statement_factory.literal_object(
+                                literal_factory
+                                    .with_data_type(v, DataType::Other(xsd::decimal().clone())),
+                            ),
+                        )
+                        .unwrap(),
+                );
+            }
         },
         Value::Boolean(v) => {
             graph.insert(
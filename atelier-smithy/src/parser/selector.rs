@@ -221,16 +221,7 @@ fn parse_selector_value(input_pair: Pair<'_, Rule>) -> ModelResult<Value> {
             let text = inner.as_str();
             Ok(Value::Text((&text[1..text.len() - 1]).to_string()))
         }
-        Rule::number => {
-            let number = inner.as_str();
-            if number.contains('.') {
-                Ok(Value::Number(Number::Float(f64::from_str(number).unwrap())))
-            } else {
-                Ok(Value::Number(Number::Integer(
-                    i64::from_str(number).unwrap(),
-                )))
-            }
-        }
+        Rule::number => Ok(Value::Number(Number::from_str(inner.as_str())?)),
         Rule::root_shape_id => {
             let inner = inner.into_inner().next().unwrap();
             match inner.as_rule() {
@@ -3,25 +3,27 @@
 use crate::parser::error::ParserError;
 use crate::REPRESENTATION_NAME;
 use atelier_core::builder::shapes::ShapeTraits;
+use atelier_core::builder::traits::documentation as documentation_trait;
 use atelier_core::builder::{
-    ListBuilder, MapBuilder, MemberBuilder, ModelBuilder, OperationBuilder, ReferenceBuilder,
-    ResourceBuilder, ServiceBuilder, SimpleShapeBuilder, StructureBuilder, TraitBuilder,
+    EnumBuilder, ListBuilder, MapBuilder, MemberBuilder, ModelBuilder, OperationBuilder,
+    ReferenceBuilder, ResourceBuilder, ServiceBuilder, SimpleShapeBuilder, StructureBuilder,
+    TraitBuilder,
 };
-use atelier_core::error::{Error, ErrorKind, Result as ModelResult, ResultExt};
-use atelier_core::model::shapes::Simple;
+use atelier_core::error::{Error, ErrorKind, Result as ModelResult};
+use atelier_core::model::shapes::{EnumValue, Simple};
 use atelier_core::model::values::{Number, Value as NodeValue, ValueMap};
-use atelier_core::model::{Identifier, Model};
+use atelier_core::model::{Identifier, Model, ShapeID, SourceLocation};
 use atelier_core::syntax::{
     MEMBER_COLLECTION_OPERATIONS, MEMBER_CREATE, MEMBER_DELETE, MEMBER_ERRORS, MEMBER_IDENTIFIERS,
     MEMBER_INPUT, MEMBER_KEY, MEMBER_LIST, MEMBER_MEMBER, MEMBER_OPERATIONS, MEMBER_OUTPUT,
-    MEMBER_PUT, MEMBER_READ, MEMBER_RESOURCES, MEMBER_UPDATE, MEMBER_VALUE, MEMBER_VERSION,
+    MEMBER_PUT, MEMBER_READ, MEMBER_RENAME, MEMBER_RESOURCES, MEMBER_UPDATE, MEMBER_VALUE,
+    MEMBER_VERSION,
 };
 use atelier_core::Version;
 use pest::error::Error as PestError;
 use pest::iterators::Pair;
 use pest::Parser;
-use std::collections::HashMap;
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use std::io::Write;
 use std::str::FromStr;
 
@@ -33,6 +35,15 @@ use std::str::FromStr;
 #[grammar = "smithy.pest"]
 struct SmithyParser;
 
+///
+/// The lines of documentation comments preceding a shape, or member, and the location of the first.
+///
+#[derive(Debug, Default)]
+struct Documentation {
+    lines: Vec<String>,
+    location: Option<SourceLocation>,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -58,6 +69,19 @@ pub fn parse_and_debug_model(w: &mut impl Write, input: &str) -> ModelResult<()>
     Ok(())
 }
 
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Documentation {
+    fn push(&mut self, line: String, location: SourceLocation) {
+        if self.location.is_none() {
+            self.location = Some(location);
+        }
+        self.lines.push(line);
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
@@ -73,10 +97,13 @@ fn parse_idl(input_pair: Pair<'_, Rule>) -> ModelResult<Model> {
                 Rule::control_section,
                 parse_control_section
             );
-            let version = if let Some(NodeValue::String(version)) = control_data.get("version") {
-                Version::from_str(version)?
-            } else {
-                Version::default()
+            let version = match control_data.get(MEMBER_VERSION) {
+                None => Version::default(),
+                Some(NodeValue::String(version)) => Version::from_str(version)?,
+                Some(NodeValue::Number(version)) => Version::from_str(&version.to_string())?,
+                Some(value) => {
+                    return Err(ErrorKind::InvalidVersionNumber(Some(value.to_string())).into())
+                }
             };
             let meta_data = next_pair_into!(
                 "parse_idl",
@@ -88,7 +115,7 @@ fn parse_idl(input_pair: Pair<'_, Rule>) -> ModelResult<Model> {
                 parse_shape_section(item, version)
             });
             match builder {
-                None => Ok(Model::default()),
+                None => Ok(Model::new(version)),
                 Some(mut builder) => builder.meta_data_from(meta_data).try_into(),
             }
         }
@@ -100,17 +127,30 @@ fn parse_control_section(input_pair: Pair<'_, Rule>) -> ModelResult<ValueMap> {
     entry!("parse_control_section", input_pair);
     let mut map: ValueMap = Default::default();
     for inner in input_pair.into_inner() {
-        let (key, value) = pair_into!(
-            "parse_control_section",
-            inner,
-            Rule::control_statement,
-            parse_node_object_kvp
-        );
+        let (key, value) = match inner.as_rule() {
+            Rule::version_statement => parse_version_statement(inner)?,
+            _ => pair_into!(
+                "parse_control_section",
+                inner,
+                Rule::control_statement,
+                parse_node_object_kvp
+            ),
+        };
         let _ = map.insert(key, value);
     }
     Ok(map)
 }
 
+fn parse_version_statement(input_pair: Pair<'_, Rule>) -> ModelResult<(String, NodeValue)> {
+    entry!("parse_version_statement", input_pair);
+    let mut inner = input_pair.into_inner();
+    let version = next_pair_as_str!("parse_version_statement", inner, Rule::version_string);
+    Ok((
+        MEMBER_VERSION.to_string(),
+        NodeValue::String(version.to_string()),
+    ))
+}
+
 fn parse_metadata_section(input_pair: Pair<'_, Rule>) -> ModelResult<ValueMap> {
     entry!("parse_metadata_section", input_pair);
     let mut map: ValueMap = Default::default();
@@ -204,58 +244,80 @@ fn parse_shape_statement(
     builder: &mut ModelBuilder,
 ) -> ModelResult<()> {
     entry!("parse_shape_statement", input_pair);
-    let mut documentation: Vec<String> = Default::default();
+    let mut documentation: Documentation = Default::default();
     let mut traits: Vec<TraitBuilder> = Default::default();
     for shape_statement in input_pair.into_inner() {
+        let location = source_location(&shape_statement);
         match shape_statement.as_rule() {
             Rule::documentation_text => {
-                documentation.push(parse_documentation_text(shape_statement)?);
+                documentation.push(parse_documentation_text(shape_statement)?, location);
             }
             Rule::trait_statements => {
                 traits = parse_trait_statements(shape_statement)?;
             }
             Rule::simple_shape_statement => {
                 let mut shape = parse_simple_shape_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.simple_shape(shape);
             }
             Rule::list_statement => {
                 let mut shape = parse_list_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.list(shape);
             }
             Rule::set_statement => {
                 let mut shape = parse_list_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.set(shape);
             }
             Rule::map_statement => {
                 let mut shape = parse_map_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.map(shape);
             }
             Rule::structure_statement => {
                 let mut shape = parse_structure_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.structure(shape);
             }
             Rule::union_statement => {
                 let mut shape = parse_union_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.union(shape);
             }
+            Rule::enum_statement => {
+                let mut shape = parse_enum_statement(shape_statement)?;
+                let _ = shape.source_location(location);
+                apply_traits(&mut shape, &documentation, &traits);
+                let _ = builder.enum_shape(shape);
+            }
+            Rule::int_enum_statement => {
+                let mut shape = parse_enum_statement(shape_statement)?;
+                let _ = shape.source_location(location);
+                apply_traits(&mut shape, &documentation, &traits);
+                let _ = builder.int_enum(shape);
+            }
             Rule::service_statement => {
                 let mut shape = parse_service_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.service(shape);
             }
             Rule::operation_statement => {
                 let mut shape = parse_operation_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.operation(shape);
             }
             Rule::resource_statement => {
                 let mut shape = parse_resource_statement(shape_statement)?;
+                let _ = shape.source_location(location);
                 apply_traits(&mut shape, &documentation, &traits);
                 let _ = builder.resource(shape);
             }
@@ -297,9 +359,10 @@ fn parse_trait_statements(input_pair: Pair<'_, Rule>) -> ModelResult<Vec<TraitBu
 
 fn parse_a_trait(input_pair: Pair<'_, Rule>) -> ModelResult<TraitBuilder> {
     entry!("parse_a_trait", input_pair);
+    let location = source_location(&input_pair);
     let mut id: Option<String> = None;
     let mut node_value: Option<NodeValue> = None;
-    let mut members: HashMap<String, NodeValue> = Default::default();
+    let mut members: ValueMap = Default::default();
     for inner in input_pair.into_inner() {
         match inner.as_rule() {
             Rule::shape_id => {
@@ -326,14 +389,13 @@ fn parse_a_trait(input_pair: Pair<'_, Rule>) -> ModelResult<TraitBuilder> {
     // NOTE: We ALWAYS provide a value, this overrides the notion of None as a value here.
     // We are essentially bouncing type validation up a level to determine the corresponding
     // trait's shape.
-    match (id, node_value) {
-        (Some(id), None) => Ok(TraitBuilder::with_value(
-            &id,
-            NodeValue::Object(ValueMap::new()),
-        )),
-        (Some(id), Some(node_value)) => Ok(TraitBuilder::with_value(&id, node_value)),
-        _ => ParserError::unreachable("parse_a_trait").into(),
-    }
+    let mut a_trait = match (id, node_value) {
+        (Some(id), None) => TraitBuilder::with_value(&id, NodeValue::Object(ValueMap::new())),
+        (Some(id), Some(node_value)) => TraitBuilder::with_value(&id, node_value),
+        _ => return ParserError::unreachable("parse_a_trait").into(),
+    };
+    let _ = a_trait.source_location(location);
+    Ok(a_trait)
 }
 
 #[allow(unused_assignments)]
@@ -407,10 +469,12 @@ fn parse_simple_shape_statement(input_pair: Pair<'_, Rule>) -> ModelResult<Simpl
 
 fn parse_list_statement(input_pair: Pair<'_, Rule>) -> ModelResult<ListBuilder> {
     entry!("parse_list_statement", input_pair);
-    let (id, members) = parse_membered_statement(input_pair)?;
+    let (id, _, members) = parse_membered_statement(input_pair)?;
     if let Some(member) = members.get(0) {
         if members.len() == 1 && member.name() == &Identifier::new_unchecked(MEMBER_MEMBER) {
-            Ok(ListBuilder::with_target(&id, member.target().clone()))
+            let mut shape = ListBuilder::with_target(&id, member.target().clone());
+            shape.target(member.clone());
+            Ok(shape)
         } else {
             ParserError::new("parse_list_statement")
                 .unreachable_rule()
@@ -427,14 +491,14 @@ fn parse_list_statement(input_pair: Pair<'_, Rule>) -> ModelResult<ListBuilder>
 
 fn parse_map_statement(input_pair: Pair<'_, Rule>) -> ModelResult<MapBuilder> {
     entry!("parse_map_statement", input_pair);
-    let (id, members) = parse_membered_statement(input_pair)?;
-    let mut key: Option<String> = None;
-    let mut value: Option<String> = None;
+    let (id, _, members) = parse_membered_statement(input_pair)?;
+    let mut key: Option<MemberBuilder> = None;
+    let mut value: Option<MemberBuilder> = None;
     for member in members {
         if member.name() == &Identifier::new_unchecked(MEMBER_KEY) {
-            key = Some(member.target().to_string())
+            key = Some(member)
         } else if member.name() == &Identifier::new_unchecked(MEMBER_VALUE) {
-            value = Some(member.target().to_string())
+            value = Some(member)
         } else {
             return ParserError::new("parse_map_statement")
                 .unreachable_rule()
@@ -443,15 +507,23 @@ fn parse_map_statement(input_pair: Pair<'_, Rule>) -> ModelResult<MapBuilder> {
         }
     }
     match (key, value) {
-        (Some(k), Some(v)) => Ok(MapBuilder::new(&id, &k, &v)),
+        (Some(k), Some(v)) => {
+            let mut shape = MapBuilder::new(&id, &k.target().to_string(), &v.target().to_string());
+            shape.key(k);
+            shape.value(v);
+            Ok(shape)
+        }
         _ => ParserError::unreachable("parse_map_statement").into(),
     }
 }
 
 fn parse_structure_statement(input_pair: Pair<'_, Rule>) -> ModelResult<StructureBuilder> {
     entry!("parse_structure_statement", input_pair);
-    let (id, members) = parse_membered_statement(input_pair)?;
+    let (id, mixins, members) = parse_membered_statement(input_pair)?;
     let mut shape = StructureBuilder::new(&id);
+    for mixin in mixins {
+        let _ = shape.with_mixin(&mixin);
+    }
     for member in members {
         let _ = shape.add_member(member);
     }
@@ -460,30 +532,142 @@ fn parse_structure_statement(input_pair: Pair<'_, Rule>) -> ModelResult<Structur
 
 fn parse_union_statement(input_pair: Pair<'_, Rule>) -> ModelResult<StructureBuilder> {
     entry!("parse_union_statement", input_pair);
-    let (id, members) = parse_membered_statement(input_pair)?;
+    let (id, mixins, members) = parse_membered_statement(input_pair)?;
     let mut shape = StructureBuilder::new(&id);
+    for mixin in mixins {
+        let _ = shape.with_mixin(&mixin);
+    }
     for member in members {
         let _ = shape.add_member(member);
     }
     Ok(shape)
 }
 
+fn parse_enum_statement(input_pair: Pair<'_, Rule>) -> ModelResult<EnumBuilder> {
+    entry!("parse_enum_statement", input_pair);
+    let is_int_enum = input_pair.as_rule() == Rule::int_enum_statement;
+    let mut inner = input_pair.into_inner();
+
+    let identifier = next_pair_as_str!("parse_enum_statement", inner, Rule::identifier);
+
+    let mut shape = EnumBuilder::new(&identifier);
+    let members = inner.next().unwrap();
+    if members.as_rule() != Rule::enum_shape_members {
+        unexpected!("parse_enum_statement", members)
+    }
+    for member in members.into_inner() {
+        let member = pair_into!(
+            "parse_enum_statement",
+            member,
+            Rule::enum_shape_member,
+            |pair| parse_enum_shape_member(pair, is_int_enum)
+        );
+        let _ = shape.add_member(member);
+    }
+    Ok(shape)
+}
+
+fn parse_enum_shape_member(
+    input_pair: Pair<'_, Rule>,
+    is_int_enum: bool,
+) -> ModelResult<MemberBuilder> {
+    entry!("parse_enum_shape_member", input_pair);
+    let mut documentation: Documentation = Default::default();
+    let mut traits: Vec<TraitBuilder> = Default::default();
+    let mut id: Option<String> = None;
+    let mut location: Option<SourceLocation> = None;
+    let mut value: Option<EnumValue> = None;
+    for inner in input_pair.into_inner() {
+        match inner.as_rule() {
+            Rule::documentation_text => {
+                let location = source_location(&inner);
+                documentation.push(parse_documentation_text(inner)?, location);
+            }
+            Rule::trait_statements => {
+                traits = parse_trait_statements(inner)?;
+            }
+            Rule::identifier => {
+                location = Some(source_location(&inner));
+                id = Some(inner.as_str().to_string());
+            }
+            Rule::node_value => {
+                let context = inner.clone();
+                let node_value = parse_node_value(inner)?;
+                match EnumValue::try_from(&node_value) {
+                    Ok(enum_value) if enum_value.is_integer() == is_int_enum => {
+                        value = Some(enum_value)
+                    }
+                    _ => {
+                        return ParserError::new("parse_enum_shape_member")
+                            .context(&context)
+                            .into()
+                    }
+                }
+            }
+            _ => unexpected!("parse_enum_shape_member", inner),
+        }
+    }
+    match (id, value) {
+        (Some(id), Some(value)) => {
+            let mut member = MemberBuilder::unit(&id);
+            let _ = member.enum_value(value);
+            if let Some(location) = location {
+                let _ = member.source_location(location);
+            }
+            apply_traits(&mut member, &documentation, &traits);
+            Ok(member)
+        }
+        (Some(id), None) if !is_int_enum => {
+            let mut member = MemberBuilder::unit(&id);
+            let _ = member.enum_value(EnumValue::String(id));
+            if let Some(location) = location {
+                let _ = member.source_location(location);
+            }
+            apply_traits(&mut member, &documentation, &traits);
+            Ok(member)
+        }
+        (Some(id), None) => ParserError::new("parse_enum_shape_member")
+            .expecting("node_value")
+            .context(&id)
+            .into(),
+        _ => ParserError::unreachable("parse_enum_shape_member").into(),
+    }
+}
+
 fn parse_membered_statement(
     input_pair: Pair<'_, Rule>,
-) -> ModelResult<(String, Vec<MemberBuilder>)> {
+) -> ModelResult<(String, Vec<String>, Vec<MemberBuilder>)> {
     entry!("parse_membered_statement", input_pair);
     let mut inner = input_pair.into_inner();
 
     let identifier = next_pair_as_str!("parse_membered_statement", inner, Rule::identifier);
 
-    let members = next_pair_into!(
+    let mut next = inner.next().unwrap();
+    let mixins = if next.as_rule() == Rule::mixins {
+        let mixins = parse_mixins(next)?;
+        next = inner.next().unwrap();
+        mixins
+    } else {
+        Default::default()
+    };
+
+    let members = pair_into!(
         "parse_membered_statement",
-        inner,
+        next,
         Rule::shape_members,
         parse_shape_members
     );
 
-    Ok((identifier, members))
+    Ok((identifier, mixins, members))
+}
+
+fn parse_mixins(input_pair: Pair<'_, Rule>) -> ModelResult<Vec<String>> {
+    entry!("parse_mixins", input_pair);
+    let mut mixins = Vec::default();
+    for inner in input_pair.into_inner() {
+        mixins.push(pair_as_str!("parse_mixins", inner, Rule::shape_id));
+    }
+    Ok(mixins)
 }
 
 fn parse_shape_members(input_pair: Pair<'_, Rule>) -> ModelResult<Vec<MemberBuilder>> {
@@ -503,19 +687,22 @@ fn parse_shape_members(input_pair: Pair<'_, Rule>) -> ModelResult<Vec<MemberBuil
 
 fn parse_shape_member_kvp(input_pair: Pair<'_, Rule>) -> ModelResult<MemberBuilder> {
     entry!("parse_shape_member_kvp", input_pair);
-    let mut documentation: Vec<String> = Default::default();
+    let mut documentation: Documentation = Default::default();
     let mut traits: Vec<TraitBuilder> = Default::default();
     let mut id: Option<String> = None;
+    let mut location: Option<SourceLocation> = None;
     let mut shape_id: Option<String> = None;
     for inner in input_pair.into_inner() {
         match inner.as_rule() {
             Rule::documentation_text => {
-                documentation.push(parse_documentation_text(inner)?);
+                let location = source_location(&inner);
+                documentation.push(parse_documentation_text(inner)?, location);
             }
             Rule::trait_statements => {
                 traits = parse_trait_statements(inner)?;
             }
             Rule::identifier => {
+                location = Some(source_location(&inner));
                 id = Some(inner.as_str().to_string());
             }
             Rule::shape_id => {
@@ -527,6 +714,9 @@ fn parse_shape_member_kvp(input_pair: Pair<'_, Rule>) -> ModelResult<MemberBuild
     match (id, shape_id) {
         (Some(id), Some(shape_id)) => {
             let mut member = MemberBuilder::new(&id, &shape_id);
+            if let Some(location) = location {
+                let _ = member.source_location(location);
+            }
             apply_traits(&mut member, &documentation, &traits);
             Ok(member)
         }
@@ -567,6 +757,30 @@ fn parse_service_statement(input_pair: Pair<'_, Rule>) -> ModelResult<ServiceBui
                             .into();
                     }
                 }
+                MEMBER_RENAME => {
+                    if let NodeValue::Object(renames) = value {
+                        for (shape_id, local_name) in renames {
+                            let local_name = match local_name.as_string() {
+                                Some(local_name) => local_name,
+                                None => {
+                                    return ParserError::new("parse_service_statement")
+                                        .in_rule(MEMBER_RENAME)
+                                        .expecting("quoted_text")
+                                        .context(&local_name)
+                                        .into()
+                                }
+                            };
+                            let shape_id = unquote_object_key(&shape_id);
+                            let _ = ShapeID::from_str(shape_id)?;
+                            let _ = Identifier::from_str(local_name)?;
+                            let _ = service.rename(shape_id, local_name);
+                        }
+                    } else {
+                        return ParserError::unreachable("parse_service_statement")
+                            .context(&value)
+                            .into();
+                    }
+                }
                 _ => {
                     return ParserError::unreachable("parse_service_statement")
                         .context(&key)
@@ -754,17 +968,7 @@ fn parse_node_value(input_pair: Pair<'_, Rule>) -> ModelResult<NodeValue> {
     Ok(match inner.as_rule() {
         Rule::node_array => parse_node_array(inner)?,
         Rule::node_object => parse_node_object(inner)?,
-        Rule::number => {
-            if inner.as_str().contains('.') {
-                NodeValue::Number(Number::Float(
-                    inner.as_str().parse().chain_err(|| "number format error")?,
-                ))
-            } else {
-                NodeValue::Number(Number::Integer(
-                    inner.as_str().parse().chain_err(|| "number format error")?,
-                ))
-            }
-        }
+        Rule::number => NodeValue::Number(Number::from_str(inner.as_str())?),
         Rule::kw_true => NodeValue::Boolean(true),
         Rule::kw_false => NodeValue::Boolean(false),
         Rule::kw_null => NodeValue::None,
@@ -852,6 +1056,16 @@ fn parse_node_object_kvp(input_pair: Pair<'_, Rule>) -> ModelResult<(String, Nod
     }
 }
 
+///
+/// Object keys are kept as written, so a quoted key includes its quotes; a service's `rename`
+/// keys are shape identifiers, and so must be quoted, and are used without them.
+///
+fn unquote_object_key(key: &str) -> &str {
+    key.strip_prefix('"')
+        .and_then(|key| key.strip_suffix('"'))
+        .unwrap_or(key)
+}
+
 fn from_pest_error(e: PestError<Rule>) -> Error {
     Error::with_chain(
         e,
@@ -859,9 +1073,16 @@ fn from_pest_error(e: PestError<Rule>) -> Error {
     )
 }
 
-fn apply_traits(shape: &mut impl ShapeTraits, doc: &[String], traits: &[TraitBuilder]) {
-    if !doc.is_empty() {
-        let _ = shape.documentation(&doc.join("\n"));
+fn source_location(pair: &Pair<'_, Rule>) -> SourceLocation {
+    let (line, column) = pair.as_span().start_pos().line_col();
+    SourceLocation::new(line, column)
+}
+
+fn apply_traits(shape: &mut impl ShapeTraits, doc: &Documentation, traits: &[TraitBuilder]) {
+    if let Some(location) = &doc.location {
+        let mut a_trait = documentation_trait(&doc.lines.join("\n"));
+        let _ = a_trait.source_location(location.clone());
+        let _ = shape.apply_trait(a_trait);
     }
     for a_trait in traits {
         let _ = shape.apply_trait(a_trait.clone());
@@ -2,16 +2,21 @@ use std::io::Write;
 
 use atelier_core::error::Result;
 use atelier_core::io::ModelWriter;
-use atelier_core::model::shapes::{HasTraits, MemberShape, ShapeKind};
+use atelier_core::model::shapes::{
+    AppliedTraits, EnumValue, Enumeration, HasTraits, MemberShape, ShapeKind, TopLevelShape,
+    TraitValue,
+};
 use atelier_core::model::values::Value;
 use atelier_core::model::{HasIdentity, Model, NamespaceID, ShapeID};
+use atelier_core::prelude::{PRELUDE_NAMESPACE, TRAIT_ENUMVALUE};
 use atelier_core::syntax::{
     MEMBER_COLLECTION_OPERATIONS, MEMBER_CREATE, MEMBER_DELETE, MEMBER_ERRORS, MEMBER_IDENTIFIERS,
     MEMBER_INPUT, MEMBER_KEY, MEMBER_LIST, MEMBER_MEMBER, MEMBER_OPERATIONS, MEMBER_OUTPUT,
-    MEMBER_PUT, MEMBER_READ, MEMBER_RESOURCES, MEMBER_UPDATE, MEMBER_VALUE, MEMBER_VERSION,
-    SHAPE_APPLY, SHAPE_LIST, SHAPE_MAP, SHAPE_OPERATION, SHAPE_RESOURCE, SHAPE_SERVICE, SHAPE_SET,
-    SHAPE_STRUCTURE, SHAPE_UNION,
+    MEMBER_PUT, MEMBER_READ, MEMBER_RENAME, MEMBER_RESOURCES, MEMBER_UPDATE, MEMBER_VALUE,
+    MEMBER_VERSION, SHAPE_APPLY, SHAPE_ENUM, SHAPE_INT_ENUM, SHAPE_LIST, SHAPE_MAP,
+    SHAPE_OPERATION, SHAPE_RESOURCE, SHAPE_SERVICE, SHAPE_SET, SHAPE_STRUCTURE, SHAPE_UNION,
 };
+use atelier_core::Version;
 
 use crate::syntax::{
     CONTROL_DATA_PREFIX, STATEMENT_METADATA, STATEMENT_NAMESPACE, STATEMENT_USE, TRAIT_PREFIX,
@@ -57,17 +62,19 @@ impl SmithyWriter {
     pub fn new(namespace: NamespaceID) -> Self {
         Self {
             namespace,
-            prelude_namespace: NamespaceID::new_unchecked(atelier_core::prelude::PRELUDE_NAMESPACE),
+            prelude_namespace: NamespaceID::new_unchecked(PRELUDE_NAMESPACE),
         }
     }
 
     fn write_control_section(&mut self, w: &mut impl Write, model: &Model) -> Result<()> {
+        let version = match model.smithy_version() {
+            Version::V10 => "1.0",
+            Version::V20 => "2",
+        };
         writeln!(
             w,
             "{}{}: \"{}\"",
-            CONTROL_DATA_PREFIX,
-            MEMBER_VERSION,
-            model.smithy_version().to_string()
+            CONTROL_DATA_PREFIX, MEMBER_VERSION, version
         )?;
         writeln!(w)?;
         Ok(())
@@ -97,8 +104,8 @@ impl SmithyWriter {
     }
 
     fn write_use_section(&mut self, w: &mut impl Write, model: &Model) -> Result<()> {
-        for use_shape in model
-            .shapes()
+        for use_shape in sorted_shapes(model)
+            .into_iter()
             .filter(|shape| shape.is_unresolved() && !shape.has_traits())
         {
             writeln!(w, "{} {}", STATEMENT_USE, use_shape.id())?;
@@ -109,12 +116,12 @@ impl SmithyWriter {
 
     fn write_shape_statements(&mut self, w: &mut impl Write, model: &Model) -> Result<()> {
         let from_namespace = self.namespace.clone();
-        for shape in model
-            .shapes()
+        for shape in sorted_shapes(model)
+            .into_iter()
             .filter(|shape| shape.id().namespace() == &from_namespace)
         {
             if !shape.body().is_unresolved() {
-                for (id, value) in shape.traits() {
+                for (id, value) in sorted_traits(shape.traits()) {
                     self.write_trait(w, id, value, "")?;
                 }
             }
@@ -144,15 +151,37 @@ impl SmithyWriter {
                     writeln!(w, "}}")?;
                 }
                 ShapeKind::Structure(structured) => {
-                    writeln!(w, "{} {} {{", SHAPE_STRUCTURE, shape.id().shape_name())?;
+                    writeln!(
+                        w,
+                        "{} {}{} {{",
+                        SHAPE_STRUCTURE,
+                        shape.id().shape_name(),
+                        mixins_clause(shape)
+                    )?;
                     self.write_members(w, structured.members(), "    ")?;
                     writeln!(w, "}}")?;
                 }
                 ShapeKind::Union(structured) => {
-                    writeln!(w, "{} {} {{", SHAPE_UNION, shape.id().shape_name())?;
+                    writeln!(
+                        w,
+                        "{} {}{} {{",
+                        SHAPE_UNION,
+                        shape.id().shape_name(),
+                        mixins_clause(shape)
+                    )?;
                     self.write_members(w, structured.members(), "    ")?;
                     writeln!(w, "}}")?;
                 }
+                ShapeKind::Enum(enumeration) => {
+                    writeln!(w, "{} {} {{", SHAPE_ENUM, shape.id().shape_name())?;
+                    self.write_enum_members(w, enumeration, false, "    ")?;
+                    writeln!(w, "}}")?;
+                }
+                ShapeKind::IntEnum(enumeration) => {
+                    writeln!(w, "{} {} {{", SHAPE_INT_ENUM, shape.id().shape_name())?;
+                    self.write_enum_members(w, enumeration, true, "    ")?;
+                    writeln!(w, "}}")?;
+                }
                 ShapeKind::Service(service) => {
                     writeln!(w, "{} {} {{", SHAPE_SERVICE, shape.id().shape_name())?;
                     writeln!(w, "    {}: {:?}", MEMBER_VERSION, service.version())?;
@@ -180,6 +209,19 @@ impl SmithyWriter {
                                 .join(", ")
                         )?;
                     }
+                    if service.has_renames() {
+                        writeln!(w, "    {}: {{", MEMBER_RENAME)?;
+                        let mut renames: Vec<_> = service.renames().collect();
+                        renames.sort();
+                        for (id, local_name) in renames {
+                            write!(w, "        ")?;
+                            self.write_value(w, &Value::from(id), false)?;
+                            write!(w, ": ")?;
+                            self.write_value(w, &Value::from(local_name.to_string()), false)?;
+                            writeln!(w)?;
+                        }
+                        writeln!(w, "    }}")?;
+                    }
                     writeln!(w, "}}")?;
                 }
                 ShapeKind::Operation(operation) => {
@@ -208,7 +250,9 @@ impl SmithyWriter {
                     writeln!(w, "{} {} {{", SHAPE_RESOURCE, shape.id().shape_name())?;
                     if resource.has_identifiers() {
                         writeln!(w, "    {}: {{", MEMBER_IDENTIFIERS)?;
-                        for (id, target) in resource.identifiers() {
+                        let mut identifiers: Vec<_> = resource.identifiers().collect();
+                        identifiers.sort();
+                        for (id, target) in identifiers {
                             writeln!(w, "        {}: {}", id, target.shape_name())?;
                         }
                         writeln!(w, "    }}")?;
@@ -271,7 +315,7 @@ impl SmithyWriter {
                 }
                 ShapeKind::Unresolved => {
                     if shape.has_traits() {
-                        for (id, value) in shape.traits() {
+                        for (id, value) in sorted_traits(shape.traits()) {
                             write!(w, "{} {} ", SHAPE_APPLY, shape.id())?;
                             self.write_trait(w, id, value, "")?;
                         }
@@ -352,6 +396,8 @@ impl SmithyWriter {
         members: impl Iterator<Item = &'a MemberShape>,
         prefix: &str,
     ) -> Result<()> {
+        let mut members: Vec<&MemberShape> = members.collect();
+        members.sort_by_key(|member| member.id());
         for member in members {
             self.write_member(w, member, prefix)?;
         }
@@ -364,7 +410,7 @@ impl SmithyWriter {
         member: &MemberShape,
         prefix: &str,
     ) -> Result<()> {
-        for (id, value) in member.traits() {
+        for (id, value) in sorted_traits(member.traits()) {
             self.write_trait(w, id, value, prefix)?;
         }
         writeln!(
@@ -376,4 +422,63 @@ impl SmithyWriter {
         )?;
         Ok(())
     }
+
+    fn write_enum_members(
+        &mut self,
+        w: &mut impl Write,
+        enumeration: &Enumeration,
+        int_enum: bool,
+        prefix: &str,
+    ) -> Result<()> {
+        let enum_value_id = ShapeID::new_unchecked(PRELUDE_NAMESPACE, TRAIT_ENUMVALUE, None);
+        let mut members: Vec<&MemberShape> = enumeration.members().collect();
+        members.sort_by_key(|member| member.id());
+        for member in members {
+            for (id, value) in sorted_traits(member.traits()) {
+                if id != &enum_value_id {
+                    self.write_trait(w, id, value, prefix)?;
+                }
+            }
+            match enumeration.member_value(member.id(), int_enum) {
+                Some(EnumValue::String(v)) if v == member.id().to_string() => {
+                    writeln!(w, "{}{}", prefix, member.id())?
+                }
+                Some(EnumValue::String(v)) => writeln!(w, "{}{} = {:?}", prefix, member.id(), v)?,
+                Some(EnumValue::Integer(v)) => writeln!(w, "{}{} = {}", prefix, member.id(), v)?,
+                None => writeln!(w, "{}{}", prefix, member.id())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn sorted_shapes(model: &Model) -> Vec<&TopLevelShape> {
+    let mut shapes: Vec<&TopLevelShape> = model.shapes().collect();
+    shapes.sort_by_key(|shape| shape.id());
+    shapes
+}
+
+fn sorted_traits(traits: &AppliedTraits) -> Vec<(&ShapeID, &TraitValue)> {
+    let mut traits: Vec<(&ShapeID, &TraitValue)> = traits.iter().collect();
+    traits.sort_by_key(|(id, _)| *id);
+    traits
+}
+
+fn mixins_clause(shape: &TopLevelShape) -> String {
+    if shape.has_mixins() {
+        format!(
+            " with [{}]",
+            shape
+                .mixins()
+                .map(|id| id.shape_name().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    } else {
+        String::new()
+    }
 }
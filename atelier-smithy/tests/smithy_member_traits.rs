@@ -0,0 +1,57 @@
+use atelier_core::io::read_model_from_string;
+use atelier_core::model::shapes::{HasTraits, ShapeKind};
+use atelier_core::model::{Model, ShapeID};
+use atelier_smithy::SmithyReader;
+use std::str::FromStr;
+
+fn parse(input: &str) -> Model {
+    read_model_from_string(&mut SmithyReader, input).unwrap()
+}
+
+fn id(s: &str) -> ShapeID {
+    ShapeID::from_str(s).unwrap()
+}
+
+#[test]
+fn list_member_traits_are_kept() {
+    let model = parse(
+        r##"$version: "2"
+        namespace smithy.example
+
+        list Names {
+            @length(min: 1)
+            @pattern("^[a-z]+$")
+            member: String
+        }"##,
+    );
+    match model.shape(&id("smithy.example#Names")).unwrap().body() {
+        ShapeKind::List(list) => {
+            assert!(list.member().has_trait(&id("smithy.api#length")));
+            assert!(list.member().has_trait(&id("smithy.api#pattern")));
+        }
+        _ => panic!("expected a list shape"),
+    }
+}
+
+#[test]
+fn map_member_traits_are_kept() {
+    let model = parse(
+        r##"$version: "2"
+        namespace smithy.example
+
+        map Counts {
+            @length(min: 1)
+            key: String
+            @range(min: 0)
+            value: Integer
+        }"##,
+    );
+    match model.shape(&id("smithy.example#Counts")).unwrap().body() {
+        ShapeKind::Map(map) => {
+            assert!(map.key().has_trait(&id("smithy.api#length")));
+            assert!(!map.key().has_trait(&id("smithy.api#range")));
+            assert!(map.value().has_trait(&id("smithy.api#range")));
+        }
+        _ => panic!("expected a map shape"),
+    }
+}
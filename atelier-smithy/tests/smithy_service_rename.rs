@@ -0,0 +1,102 @@
+use atelier_core::io::lines::make_line_oriented_form;
+use atelier_core::io::{read_model_from_string, write_model_to_string};
+use atelier_core::model::shapes::ShapeKind;
+use atelier_core::model::{Identifier, Model, NamespaceID, ShapeID};
+use atelier_smithy::{SmithyReader, SmithyWriter};
+use std::str::FromStr;
+
+const MODEL: &str = r##"$version: "2"
+
+namespace smithy.example
+
+service Weather {
+    version: "2006-03-01"
+    operations: [GetCity]
+    rename: {
+        "smithy.other#City": "OtherCity"
+        "smithy.other#Forecast": "OtherForecast"
+    }
+}
+
+operation GetCity {}
+"##;
+
+fn renames(model: &Model) -> Vec<(ShapeID, Identifier)> {
+    let service = model
+        .shape(&ShapeID::from_str("smithy.example#Weather").unwrap())
+        .unwrap();
+    if let ShapeKind::Service(service) = service.body() {
+        let mut renames: Vec<(ShapeID, Identifier)> = service
+            .renames()
+            .map(|(id, name)| (id.clone(), name.clone()))
+            .collect();
+        renames.sort();
+        renames
+    } else {
+        panic!("expected a service shape")
+    }
+}
+
+#[test]
+fn test_read_service_rename() {
+    let model = read_model_from_string(&mut SmithyReader, MODEL).unwrap();
+    assert_eq!(
+        renames(&model),
+        vec![
+            (
+                ShapeID::from_str("smithy.other#City").unwrap(),
+                Identifier::from_str("OtherCity").unwrap()
+            ),
+            (
+                ShapeID::from_str("smithy.other#Forecast").unwrap(),
+                Identifier::from_str("OtherForecast").unwrap()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_write_service_rename() {
+    let model = read_model_from_string(&mut SmithyReader, MODEL).unwrap();
+    let mut writer = SmithyWriter::new(NamespaceID::new_unchecked("smithy.example"));
+    let output = write_model_to_string(&mut writer, &model).unwrap();
+    assert!(output.contains(
+        r#"    rename: {
+        "smithy.other#City": "OtherCity"
+        "smithy.other#Forecast": "OtherForecast"
+    }"#
+    ));
+
+    let reread = read_model_from_string(&mut SmithyReader, output).unwrap();
+    assert_eq!(renames(&reread), renames(&model));
+}
+
+#[test]
+fn test_invalid_service_rename() {
+    let model = MODEL.replace("\"OtherCity\"", "\"Other City\"");
+    assert!(read_model_from_string(&mut SmithyReader, model).is_err());
+}
+
+#[test]
+fn test_non_string_service_rename() {
+    let model = MODEL.replace("\"OtherCity\"", "[\"OtherCity\"]");
+    assert!(read_model_from_string(&mut SmithyReader, model).is_err());
+}
+
+#[test]
+fn test_only_rename_keys_are_unquoted() {
+    let model = read_model_from_string(
+        &mut SmithyReader,
+        MODEL.replace(
+            "operation GetCity {}",
+            "@examples([{ title: \"Get\", \"documentation\": \"Quoted key\" }])\noperation GetCity {}",
+        ),
+    )
+    .unwrap();
+    assert_eq!(renames(&model).len(), 2);
+    let lines = make_line_oriented_form(&model);
+    assert!(lines.contains(
+        &"operation::smithy.example#GetCity::trait::smithy.api#examples<=[0]={\"documentation\"}=\"Quoted key\""
+            .to_string()
+    ));
+}
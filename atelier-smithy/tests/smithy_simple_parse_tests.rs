@@ -1,5 +1,7 @@
-use atelier_core::model::Model;
-use atelier_smithy::SmithyReader;
+use atelier_core::io::{read_model_from_string, write_model_to_string};
+use atelier_core::model::{Model, NamespaceID};
+use atelier_core::Version;
+use atelier_smithy::{SmithyReader, SmithyWriter};
 use atelier_test::{parse_and_compare_model, parts as model_part};
 
 fn model_test(input_str: &str, expected: Model) {
@@ -11,6 +13,36 @@ fn empty_file() {
     model_test("", Model::default())
 }
 
+#[test]
+fn version_2_file() {
+    model_test(r##"$version: "2""##, Model::new(Version::V20));
+    model_test(r##"$version: "2.0""##, Model::new(Version::V20))
+}
+
+#[test]
+fn version_2_round_trip() {
+    let model = read_model_from_string(
+        &mut SmithyReader,
+        r##"$version: "2.0"
+
+        namespace smithy.waiters
+
+        integer WaiterDelay"##,
+    )
+    .unwrap();
+    assert_eq!(model.smithy_version(), &Version::V20);
+
+    let mut writer = SmithyWriter::new(NamespaceID::new_unchecked("smithy.waiters"));
+    let output = write_model_to_string(&mut writer, &model).unwrap();
+    assert!(output.starts_with("$version: \"2\"\n"));
+}
+
+#[test]
+fn invalid_version() {
+    assert!(read_model_from_string(&mut SmithyReader, r##"$version: "3""##).is_err());
+    assert!(read_model_from_string(&mut SmithyReader, r##"$version: true"##).is_err());
+}
+
 #[test]
 fn namespace_only() {
     model_test(
@@ -87,3 +119,111 @@ fn simple_shape_with_traits_and_documentation() {
         model_part::simple_shape_with_traits_and_documentation().model,
     )
 }
+
+#[test]
+fn enum_shape() {
+    model_test(
+        r##"$version: "2"
+        namespace smithy.example
+
+        enum Suit {
+            CLUB = "club"
+            /// Red.
+            DIAMOND = "diamond"
+            HEART
+        }"##,
+        model_part::enum_shape().model,
+    )
+}
+
+#[test]
+fn int_enum_shape() {
+    model_test(
+        r##"$version: "2"
+        namespace smithy.example
+
+        intEnum FaceCard {
+            JACK = 11
+            QUEEN = 12
+            KING = 13
+        }"##,
+        model_part::int_enum_shape().model,
+    )
+}
+
+#[test]
+fn enum_shape_invalid_values() {
+    assert!(read_model_from_string(
+        &mut SmithyReader,
+        r##"$version: "2"
+        namespace smithy.example
+
+        enum Suit {
+            CLUB = 1
+        }"##,
+    )
+    .is_err());
+    assert!(read_model_from_string(
+        &mut SmithyReader,
+        r##"$version: "2"
+        namespace smithy.example
+
+        intEnum FaceCard {
+            JACK = "jack"
+        }"##,
+    )
+    .is_err());
+    assert!(read_model_from_string(
+        &mut SmithyReader,
+        r##"$version: "2"
+        namespace smithy.example
+
+        intEnum FaceCard {
+            JACK
+        }"##,
+    )
+    .is_err());
+}
+
+#[test]
+fn enum_shape_round_trip() {
+    let model = model_part::enum_shape().model;
+    let mut writer = SmithyWriter::new(NamespaceID::new_unchecked("smithy.example"));
+    let output = write_model_to_string(&mut writer, &model).unwrap();
+    println!("{}", output);
+    model_test(&output, model);
+
+    let model = model_part::int_enum_shape().model;
+    let mut writer = SmithyWriter::new(NamespaceID::new_unchecked("smithy.example"));
+    let output = write_model_to_string(&mut writer, &model).unwrap();
+    println!("{}", output);
+    model_test(&output, model);
+}
+
+#[test]
+fn structure_with_mixins() {
+    model_test(
+        r##"$version: "2"
+        namespace smithy.example
+
+        @mixin
+        structure PaginatedInput {
+            nextToken: String
+        }
+
+        structure ListThingsInput with [PaginatedInput] {
+            filter: String
+        }"##,
+        model_part::structure_with_mixins().model,
+    )
+}
+
+#[test]
+fn structure_with_mixins_round_trip() {
+    let model = model_part::structure_with_mixins().model;
+    let mut writer = SmithyWriter::new(NamespaceID::new_unchecked("smithy.example"));
+    let output = write_model_to_string(&mut writer, &model).unwrap();
+    println!("{}", output);
+    assert!(output.contains("structure ListThingsInput with [PaginatedInput] {"));
+    model_test(&output, model);
+}
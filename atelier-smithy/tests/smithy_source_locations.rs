@@ -0,0 +1,114 @@
+use atelier_core::action::validate::{run_validation_actions, CorrectTypeReferences};
+use atelier_core::io::read_model_from_string;
+use atelier_core::model::shapes::HasTraits;
+use atelier_core::model::{HasSourceLocation, Identifier, Model, ShapeID, SourceLocation};
+use atelier_core::prelude::{prelude_shape_named, TRAIT_DOCUMENTATION, TRAIT_REQUIRED};
+use atelier_smithy::SmithyReader;
+use std::str::FromStr;
+
+const MODEL: &str = r##"namespace smithy.example
+
+/// A string.
+string MyString
+
+@sensitive
+structure MyStructure {
+    @required
+    name: MyString,
+    other: Undefined
+}
+
+list MyList {
+    member: MyString
+}
+
+integer MyInteger
+"##;
+
+fn read_model() -> Model {
+    read_model_from_string(&mut SmithyReader, MODEL).unwrap()
+}
+
+fn shape_id(shape_name: &str) -> ShapeID {
+    ShapeID::new_unchecked("smithy.example", shape_name, None)
+}
+
+#[test]
+fn shape_locations() {
+    let model = read_model();
+
+    let shape = model.shape(&shape_id("MyString")).unwrap();
+    assert_eq!(shape.location(), &Some(SourceLocation::new(4, 1)));
+    assert_eq!(
+        shape.trait_location(&prelude_shape_named(TRAIT_DOCUMENTATION).unwrap()),
+        Some(&SourceLocation::new(3, 4))
+    );
+
+    let shape = model.shape(&shape_id("MyStructure")).unwrap();
+    assert_eq!(shape.location(), &Some(SourceLocation::new(7, 1)));
+    assert_eq!(
+        shape.trait_location(&prelude_shape_named("sensitive").unwrap()),
+        Some(&SourceLocation::new(6, 1))
+    );
+}
+
+#[test]
+fn member_locations() {
+    let model = read_model();
+
+    let shape = model.shape(&shape_id("MyStructure")).unwrap();
+    let member = shape
+        .member(&Identifier::from_str("name").unwrap())
+        .unwrap();
+    assert!(member.is_required());
+    assert_eq!(member.location(), &Some(SourceLocation::new(9, 5)));
+    assert_eq!(
+        member.trait_location(&prelude_shape_named(TRAIT_REQUIRED).unwrap()),
+        Some(&SourceLocation::new(8, 5))
+    );
+    assert_eq!(
+        model.location_of(
+            &shape_id("MyStructure").make_member(Identifier::from_str("other").unwrap())
+        ),
+        Some(&SourceLocation::new(10, 5))
+    );
+
+    let shape = model.shape(&shape_id("MyList")).unwrap();
+    let member = shape
+        .member(&Identifier::from_str("member").unwrap())
+        .unwrap();
+    assert_eq!(member.location(), &Some(SourceLocation::new(14, 5)));
+}
+
+#[test]
+fn locations_ignored_in_equality() {
+    let model = read_model();
+    let mut other = model.clone();
+    other
+        .shape_mut(&shape_id("MyString"))
+        .unwrap()
+        .unset_location();
+    assert_eq!(model, other);
+}
+
+#[test]
+fn issue_locations() {
+    let mut model = read_model();
+    model.set_source_file("example.smithy");
+
+    let issues = run_validation_actions(
+        &mut [Box::new(CorrectTypeReferences::default())],
+        &model,
+        false,
+    )
+    .unwrap();
+    let issue = issues
+        .iter()
+        .find(|issue| issue.locus() == &Some(shape_id("MyInteger")))
+        .unwrap();
+    assert_eq!(
+        issue.location(),
+        &Some(SourceLocation::in_file("example.smithy", 17, 1))
+    );
+    assert!(issue.to_string().contains("(example.smithy:17:1)"));
+}
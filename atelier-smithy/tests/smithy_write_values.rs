@@ -0,0 +1,57 @@
+use atelier_core::io::{read_model_from_string, write_model_to_string};
+use atelier_core::model::NamespaceID;
+use atelier_smithy::{SmithyReader, SmithyWriter};
+
+const MODEL: &str = r##"$version: "2"
+
+metadata zulu = 1
+metadata alpha = 2
+
+namespace example.values
+
+@range(min: -170141183460469231731687303715884105728, max: 170141183460469231731687303715884105727)
+bigInteger Huge
+
+@range(min: 0.1000000000000000000000000000001, max: 1.5)
+bigDecimal Precise
+
+@documentation("ordered")
+@custom(zulu: "z", alpha: "a", mike: "m")
+structure Ordered {
+    zulu: String
+    alpha: Huge
+    mike: Precise
+}
+
+@trait
+structure custom {
+    zulu: String
+    alpha: String
+    mike: String
+}
+"##;
+
+fn round_trip() -> String {
+    let model = read_model_from_string(&mut SmithyReader, MODEL).unwrap();
+    let mut writer = SmithyWriter::new(NamespaceID::new_unchecked("example.values"));
+    write_model_to_string(&mut writer, &model).unwrap()
+}
+
+#[test]
+fn test_numbers_are_lossless() {
+    let output = round_trip();
+    assert!(output.contains(
+        "@range(min: -170141183460469231731687303715884105728, max: 170141183460469231731687303715884105727)"
+    ));
+    assert!(output.contains("@range(min: 0.1000000000000000000000000000001, max: 1.5)"));
+}
+
+#[test]
+fn test_output_is_reproducible() {
+    let output = round_trip();
+    assert!(output.contains("metadata \"zulu\" = 1\nmetadata \"alpha\" = 2\n"));
+    assert!(output.contains(r#"@custom(zulu: "z", alpha: "a", mike: "m")"#));
+    for _ in 0..10 {
+        assert_eq!(round_trip(), output);
+    }
+}
@@ -5,8 +5,10 @@ Provides simple models as basic tests.
 use crate::TestCaseModel;
 use atelier_core::builder::traits::{documentation, pattern, range, range_min};
 use atelier_core::builder::{
-    ModelBuilder, OperationBuilder, ServiceBuilder, ShapeTraits, SimpleShapeBuilder,
+    EnumBuilder, MemberBuilder, ModelBuilder, OperationBuilder, ServiceBuilder, ShapeTraits,
+    SimpleShapeBuilder, StructureBuilder,
 };
+use atelier_core::model::shapes::EnumValue;
 use atelier_core::Version;
 use std::convert::TryInto;
 
@@ -266,3 +268,144 @@ pub fn service_with_renames() -> TestCaseModel {
         expected_lines: SERVICE_WITH_RENAMES.to_vec(),
     }
 }
+
+// ------------------------------------------------------------------------------------------------
+
+const ENUM_SHAPE: &[&str] = &[
+    "enum::smithy.example#Suit",
+    "enum::smithy.example#Suit::CLUB::trait::smithy.api#enumValue<=\"club\"",
+    "enum::smithy.example#Suit::CLUB=>smithy.api#Unit",
+    "enum::smithy.example#Suit::DIAMOND::trait::smithy.api#documentation<=\"Red.\"",
+    "enum::smithy.example#Suit::DIAMOND::trait::smithy.api#enumValue<=\"diamond\"",
+    "enum::smithy.example#Suit::DIAMOND=>smithy.api#Unit",
+    "enum::smithy.example#Suit::HEART::trait::smithy.api#enumValue<=\"HEART\"",
+    "enum::smithy.example#Suit::HEART=>smithy.api#Unit",
+];
+
+///
+/// Example model with an enum shape.
+///
+/// ```smithy
+/// $version: "2"
+/// namespace smithy.example
+///
+/// enum Suit {
+///     CLUB = "club"
+///     /// Red.
+///     DIAMOND = "diamond"
+///     HEART
+/// }
+/// ```
+///
+pub fn enum_shape() -> TestCaseModel {
+    TestCaseModel {
+        model: ModelBuilder::new(Version::V20, "smithy.example")
+            .enum_shape(
+                EnumBuilder::new("Suit")
+                    .string_member("CLUB", "club")
+                    .add_member({
+                        let mut member = MemberBuilder::unit("DIAMOND");
+                        let _ = member
+                            .documentation("Red.")
+                            .enum_value(EnumValue::from("diamond"));
+                        member
+                    })
+                    .member("HEART")
+                    .into(),
+            )
+            .try_into()
+            .unwrap(),
+        expected_lines: ENUM_SHAPE.to_vec(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+const INT_ENUM_SHAPE: &[&str] = &[
+    "intEnum::smithy.example#FaceCard",
+    "intEnum::smithy.example#FaceCard::JACK::trait::smithy.api#enumValue<=11",
+    "intEnum::smithy.example#FaceCard::JACK=>smithy.api#Unit",
+    "intEnum::smithy.example#FaceCard::KING::trait::smithy.api#enumValue<=13",
+    "intEnum::smithy.example#FaceCard::KING=>smithy.api#Unit",
+    "intEnum::smithy.example#FaceCard::QUEEN::trait::smithy.api#enumValue<=12",
+    "intEnum::smithy.example#FaceCard::QUEEN=>smithy.api#Unit",
+];
+
+///
+/// Example model with an intEnum shape.
+///
+/// ```smithy
+/// $version: "2"
+/// namespace smithy.example
+///
+/// intEnum FaceCard {
+///     JACK = 11
+///     QUEEN = 12
+///     KING = 13
+/// }
+/// ```
+///
+pub fn int_enum_shape() -> TestCaseModel {
+    TestCaseModel {
+        model: ModelBuilder::new(Version::V20, "smithy.example")
+            .int_enum(
+                EnumBuilder::new("FaceCard")
+                    .int_member("JACK", 11)
+                    .int_member("QUEEN", 12)
+                    .int_member("KING", 13)
+                    .into(),
+            )
+            .try_into()
+            .unwrap(),
+        expected_lines: INT_ENUM_SHAPE.to_vec(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+const STRUCTURE_WITH_MIXINS: &[&str] = &[
+    "structure::smithy.example#ListThingsInput",
+    "structure::smithy.example#ListThingsInput::filter=>smithy.api#String",
+    "structure::smithy.example#ListThingsInput::mixin=>smithy.example#PaginatedInput",
+    "structure::smithy.example#PaginatedInput",
+    "structure::smithy.example#PaginatedInput::nextToken=>smithy.api#String",
+    "structure::smithy.example#PaginatedInput::trait::smithy.api#mixin<={}",
+];
+
+///
+/// Example model with a structure shape that uses a mixin.
+///
+/// ```smithy
+/// $version: "2"
+/// namespace smithy.example
+///
+/// @mixin
+/// structure PaginatedInput {
+///     nextToken: String
+/// }
+///
+/// structure ListThingsInput with [PaginatedInput] {
+///     filter: String
+/// }
+/// ```
+///
+pub fn structure_with_mixins() -> TestCaseModel {
+    TestCaseModel {
+        model: ModelBuilder::new(Version::V20, "smithy.example")
+            .structure(
+                StructureBuilder::new("PaginatedInput")
+                    .mixin()
+                    .string("nextToken")
+                    .into(),
+            )
+            .structure(
+                StructureBuilder::new("ListThingsInput")
+                    .with_mixin("PaginatedInput")
+                    .string("filter")
+                    .into(),
+            )
+            .try_into()
+            .unwrap(),
+        expected_lines: STRUCTURE_WITH_MIXINS.to_vec(),
+    }
+}